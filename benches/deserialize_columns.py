@@ -0,0 +1,102 @@
+"""
+deserialize_columns.py
+
+Benchmark of reading result pages made of fixed-size native columns
+(tinyint, smallint, int, bigint, float, double, boolean).
+
+Cells of these columns are decoded by deserializers picked once per page from
+the result metadata, while other types go through the generic per-cell type
+dispatch. To compare the two paths, build the driver at the commit that
+introduced the per-column deserializers (207bb7b) and at its parent, and run
+this script against each build with the same arguments:
+
+    git checkout 207bb7b~1 && uv run maturin develop --release
+    uv run python benches/deserialize_columns.py
+    git checkout 207bb7b && uv run maturin develop --release
+    uv run python benches/deserialize_columns.py --skip-setup
+
+The script prints the best time of all runs, which is the least affected by
+noise. A `text` table is read as well, as a reference for the generic path
+that is expected to be the same in both builds.
+"""
+
+import argparse
+import asyncio
+import os
+import time
+
+from scylla.session import Session
+from scylla.session_builder import SessionBuilder
+from scylla.statement import Statement
+
+FIXED_SIZE_TYPES = ["tinyint", "smallint", "int", "bigint", "float", "double", "boolean"]
+
+
+def fixed_size_values(i: int) -> list[object]:
+    return [i % 128, i % 32768, i, i * 1_000_003, i / 4, i / 3, i % 2 == 0]
+
+
+async def setup(session: Session, rows: int) -> None:
+    await session.execute(
+        """
+        CREATE KEYSPACE IF NOT EXISTS benches_ks
+        WITH replication = {'class': 'NetworkTopologyStrategy', 'replication_factor': 1};
+        """
+    )
+    await session.use_keyspace("benches_ks")
+
+    columns = ", ".join(f"c{i} {typ}" for i, typ in enumerate(FIXED_SIZE_TYPES))
+    await session.execute("DROP TABLE IF EXISTS fixed_size_columns")
+    await session.execute(f"CREATE TABLE fixed_size_columns (id int PRIMARY KEY, {columns})")
+    await session.execute("DROP TABLE IF EXISTS text_columns")
+    await session.execute(
+        "CREATE TABLE text_columns (id int PRIMARY KEY, "
+        + ", ".join(f"c{i} text" for i in range(len(FIXED_SIZE_TYPES)))
+        + ")"
+    )
+
+    markers = ", ".join("?" for _ in range(len(FIXED_SIZE_TYPES) + 1))
+    names = ", ".join(f"c{i}" for i in range(len(FIXED_SIZE_TYPES)))
+    insert_fixed = await session.prepare(f"INSERT INTO fixed_size_columns (id, {names}) VALUES ({markers})")
+    insert_text = await session.prepare(f"INSERT INTO text_columns (id, {names}) VALUES ({markers})")
+    for i in range(rows):
+        values = fixed_size_values(i)
+        await asyncio.gather(
+            session.execute(insert_fixed, [i, *values]),
+            session.execute(insert_text, [i, *(str(value) for value in values)]),
+        )
+
+
+async def read_all(session: Session, table: str, page_size: int) -> tuple[float, int]:
+    statement = Statement(f"SELECT * FROM {table}").with_page_size(page_size)
+    start = time.perf_counter()
+    rows = 0
+    async for _ in await session.execute(statement):
+        rows += 1
+    return time.perf_counter() - start, rows
+
+
+async def main() -> None:
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("--rows", type=int, default=100_000)
+    parser.add_argument("--page-size", type=int, default=5_000)
+    parser.add_argument("--runs", type=int, default=10)
+    parser.add_argument("--skip-setup", action="store_true", help="reuse the tables from a previous run")
+    args = parser.parse_args()
+
+    uri = os.environ.get("SCYLLA_URI", "127.0.0.2:9042")
+    session = await SessionBuilder().contact_points([uri]).connect()
+    if args.skip_setup:
+        await session.use_keyspace("benches_ks")
+    else:
+        await setup(session, args.rows)
+
+    for table in ["fixed_size_columns", "text_columns"]:
+        # The first read warms up connections and the server's caches.
+        await read_all(session, table, args.page_size)
+        best, rows = min([await read_all(session, table, args.page_size) for _ in range(args.runs)])
+        print(f"{table}: best of {args.runs} runs {best * 1000:.1f} ms, {rows / best:,.0f} rows/s")
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
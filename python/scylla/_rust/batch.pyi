@@ -24,15 +24,31 @@ class Batch:
     (`self`) retains its old configuration and remains unchanged.
     """
     def __init__(self, batch_type: BatchType = BatchType.Logged) -> None: ...
-    def add(self, statement: str | Statement | PreparedStatement, values: Any | None = None) -> None: ...
-    def add_all(self, items: Sequence[tuple[str | Statement | PreparedStatement, Any | None]]) -> None: ...
+    def add(
+        self, statement: str | Statement | PreparedStatement, values: Any | None = None, *, none_as_unset: bool = False
+    ) -> None:
+        """
+        Append a statement with its bound values to the batch.
+
+        With `none_as_unset`, `None` values of this row are sent as unset instead of null,
+        leaving the columns untouched rather than writing tombstones. Individual values
+        can be left unset regardless of this flag by passing `scylla.types.Unset`.
+        """
+        ...
+    def add_all(
+        self, items: Sequence[tuple[str | Statement | PreparedStatement, Any | None]], *, none_as_unset: bool = False
+    ) -> None:
+        """
+        Append several statements with their bound values, as with `add`.
+        """
+        ...
     @property
     def type(self) -> BatchType: ...
     def with_execution_profile(self, profile: ExecutionProfile) -> Batch: ...
     def without_execution_profile(self) -> Batch: ...
     @property
     def execution_profile(self) -> ExecutionProfile | None: ...
-    def with_consistency(self, c: Consistency) -> Batch: ...
+    def with_consistency(self, c: Consistency | str) -> Batch: ...
     def without_consistency(self) -> Batch: ...
     @property
     def consistency(self) -> Consistency | None: ...
@@ -40,7 +56,35 @@ class Batch:
     def without_serial_consistency(self) -> Batch: ...
     @property
     def serial_consistency(self) -> SerialConsistency | None | UnsetType: ...
-    def with_request_timeout(self, timeout: float | None) -> Batch: ...
+    def with_request_timeout(self, timeout: float | None) -> Batch:
+        """
+        Set the client-side request timeout, in seconds. `None` disables it.
+
+        See the "Timeouts" configuration topic for the server-side counterpart.
+        """
+        ...
     def without_request_timeout(self) -> Batch: ...
     @property
     def request_timeout(self) -> float | None | UnsetType: ...
+    def with_timestamp(self, timestamp: int) -> Batch:
+        """
+        Set the client-side write timestamp, in integer microseconds since the Unix epoch.
+
+        The value is sent as-is, without a round trip through `float`, so it
+        keeps full microsecond precision. It takes precedence over the
+        session's `TimestampGenerator`.
+        """
+        ...
+    def without_timestamp(self) -> Batch: ...
+    @property
+    def timestamp(self) -> int | None: ...
+    def with_is_idempotent(self, is_idempotent: bool) -> Batch:
+        """
+        Mark the batch as idempotent, i.e. safe to execute more than once with
+        the same effect. Writes are only retried for idempotent batches. If not
+        set, the session's default idempotence applies (see
+        `SessionBuilder.default_idempotence`).
+        """
+        ...
+    @property
+    def is_idempotent(self) -> bool: ...
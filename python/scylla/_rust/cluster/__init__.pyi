@@ -4,14 +4,19 @@ from ipaddress import IPv4Address, IPv6Address
 from typing import Mapping
 from uuid import UUID
 
-from ..routing import ReplicaLocator, Shard, Token
+from ..routing import ReplicaLocator, Shard, Sharder, Token
 from .metadata import Keyspace
 
 class Node:
     """
     Represents a node in the cluster.
+
+    Nodes are compared and hashed by their host ID, so `(Node, Shard)` replica
+    tuples, also taken from different `ClusterState` snapshots, can be deduplicated in a set.
     """
 
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
     @property
     def host_id(self) -> UUID:
         """
@@ -43,6 +48,12 @@ class Node:
         """
         ...
     @property
+    def sharder(self) -> Sharder | None:
+        """
+        Access the sharder of the node, or `None` if the node is not sharded.
+        """
+        ...
+    @property
     def connected(self) -> bool:
         """
         Is `True` if the driver has a connection open to this node,
@@ -69,9 +80,14 @@ class ClusterState:
     keyspaces, replica locator and token calculation.
     """
 
-    def get_keyspace(self, keyspace: str) -> Keyspace | None:
+    def get_keyspace(self, keyspace: str, *, normalize_case: bool = False) -> Keyspace | None:
         """
         Get the keyspace by name.
+
+        The name is matched as stored, so keyspaces created with a quoted
+        name, such as `"MyKeyspace"`, are found by that exact name. With
+        `normalize_case=True` the name is lowercased first, as CQL does
+        with unquoted identifiers.
         """
         ...
     @property
@@ -81,12 +97,44 @@ class ClusterState:
         keyspace name to Keyspace object.
         """
         ...
+    def keyspace_names(self) -> list[str]:
+        """
+        Get the names of all keyspaces of the cluster.
+
+        Cheaper than `keyspaces` when only the names are needed, as no
+        keyspace metadata is converted to Python objects.
+        """
+        ...
+    def all_tables(self) -> list[tuple[str, str]]:
+        """
+        Get `(keyspace, table)` pairs of all tables of the cluster, sorted.
+
+        Cheaper than iterating `keyspaces` and their `tables`, as no keyspace
+        or table metadata is converted to Python objects. Materialized views
+        are not included.
+        """
+        ...
     @property
     def nodes_info(self) -> Mapping[UUID, Node]:
         """
         Access the nodes of the cluster as a dict of host ID to Node objects.
         """
         ...
+    def size_summary(self) -> dict[str, int]:
+        """
+        Get node and datacenter counts of the cluster in one call.
+
+        Returns
+        -------
+        dict[str, int]
+            A dict with the following keys:
+
+            - `total_nodes` - number of all known nodes,
+            - `up_nodes` - number of nodes that are both `connected` and `enabled`,
+            - `datacenters` - number of distinct datacenters of the known nodes.
+              Nodes with an unknown datacenter are not counted.
+        """
+        ...
     def compute_token(self, keyspace: str, table: str, partition_key: object) -> Token:
         """
         Computes the token for a given keyspace, table and partition key.
@@ -104,6 +152,21 @@ class ClusterState:
         to be replicas for a given token.
         """
         ...
+    def get_token_range_endpoints(
+        self, keyspace: str, table: str, start: Token, end: Token
+    ) -> list[tuple[Token, Token, list[tuple[Node, Shard]]]]:
+        """
+        Returns the replicas of each part of the token range `(start, end]`.
+
+        The range is split at the tokens of the ring into `(part_start, part_end, replicas)`
+        tuples, in ring order, where `replicas` are the `[Node, Shard]` tuples owning all
+        tokens of the part. The range wraps around the end of the ring if `start >= end`,
+        so `start == end` covers the whole ring.
+
+        For tablet-based tables, tablet boundaries are not taken into account, so only
+        the replicas of the last token of each part are reported.
+        """
+        ...
     def get_endpoints(self, keyspace: str, table: str, partition_key: object) -> list[tuple[Node, Shard]]:
         """
         Returns a list of `[Node, Shard]` tuples that are replicas owning the partition key.
@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 from enum import IntEnum
-from typing import Mapping
+from typing import Mapping, Sequence
 
 class StrategyKind(IntEnum):
     Simple = ...
@@ -80,28 +80,33 @@ class CqlList(CqlCollectionType):
     """CqlList<T> — ordered sequence of elements."""
 
     column_type: CqlColumnType
+    def __init__(self, column_type: CqlColumnType, *, frozen: bool = False) -> None: ...
 
 class CqlMap(CqlCollectionType):
     """CqlMap<K, V> — key-value pairs."""
 
     key_type: CqlColumnType
     value_type: CqlColumnType
+    def __init__(self, key_type: CqlColumnType, value_type: CqlColumnType, *, frozen: bool = False) -> None: ...
 
 class CqlSet(CqlCollectionType):
     """CqlSet<T> — unordered set of elements."""
 
     column_type: CqlColumnType
+    def __init__(self, column_type: CqlColumnType, *, frozen: bool = False) -> None: ...
 
 class CqlTuple(CqlColumnType):
     """CqlTuple<T1, T2, ...> — positional tuple of column types."""
 
     element_types: list[CqlColumnType]
+    def __init__(self, element_types: Sequence[CqlColumnType]) -> None: ...
 
 class CqlVector(CqlColumnType):
     """CqlVector<T, N> — fixed-length vector of elements."""
 
     typ: CqlColumnType
     dimensions: int
+    def __init__(self, typ: CqlColumnType, dimensions: int) -> None: ...
 
 class CqlUserDefinedType(CqlColumnType):
     """CQL user-defined type (UDT) — custom type with named fields."""
@@ -110,6 +115,21 @@ class CqlUserDefinedType(CqlColumnType):
     frozen: bool
     keyspace: str
     field_types: list[tuple[str, CqlColumnType]]
+    def __init__(
+        self,
+        name: str,
+        keyspace: str,
+        field_types: Sequence[tuple[str, CqlColumnType]],
+        *,
+        frozen: bool = False,
+    ) -> None: ...
+    def export_as_string(self) -> str:
+        """
+        Render this type as a `CREATE TYPE` statement.
+
+        Fields are listed in their declaration order.
+        """
+        ...
 
 class ColumnKind(IntEnum):
     Regular = ...
@@ -119,6 +139,12 @@ class ColumnKind(IntEnum):
 
 class Column:
     @property
+    def name(self) -> str:
+        """
+        Access the name of this column.
+        """
+        ...
+    @property
     def typ(self) -> CqlColumnType:
         """
         Access the type of this column.
@@ -130,6 +156,16 @@ class Column:
         Access the kind of this column.
         """
         ...
+    @property
+    def position(self) -> int | None:
+        """
+        Access the index of this column within the partition key or the
+        clustering key, depending on its `kind`.
+
+        For example, in `PRIMARY KEY ((a, b), c, d)` the positions of `a`, `b`,
+        `c` and `d` are 0, 1, 0 and 1. `None` for regular and static columns.
+        """
+        ...
     def __repr__(self) -> str: ...
 
 class Table:
@@ -157,6 +193,30 @@ class Table:
         Access the name of partitioner used by this table or None.
         """
         ...
+    def has_column(self, name: str, *, normalize_case: bool = False) -> bool:
+        """
+        Check whether this table has a column with the given name.
+
+        The name is lowercased first only if `normalize_case` is True, as for `ClusterState.get_keyspace`.
+        """
+        ...
+    def column(self, name: str, *, normalize_case: bool = False) -> Column | None:
+        """
+        Get the column with the given name, or None if there is no such column.
+
+        The name is lowercased first only if `normalize_case` is True, as for `ClusterState.get_keyspace`.
+        """
+        ...
+    def export_as_string(self) -> str:
+        """
+        Render this table as a `CREATE TABLE` statement.
+
+        Partition key columns come first, then clustering columns, then the remaining
+        columns in alphabetical order. Clustering order and table options are not
+        included, as they are not part of the metadata fetched by the driver; use
+        `Session.export_schema` to include the clustering order.
+        """
+        ...
     def __repr__(self) -> str: ...
 
 class MaterializedView:
@@ -199,10 +259,29 @@ class Keyspace:
         Access the tables of this keyspace as a read-only dictionary of name to table.
         """
         ...
+    def has_table(self, name: str, *, normalize_case: bool = False) -> bool:
+        """
+        Check whether this keyspace has a table with the given name,
+        without building the `tables` mapping.
+
+        The name is lowercased first only if `normalize_case` is True, as for `ClusterState.get_keyspace`.
+        """
+        ...
     @property
     def views(self) -> Mapping[str, MaterializedView]:
         """
         Access the materialized views of this keyspace as a read-only dictionary of name to view.
         """
         ...
+    def export_as_string(self) -> str:
+        """
+        Render this keyspace as CQL statements that recreate its schema.
+
+        The result contains the `CREATE KEYSPACE` statement, followed by `CREATE TYPE`
+        statements for user-defined types (each after the types it depends on) and
+        `CREATE TABLE` statements for tables. Statements are separated by blank lines.
+        Materialized views are not included, and neither is the clustering order of
+        tables, see `Table.export_as_string`.
+        """
+        ...
     def __repr__(self) -> str: ...
@@ -15,6 +15,29 @@ class Consistency(IntEnum):
     LocalOne = ...
     Serial = ...
     LocalSerial = ...
+    @classmethod
+    def from_string(cls, name: str) -> Consistency:
+        """
+        Look up a consistency level by name.
+
+        The lookup ignores case and underscores, so `"LOCAL_QUORUM"`,
+        `"local_quorum"` and `"LocalQuorum"` all return `Consistency.LocalQuorum`.
+
+        Parameters
+        ----------
+        name : str
+            Name of the consistency level.
+
+        Returns
+        -------
+        Consistency
+
+        Raises
+        ------
+        ValueError
+            If `name` does not name a consistency level.
+        """
+        ...
 
 class SerialConsistency(IntEnum):
     Serial = ...
@@ -24,6 +47,20 @@ class Compression(IntEnum):
     Lz4 = ...
     Snappy = ...
 
+class AllowFilteringPolicy(IntEnum):
+    """
+    What a session does when an executed statement contains `ALLOW FILTERING`.
+
+    See `SessionBuilder.allow_filtering_policy`.
+    """
+
+    Off = ...
+    """Statements are not checked."""
+    Warn = ...
+    """A warning is logged and the statement is executed."""
+    Error = ...
+    """`ExecuteError` is raised and the statement is not sent."""
+
 class PoolSize:
     @staticmethod
     def per_host(connections: int) -> PoolSize:
@@ -1,7 +1,13 @@
 from __future__ import annotations
 
+from typing import Literal
+
+from .enums import Consistency
+
 class ScyllaError(Exception): ...
 class RowIterationError(ScyllaError): ...
+class LwtResultError(ScyllaError): ...
+class ColumnTypeMismatchError(ScyllaError): ...
 
 class DeserializationError(ScyllaError):
     column_name: str | None
@@ -15,8 +21,54 @@ class SessionConnectionError(ScyllaError): ...
 class SessionConfigError(ScyllaError): ...
 class StatementConversionError(ScyllaError): ...
 class PrepareError(ScyllaError): ...
+
+class InvalidStatementError(PrepareError):
+    """
+    The server rejected the statement as invalid while preparing it.
+
+    `reason` has the same meaning as in `InvalidQueryError`.
+    """
+
+    reason: Literal["table_not_found", "keyspace_not_found"] | None
+
 class ExecuteError(ScyllaError): ...
+
+class WriteTimeoutError(ExecuteError):
+    """
+    The coordinator timed out waiting for replicas to acknowledge a write.
+
+    `write_type` tells which kind of write timed out, e.g. ``"SIMPLE"``, ``"BATCH"``,
+    ``"UNLOGGED_BATCH"``, ``"COUNTER"``, ``"BATCH_LOG"``, ``"CAS"``, ``"VIEW"`` or ``"CDC"``.
+    Counter writes are not idempotent and should not be retried blindly.
+    """
+
+    write_type: str
+    consistency: Consistency
+    received: int
+    required: int
+
+class InvalidQueryError(ExecuteError):
+    """
+    The server rejected the request as invalid. Also raised when an
+    unprepared statement executed with values is rejected while it is
+    implicitly prepared.
+
+    `reason` is ``"table_not_found"`` or ``"keyspace_not_found"`` if the table
+    or keyspace used by the request does not exist, and `None` otherwise. The
+    server reports these cases only in the error message, so `reason` is
+    derived from its wording in ScyllaDB and Cassandra.
+    """
+
+    reason: Literal["table_not_found", "keyspace_not_found"] | None
+
 class SchemaAgreementError(ScyllaError): ...
+
+class TracingError(ScyllaError):
+    """
+    Raised when the tracing info of a request cannot be read, e.g. because
+    it was not yet written to `system_traces` after the retries elapsed.
+    """
+
 class StatementConfigError(ScyllaError): ...
 class BatchError(ScyllaError): ...
 
@@ -1,11 +1,14 @@
 from .enums import Consistency, SerialConsistency
+from .policies import LoadBalancingPolicy, RetryPolicy
 
 class ExecutionProfile:
     def __init__(
         self,
         timeout: float | None = 30.0,
-        consistency: Consistency = Consistency.LocalQuorum,
+        consistency: Consistency | str = Consistency.LocalQuorum,
         serial_consistency: SerialConsistency | None = SerialConsistency.LocalSerial,
+        load_balancing_policy: LoadBalancingPolicy | None = None,
+        retry_policy: RetryPolicy | None = None,
     ) -> None: ...
     @property
     def request_timeout(self) -> float | None: ...
@@ -13,3 +16,9 @@ class ExecutionProfile:
     def consistency(self) -> Consistency: ...
     @property
     def serial_consistency(self) -> SerialConsistency | None: ...
+    @property
+    def load_balancing_policy(self) -> LoadBalancingPolicy:
+        """
+        The profile's load balancing policy, or the driver's default policy if none was given.
+        """
+        ...
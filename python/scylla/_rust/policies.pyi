@@ -127,3 +127,77 @@ class HostFilter:
         accepting the host.
         """
         ...
+
+class LoadBalancingPolicy:
+    """
+    Load balancing policy deciding which nodes serve a request.
+
+    Can be set on an `ExecutionProfile`, or on a single `Statement` or
+    `PreparedStatement` to override the profile's policy.
+    """
+
+    def __init__(
+        self,
+        *,
+        preferred_datacenter: str | None = None,
+        token_aware: bool = True,
+        permit_dc_failover: bool = False,
+    ) -> None:
+        """
+        Parameters
+        ----------
+        preferred_datacenter : str | None, optional
+            Datacenter whose nodes are tried first. Default is None (no preference).
+        token_aware : bool, optional
+            Route requests to replicas owning the partition key. Default is True.
+        permit_dc_failover : bool, optional
+            Allow falling back to nodes outside of `preferred_datacenter`. Default is False.
+        """
+        ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool:
+        """
+        Policies are equal if they are the same policy, e.g. the one passed to
+        an `ExecutionProfile` and the one returned by its `load_balancing_policy`.
+        """
+        ...
+    def __hash__(self) -> int: ...
+
+class RetryPolicy:
+    """
+    Base class of the retry policies, which decide whether and how a failed
+    request attempt is retried. Can be set on an `ExecutionProfile`.
+    """
+
+    def __repr__(self) -> str: ...
+
+class DefaultRetryPolicy(RetryPolicy):
+    """
+    The policy used when no other is set.
+
+    Retries on another node or the same node when that is likely to succeed,
+    always at the original consistency level. Writes are retried only if
+    the statement is idempotent.
+    """
+
+    def __init__(self) -> None: ...
+
+class DowngradingConsistencyRetryPolicy(RetryPolicy):
+    """
+    Retries a request that failed because too few replicas were alive or
+    responded, at the highest consistency level those replicas can satisfy
+    (``THREE``, ``TWO`` or ``ONE``).
+
+    A request is downgraded at most once. Writes are retried only if the
+    statement is idempotent.
+
+    Warning
+    -------
+    A downgraded request succeeds with a weaker guarantee than requested,
+    without raising. A read may return stale data, and a write acknowledged
+    by fewer replicas may be lost or not visible to later reads at the
+    original consistency level. Use it only where availability matters more
+    than consistency.
+    """
+
+    def __init__(self) -> None: ...
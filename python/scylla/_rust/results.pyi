@@ -1,11 +1,15 @@
 import ipaddress
+from collections import OrderedDict
 from datetime import date, datetime, time
 from decimal import Decimal
 from typing import Any, AsyncIterator, Dict, List, Set, Tuple, Union
 from uuid import UUID
 
+import pandas
 from dateutil.relativedelta import relativedelta
 
+from .cluster.metadata import CqlColumnType
+
 CqlNative = Union[
     # CQL:
     # - Counter
@@ -97,15 +101,127 @@ class RowFactory:
     Factory used to construct a row object from a column iterator.
 
     Allows custom row representations (e.g. dicts, dataclasses).
+
+    Subclasses can change how some CQL types are represented by overriding
+    the following attributes, either on the class or on the instance:
+
+    - `time_as_nanoseconds` - return CQL `time` values as an `int` of
+      nanoseconds since midnight instead of `datetime.time`, which has only
+      microsecond precision.
+    - `date_as_days` - return CQL `date` values as an `int` of days since the
+      Unix epoch instead of `datetime.date`, which cannot represent dates
+      outside of years 1-9999.
+    - `null_text_as_empty` - return null CQL `ascii` and `text` column values
+      as `""` instead of `None`. CQL keeps empty and null text distinct, so
+      this makes them indistinguishable in the returned rows.
+
+    All of them apply to nested values as well, except `null_text_as_empty`,
+    which, like `SessionBuilder.empty_string_as_null`, leaves fields of tuples
+    and UDTs as they are.
+
+    Every `Column` yielded by the iterator carries its CQL type in
+    `Column.column_type`.
     """
 
+    time_as_nanoseconds: bool
+    date_as_days: bool
+    null_text_as_empty: bool
     def __init__(self, *args: Any, **kwargs: Any) -> None: ...
     def build(self, column_iterator: ColumnIterator) -> Dict[str, CqlValue]:
         """
         Build a row object from the provided column iterator.
+
+        The default implementation returns a `dict` with keys inserted in the
+        order of the columns in the result.
+        """
+        ...
+
+class TypedRowFactory(RowFactory):
+    """
+    Row factory producing `TypedRow` objects instead of dicts.
+
+    Opt-in: the default dict rows stay the fastest way to read results.
+    """
+
+    def __init__(self) -> None: ...
+    def build(self, column_iterator: ColumnIterator) -> TypedRow:  # pyright: ignore[reportIncompatibleMethodOverride]
+        """
+        Build a `TypedRow` from the provided column iterator.
+        """
+        ...
+
+class OrderedDictRowFactory(RowFactory):
+    """
+    Row factory producing `collections.OrderedDict` rows, in column order.
+
+    Default dict rows keep the column order as well; use this for code relying
+    on `OrderedDict` semantics, such as order-sensitive equality.
+    """
+
+    def __init__(self) -> None: ...
+    def build(self, column_iterator: ColumnIterator) -> OrderedDict[str, CqlValue]:
+        """
+        Build an `OrderedDict` from the provided column iterator.
+        """
+        ...
+
+class RawBytesRowFactory(RowFactory):
+    """
+    Row factory producing `dict` rows of raw column bytes, without deserialization.
+
+    Values are in the CQL wire representation of their column type; nulls are `None`.
+    """
+
+    def __init__(self) -> None: ...
+    def build(self, column_iterator: ColumnIterator) -> dict[str, bytes | None]:
+        """
+        Build a `dict` mapping column names to raw bytes from the provided column iterator.
         """
         ...
 
+class TypedRow:
+    """
+    A result row with typed accessors.
+
+    Each `get_*` method checks that the CQL type of the column matches the
+    requested Python type, which catches schema drift early. Null values are
+    returned as `None` regardless of the getter.
+
+    Raises
+    ------
+    KeyError
+        If the row has no column with the given name.
+    ColumnTypeMismatchError
+        If the CQL type of the column does not match the getter.
+    """
+
+    def get_int(self, name: str) -> int | None:
+        """Value of a `tinyint`, `smallint`, `int`, `bigint`, `varint` or `counter` column."""
+        ...
+    def get_float(self, name: str) -> float | None:
+        """Value of a `float` or `double` column."""
+        ...
+    def get_str(self, name: str) -> str | None:
+        """Value of a `text` or `ascii` column."""
+        ...
+    def get_bool(self, name: str) -> bool | None:
+        """Value of a `boolean` column."""
+        ...
+    def get_bytes(self, name: str) -> bytes | None:
+        """Value of a `blob` column."""
+        ...
+    def get_uuid(self, name: str) -> UUID | None:
+        """Value of a `uuid` or `timeuuid` column."""
+        ...
+    def __getitem__(self, name: str) -> CqlValue:
+        """Value of a column, without any type check."""
+        ...
+    def __len__(self) -> int: ...
+    def __contains__(self, name: str) -> bool: ...
+    def as_dict(self) -> Dict[str, CqlValue]:
+        """Convert the row into a dict mapping column names to values."""
+        ...
+
 class Column:
     """
     Represents a single column in a result row.
@@ -120,6 +236,19 @@ class Column:
         """Deserialized value of the column."""
         ...
 
+    @property
+    def column_type(self) -> CqlColumnType:
+        """
+        CQL type of the column, as read from the result metadata.
+
+        Collection, tuple, vector and UDT types carry the types of their
+        elements (e.g. `CqlList.column_type`, `CqlMap.key_type`), so a row
+        factory can decide how to build nested containers. The types are the
+        same `CqlColumnType` objects as in the cluster schema metadata, and
+        are shared by all rows of a page.
+        """
+        ...
+
 class SinglePageIterator:
     """
     Iterates over rows in a single page of query results.
@@ -148,9 +277,9 @@ class PagingState:
         """
         Returns the inner representation of `PagingState` as bytes.
 
-        Use this to store paging state for a longer time, and later restore it
-        using `from_bytes()`. Returns `None` if this represents the start state
-        (no previous page).
+        These are the opaque bytes sent by the server. To store paging state
+        outside the process, use `to_bytes()` instead. Returns `None` if this
+        represents the start state (no previous page).
 
         Returns
         -------
@@ -159,23 +288,43 @@ class PagingState:
         """
         ...
 
-    @staticmethod
-    def from_bytes(raw_bytes: bytes) -> PagingState:
+    def to_bytes(self) -> bytes:
         """
-        Creates `PagingState` from raw bytes.
+        Serializes the paging state, including the start state, into a
+        self-describing, checksummed byte string.
 
-        Use this to restore paging state after longer time, having previously
-        stored it using `as_bytes()`.
+        The result can be stored outside the process (e.g. in a cookie or URL,
+        after encoding it as base64) and restored with `from_bytes()`.
+        It includes a hash of the statement the state was obtained for, so
+        executing another statement with the restored state raises `ExecuteError`.
+
+        Returns
+        -------
+        bytes
+            Serialized paging state.
+        """
+        ...
+
+    @staticmethod
+    def from_bytes(data: bytes) -> PagingState:
+        """
+        Restores `PagingState` from bytes produced by `to_bytes()`.
 
         Parameters
         ----------
-        raw_bytes : bytes
-            Raw paging state bytes previously obtained from `as_bytes()`.
+        data : bytes
+            Bytes previously obtained from `to_bytes()`.
 
         Returns
         -------
         PagingState
-            A new `PagingState` restored from the raw bytes.
+            The restored paging state.
+
+        Raises
+        ------
+        ValueError
+            If the bytes were not produced by `to_bytes()`, come from an
+            incompatible format version or were corrupted.
         """
         ...
 
@@ -192,6 +341,43 @@ class RequestResult:
         """
         ...
 
+    def __bool__(self) -> bool:
+        """
+        Returns True if the result holds any rows.
+
+        The row count is read from the result metadata, so no rows are consumed.
+
+        Raises
+        ------
+        TypeError
+            If the current page is empty but more pages remain to be fetched.
+            The server may return empty pages before the ones holding rows,
+            e.g. with ``ALLOW FILTERING``, so use `first_row()` instead.
+        """
+        ...
+
+    def __len__(self) -> int:
+        """
+        Returns the number of rows, once the whole result is in memory.
+
+        The total is only known when there are no more pages to fetch. For a
+        paged result, materialize the rows with `all()` and take its length.
+
+        Raises
+        ------
+        TypeError
+            If more pages remain to be fetched.
+        """
+        ...
+
+    @property
+    def tracing_id(self) -> UUID | None:
+        """
+        Tracing ID of the request if it was executed with tracing
+        (see `Statement.with_tracing`), otherwise `None`.
+        """
+        ...
+
     def paging_state(self) -> PagingState | None:
         """
         Returns current paging state. Can be `None` if there are no more pages available.
@@ -209,6 +395,12 @@ class RequestResult:
         -------
         RequestResult | None
             A new RequestResult with the next page data, or None if no more pages.
+
+        Raises
+        ------
+        ExecuteError
+            If fetching the page fails or the coroutine is awaited without a
+            running asyncio event loop.
         """
         ...
 
@@ -245,6 +437,79 @@ class RequestResult:
         """
         ...
 
+    async def to_pandas(self) -> pandas.DataFrame:
+        """
+        Return all rows of the result set as a `pandas.DataFrame`.
+
+        Like `all`, this fetches all remaining pages. Rows are read as with
+        the default row factory, whatever the factory of the request. Column
+        dtypes follow the CQL types, with nulls as pandas missing values:
+
+        - `tinyint`, `smallint`, `int`, `bigint`, `counter`: ``Int8`` to ``Int64``
+        - `float`, `double`: ``Float32``, ``Float64``
+        - `boolean`: ``boolean``
+        - `text`, `ascii`: ``string``
+        - `timestamp`: ``datetime64[ms, UTC]``
+
+        Columns of other types have the ``object`` dtype and hold the same
+        Python objects as rows returned by `all`, with `None` for nulls.
+
+        Raises
+        ------
+        ImportError
+            If pandas is not installed.
+        """
+        ...
+
+    def lwt_result(self) -> LwtResult:
+        """
+        Decode the result of a conditional (LWT) statement.
+
+        Reads the first row of the current page and splits it into the
+        `[applied]` flag and the remaining columns. When the condition was
+        not met, the remaining columns hold the current values of the row.
+
+        Returns
+        -------
+        LwtResult
+            The applied flag and the remaining columns of the row.
+
+        Raises
+        ------
+        LwtResultError
+            If the result has no rows or no boolean `[applied]` column.
+        """
+        ...
+
+class LwtResult:
+    """
+    Decoded result of a conditional (LWT) statement.
+
+    Truthy if and only if the statement was applied.
+    """
+
+    @property
+    def applied(self) -> bool:
+        """Whether the condition was met and the statement applied."""
+        ...
+
+    @property
+    def current_row(self) -> Dict[str, CqlValue]:
+        """
+        Values of the row returned by the server, without the `[applied]`
+        column. If the statement was not applied, these are the current
+        values of the row.
+
+        If the statement was applied, the contents depend on the server:
+        Cassandra returns only `[applied]`, so the dictionary is empty, while
+        ScyllaDB also returns the columns of the row, with `None` values.
+        Only rely on the values when `applied` is False.
+        """
+        ...
+
+    def __bool__(self) -> bool: ...
+    def __repr__(self) -> str: ...
+
 class AsyncRowsIterator(AsyncIterator[Any]):
     """
     Async iterator over rows with automatic paging.
@@ -255,3 +520,18 @@ class AsyncRowsIterator(AsyncIterator[Any]):
 
     def __aiter__(self) -> AsyncRowsIterator: ...
     async def __anext__(self) -> Any: ...
+    def paging_state(self) -> PagingState | None:
+        """
+        Returns the paging state of the page following the one being iterated.
+
+        Passing it to `Session.execute` resumes from the next page, so it is a
+        checkpoint once all rows of the current page were consumed: rows of the
+        current page not yielded yet are skipped when resuming. `None` once
+        the last page was fetched, in particular for a fully consumed iterator.
+
+        Raises
+        ------
+        RuntimeError
+            If called while `__anext__` is fetching a row.
+        """
+        ...
@@ -11,14 +11,58 @@ Shard: TypeAlias = int
 class Token:
     """
     Token is a result of computing a hash of a primary key.
+
+    Tokens are ordered by their signed 64-bit value, from the lowest
+    (`-2**63`) to the highest (`2**63 - 1`) position on the token ring.
+    The ordering does not wrap around: the ring's wraparound point lies
+    between the highest and the lowest token.
     """
     def __init__(self, value: int) -> None: ...
     @property
     def value(self) -> int: ...
     def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: Token) -> bool: ...
+    def __le__(self, other: Token) -> bool: ...
+    def __gt__(self, other: Token) -> bool: ...
+    def __ge__(self, other: Token) -> bool: ...
     def __hash__(self) -> int: ...
     def __repr__(self) -> str: ...
 
+class Sharder:
+    """
+    Maps tokens and source ports to shards of a Scylla node.
+
+    The sharder of a node is available as `Node.sharder`.
+    """
+    def __init__(self, nr_shards: int, msb_ignore: int) -> None:
+        """
+        Raises `ValueError` if `nr_shards` is 0 or `msb_ignore` is not less than 64.
+        """
+        ...
+    @property
+    def nr_shards(self) -> int: ...
+    @property
+    def msb_ignore(self) -> int: ...
+    def shard_of(self, token: Token) -> Shard:
+        """
+        Returns the shard owning the token.
+        """
+        ...
+    def shard_of_source_port(self, port: int) -> Shard:
+        """
+        Returns the shard that a connection from the local source port is routed to.
+        """
+        ...
+    def shard_of_source_ports(self, ports: list[int]) -> list[Shard]:
+        """
+        Returns the shards of many source ports in one call.
+
+        Equivalent to calling `shard_of_source_port` for every port, in the same order.
+        """
+        ...
+    def __eq__(self, other: object) -> bool: ...
+    def __repr__(self) -> str: ...
+
 class ReplicaLocator:
     """
     `ReplicaLocator` provides a way to find the set of owning nodes
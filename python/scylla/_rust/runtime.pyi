@@ -0,0 +1,28 @@
+def configure_runtime(*, worker_threads: int | None = None) -> None:
+    """
+    Configure the runtime that executes all requests of the driver.
+
+    The driver runs a single, process-wide multi-threaded Tokio runtime, which
+    is created the first time it is needed (usually by the first `connect`).
+    This function only has an effect when called before that point; afterwards
+    it does nothing and logs a warning.
+
+    Parameters
+    ----------
+    worker_threads : int | None, optional
+        Number of worker threads of the runtime. If None, the number of CPU
+        cores is used. Default is None.
+
+    Raises
+    ------
+    ValueError
+        If `worker_threads` is 0.
+    """
+    ...
+
+def runtime_initialized() -> bool:
+    """
+    Return `True` if the runtime has already been created, in which case
+    `configure_runtime` has no effect.
+    """
+    ...
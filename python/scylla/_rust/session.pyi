@@ -1,10 +1,82 @@
 import uuid
+from collections.abc import Callable, Sequence
+from datetime import timedelta
 from typing import Any
 
 from .batch import Batch
 from .cluster import ClusterState
 from .results import PagingState, RequestResult, RowFactory
+from .routing import Token
 from .statement import PreparedStatement, Statement
+from .tracing import TracingInfo
+
+class ProtocolFeatures:
+    """
+    Protocol-level features in use by a `Session`.
+
+    The Rust driver does not expose the protocol version or the compression
+    negotiated on each connection, so they are not reported.
+    """
+
+    @property
+    def shard_aware(self) -> bool:
+        """
+        `True` if at least one known node advertised Scylla sharding information,
+        which means connections to it are shard-aware.
+        """
+        ...
+    def __repr__(self) -> str: ...
+
+class InsertManyResult:
+    """
+    Outcome of `Session.insert_many`.
+    """
+
+    @property
+    def succeeded(self) -> int:
+        """
+        Number of rows that were inserted successfully.
+        """
+        ...
+    @property
+    def failed(self) -> int:
+        """
+        Number of rows that were not inserted.
+        """
+        ...
+    @property
+    def failures(self) -> list[tuple[int, Exception]]:
+        """
+        `(index, error)` pairs of the rows that were not inserted, ordered by
+        the row's index in the `rows` passed to `insert_many`.
+        """
+        ...
+    def __repr__(self) -> str: ...
+
+class SizeEstimate:
+    """
+    Estimated size of a token range of a table, read from `system.size_estimates`.
+
+    The range is `(range_start, range_end]`.
+    """
+
+    @property
+    def range_start(self) -> Token: ...
+    @property
+    def range_end(self) -> Token: ...
+    @property
+    def mean_partition_size(self) -> int:
+        """
+        Mean size of a partition in the range, in bytes.
+        """
+        ...
+    @property
+    def partitions_count(self) -> int:
+        """
+        Estimated number of partitions in the range.
+        """
+        ...
+    def __repr__(self) -> str: ...
 
 class Session:
     """
@@ -15,6 +87,134 @@ class Session:
     def cluster_state(self) -> ClusterState:
         """
         Access information about the cluster topology or schema through ClusterState object.
+
+        After `shutdown`, the last known state is returned.
+        """
+        ...
+    def shutdown(self) -> None:
+        """
+        Releases the session.
+
+        Requests made afterwards raise the error of the request type, e.g.
+        `ExecuteError`, including fetching further pages of results. Requests
+        already in flight are completed; the connections are closed once they
+        finish. Calling it again has no effect.
+        """
+        ...
+    @property
+    def is_shut_down(self) -> bool:
+        """
+        `True` once `shutdown` was called.
+        """
+        ...
+    def __enter__(self) -> Session:
+        """
+        Returns the session, so it can be used as ``with session: ...``.
+        """
+        ...
+    def __exit__(self, exc_type: Any, exc_value: Any, traceback: Any) -> bool:
+        """
+        Calls `shutdown`. Exceptions raised in the ``with`` block are propagated.
+        """
+        ...
+    def default_request_timeout(self) -> float | None:
+        """
+        Returns the client-side request timeout of the session's default
+        execution profile in seconds, or `None` if requests do not time out.
+
+        Statements and execution profiles that set their own timeout
+        override this default.
+        """
+        ...
+    def protocol_features(self) -> ProtocolFeatures:
+        """
+        Returns notable protocol options in use by this session.
+
+        The Rust driver does not expose the protocol version or compression
+        negotiated on each connection, so they are not reported.
+        """
+        ...
+    async def size_estimates(self, keyspace: str, table: str) -> list[SizeEstimate]:
+        """
+        Returns the per-range size estimates of a table from `system.size_estimates`.
+
+        The estimates are computed periodically by the server and each node
+        reports only the ranges it owns, so the result reflects the node that
+        served the request. An empty list is returned if the estimates were
+        not computed yet, e.g. for a newly created table.
+
+        Raises
+        ------
+        ExecuteError
+            If the system table query fails.
+        """
+        ...
+    async def export_schema(self, keyspace: str, table: str | None = None) -> str | None:
+        """
+        Render a keyspace, or one of its tables, as CQL statements that recreate its schema.
+
+        The result is the same as `Keyspace.export_as_string` or `Table.export_as_string`
+        of the current `cluster_state`, except that every `CREATE TABLE` statement
+        with clustering columns ends with a ``WITH CLUSTERING ORDER BY`` clause.
+        The clustering order is not part of the driver's schema metadata, so it is
+        read from `system_schema.columns`.
+
+        Parameters
+        ----------
+        keyspace : str
+            Name of the keyspace, as stored (case-sensitive).
+        table : str, optional
+            Name of the table to render, as stored. By default the whole keyspace is rendered.
+
+        Returns
+        -------
+        str | None
+            The statements, or None if the keyspace or the table is not in the schema metadata.
+
+        Raises
+        ------
+        ExecuteError
+            If the system table query fails.
+        """
+        ...
+    async def server_version(self) -> str:
+        """
+        Returns the release version reported by the server, e.g. `"3.0.8"`.
+
+        Read from `system.local` of the node that served the request on the
+        first call and cached for the lifetime of the session.
+
+        Raises
+        ------
+        ExecuteError
+            If the system table query fails.
+        """
+        ...
+    async def supported_features(self) -> dict[str, Any]:
+        """
+        Returns versions and features advertised by the server, for gating
+        the use of features that depend on the server version.
+
+        The returned dictionary contains:
+
+        - `release_version` : str, as returned by `server_version()`,
+        - `cql_version` : str | None, version of CQL supported by the server,
+        - `native_protocol_version` : str | None, highest protocol version supported by the server,
+        - `shard_aware` : bool, whether the ScyllaDB shard-awareness extension is in use,
+        - `features` : list[str], ScyllaDB cluster features (empty for other servers).
+
+        The versions and cluster features come from `system.local`. They are read on
+        the first call and cached for the lifetime of the session, except `shard_aware`,
+        which reflects the current cluster state.
+
+        The protocol extensions a node advertises in its SUPPORTED response, such as
+        ScyllaDB's `SCYLLA_*` options, are not available: the Rust driver reads them
+        when opening a connection but does not expose them.
+
+        Raises
+        ------
+        ExecuteError
+            If the system table query fails.
         """
         ...
     async def use_keyspace(self, keyspace: str, case_sensitive: bool = False) -> None:
@@ -25,6 +225,10 @@ class Session:
         Note that even failed `use_keyspace` can change currently used keyspace - the request is sent on all connections and
         can overwrite previously used keyspace.
 
+        Unless `case_sensitive` is True, the name is lowercased, as CQL does
+        with unquoted identifiers. Keyspaces created with a quoted name, such
+        as `"MyKeyspace"`, need `case_sensitive=True`.
+
         Raises
         ------
         UseKeyspaceError
@@ -35,6 +239,12 @@ class Session:
         """
         Prepare a statement for repeated execution.
 
+        The statement is prepared on every node the session is connected to,
+        so it can later be routed to any replica without a round trip to
+        prepare it first. This is always the case and cannot be configured.
+        If a node loses the statement, e.g. after a restart, the driver
+        re-prepares it transparently when the node reports it as unprepared.
+
         Parameters
         ----------
         statement : Statement | str
@@ -44,6 +254,26 @@ class Session:
         -------
         PreparedStatement
             A prepared statement ready for execution with parameters.
+
+        Raises
+        ------
+        PrepareError
+            If preparation fails or the coroutine is awaited without a running
+            asyncio event loop.
+        """
+        ...
+    def prepare_blocking(self, statement: Statement | str) -> PreparedStatement:
+        """
+        Blocking variant of `prepare`, for synchronous code.
+
+        Blocks the calling thread until the statement is prepared. The GIL is
+        released while waiting. Must not be called from a thread with a running
+        asyncio event loop; use `prepare` there instead.
+
+        Raises
+        ------
+        PrepareError
+            If preparation fails or an event loop is running in the current thread.
         """
         ...
 
@@ -56,6 +286,8 @@ class Session:
         factory: RowFactory | None = None,
         paging_state: PagingState | None = None,
         paged: bool = True,
+        max_rows: int | None = None,
+        retries: int = 0,
     ) -> RequestResult:
         """
         Execute a query and return results.
@@ -70,17 +302,163 @@ class Session:
             Row factory to use for constructing row objects. If None, uses default
             dictionary mapping. Default is None.
         paging_state : PagingState | None, optional
-            Paging state to resume from a previous query. Default is None.
+            Paging state to resume from a previous query of the same statement.
+            `ExecuteError` is raised for a state obtained for another statement.
+            Default is None.
         paged : bool, optional
             Enable automatic paging if True. Otherwise, all rows come in a single result frame,
             which is **strongly discouraged** for large (over thousands of rows) responses,
             and acceptable for responses containing few or no rows.
             Default is True.
+        max_rows : int | None, optional
+            Upper bound on the number of rows an unpaged query may return.
+            If the response contains more rows, `ExecuteError` is raised before
+            any row is converted to a Python object. Only allowed together with
+            ``paged=False``. Default is None (no limit).
+        retries : int, optional
+            How many times to retry the request if it fails with a timeout or an
+            `Unavailable` error, with a short, growing backoff between attempts.
+            Only allowed for idempotent statements, see `with_is_idempotent` and
+            `SessionBuilder.default_idempotence`.
+            With paging, applies to each page fetched. Default is 0.
 
         Returns
         -------
         RequestResult
             Query results with paging support.
+
+        Raises
+        ------
+        ExecuteError
+            If execution fails (after all retries, in which case the message
+            includes the number of attempts) or the coroutine is awaited without
+            a running asyncio event loop.
+        """
+        ...
+
+    async def prepare_and_execute(
+        self,
+        query: str,
+        values: Any | None = None,
+        /,
+        *,
+        factory: RowFactory | None = None,
+        paging_state: PagingState | None = None,
+        paged: bool = True,
+        max_rows: int | None = None,
+        retries: int = 0,
+    ) -> RequestResult:
+        """
+        Prepare a query and execute it, reusing the prepared statement on later calls.
+
+        The prepared statement is cached in the session, keyed by the query text,
+        so only the first call for a given query sends a prepare request. The
+        cache keeps the most recently used statements, up to
+        `SessionBuilder.prepared_statement_cache_size`.
+        Accepts the same keyword arguments as `execute`.
+
+        Parameters
+        ----------
+        query : str
+            The CQL query to prepare and execute.
+        values : Any | None, optional
+            Query parameters to bind to the statement. Default is None.
+
+        Returns
+        -------
+        RequestResult
+            Query results with paging support.
+
+        Raises
+        ------
+        PrepareError
+            If preparation fails, or the statement has to be prepared and the
+            coroutine is awaited without a running asyncio event loop.
+        ExecuteError
+            If execution fails or the coroutine is awaited without a running
+            asyncio event loop.
+        """
+        ...
+
+    async def execute_by_id(
+        self,
+        statement_id: bytes,
+        values: Any | None = None,
+        /,
+        *,
+        query: str | None = None,
+        factory: RowFactory | None = None,
+        paging_state: PagingState | None = None,
+        paged: bool = True,
+        max_rows: int | None = None,
+        retries: int = 0,
+    ) -> RequestResult:
+        """
+        Execute a statement prepared by this session, identified by its
+        `PreparedStatement.id`, without holding the `PreparedStatement` itself.
+
+        The session keeps the statements it prepared most recently, keyed by
+        id, in the cache sized by `SessionBuilder.prepared_statement_cache_size`.
+        If the id is unknown to the session, e.g. it was prepared by another
+        process or another session, or was evicted from the cache, `query` is
+        prepared instead, as with `prepare_and_execute`, and the new statement
+        is executed.
+        Accepts the same keyword arguments as `execute`.
+
+        Parameters
+        ----------
+        statement_id : bytes
+            Id of the prepared statement, as returned by `PreparedStatement.id`.
+        values : Any | None, optional
+            Query parameters to bind to the statement. Default is None.
+        query : str | None, optional
+            CQL text of the statement, prepared if `statement_id` is unknown.
+            Default is None.
+
+        Returns
+        -------
+        RequestResult
+            Query results with paging support.
+
+        Raises
+        ------
+        PrepareError
+            If `query` has to be prepared and preparation fails, or the
+            coroutine is awaited without a running asyncio event loop.
+        ExecuteError
+            If the id is unknown and no `query` is given, if execution fails or
+            the coroutine is awaited without a running asyncio event loop.
+        """
+        ...
+
+    def execute_blocking(
+        self,
+        statement: PreparedStatement | Statement | str,
+        values: Any | None = None,
+        /,
+        *,
+        factory: RowFactory | None = None,
+        paging_state: PagingState | None = None,
+        paged: bool = True,
+        max_rows: int | None = None,
+        retries: int = 0,
+    ) -> RequestResult:
+        """
+        Blocking variant of `execute`, for synchronous code.
+
+        Accepts the same arguments as `execute`. Blocks the calling thread until
+        the first page (or the whole unpaged result) arrives. The GIL is released
+        while waiting. Asynchronous iteration over further pages is not available
+        without an event loop; read the current page with `iter_current_page()` and
+        fetch the next one by passing `paging_state` to another `execute_blocking` call.
+
+        Must not be called from a thread with a running asyncio event loop;
+        use `execute` there instead.
+
+        Raises
+        ------
+        ExecuteError
+            If execution fails or an event loop is running in the current thread.
         """
         ...
 
@@ -110,6 +488,89 @@ class Session:
             In each returned row, columns other than `[applied]` contain either the current
             values of that row (if the condition was not met) or `None` values (if it was met).
 
+        Raises
+        ------
+        ExecuteError
+            If execution fails or the coroutine is awaited without a running
+            asyncio event loop.
+        """
+        ...
+
+    async def insert_many(
+        self,
+        prepared: PreparedStatement,
+        rows: Sequence[Any],
+        /,
+        *,
+        concurrency: int = 32,
+    ) -> InsertManyResult:
+        """
+        Execute a prepared statement once for every row of values.
+
+        Rows are sent concurrently, with at most `concurrency` requests in flight.
+        A row that fails does not stop the others, so this is best used with
+        idempotent inserts, whose failed rows can safely be retried.
+
+        Like `execute`, the statement is checked against the session's
+        `AllowFilteringPolicy`, and the request hook is notified of every row,
+        once all of them completed.
+
+        Parameters
+        ----------
+        prepared : PreparedStatement
+            The statement to execute, typically an `INSERT`.
+        rows : Sequence[Any]
+            Values to bind for each execution, in any form accepted by `execute`.
+        concurrency : int, optional
+            Maximum number of requests in flight at once. Must be positive.
+            Default is 32.
+
+        Returns
+        -------
+        InsertManyResult
+            The number of inserted rows and, for every failed row, its index
+            together with the `ExecuteError` it failed with.
+
+        Raises
+        ------
+        ExecuteError
+            If the coroutine is awaited without a running asyncio event loop,
+            or the statement is rejected by the session's `AllowFilteringPolicy`.
+        """
+        ...
+
+    def set_request_hook(self, hook: Callable[[str, float, bool], object] | None, /) -> None:
+        """
+        Set a callable notified after each request, or remove it.
+
+        The hook is called after every request made by `execute`,
+        `prepare_and_execute`, `execute_blocking`, `batch` and `insert_many`
+        with three arguments: the CQL text of the request (statements of a
+        batch are joined with ``"; "``), its duration in seconds, including
+        retries, and whether it succeeded. For paged requests, only the first
+        page is covered. `insert_many` reports each of its rows as a separate
+        request.
+
+        Requests rejected before being sent, e.g. by the session's
+        `AllowFilteringPolicy`, because of invalid arguments or because their
+        values cannot be serialized, are reported as failed as well. Preparing
+        a statement, explicitly or within `prepare_and_execute` and
+        `execute_by_id`, is not reported.
+
+        The hook runs after the request has completed, on the thread awaiting
+        it, so it should return quickly. Exceptions raised by the hook are
+        logged and ignored.
+
+        Parameters
+        ----------
+        hook : Callable[[str, float, bool], object] | None
+            The callable to notify, replacing the current one, or None to
+            remove the current one.
+
+        Raises
+        ------
+        TypeError
+            If `hook` is neither callable nor None.
         """
         ...
 
@@ -150,3 +611,47 @@ class Session:
             If the schema agreement check failed.
         """
         ...
+
+    async def get_tracing_info(self, tracing_id: uuid.UUID) -> TracingInfo:
+        """
+        Read the tracing info of a request executed with tracing enabled.
+
+        Parameters
+        ----------
+        tracing_id : uuid.UUID
+            The `tracing_id` of the request's result.
+
+        Returns
+        -------
+        TracingInfo
+            The tracing session with its events ordered by `source_elapsed`.
+
+        Raises
+        ------
+        TracingError
+            If the tracing info could not be read, e.g. because it was not
+            written to `system_traces` yet.
+        """
+        ...
+
+    async def wait_for_nodes(self, count: int, timeout: timedelta | float) -> None:
+        """
+        Wait until at least `count` nodes of the cluster are connected.
+
+        The cluster state is polled every 100 milliseconds. Only nodes that are both
+        connected and enabled by the host filter are counted. Useful in tests and
+        warm-up code that needs every node reachable before relying on routing.
+
+        Parameters
+        ----------
+        count : int
+            The number of connected nodes to wait for.
+        timeout : timedelta | float
+            How long to wait. If a ``float`` is provided, it is interpreted as seconds.
+
+        Raises
+        ------
+        RuntimeError
+            If fewer than `count` nodes are connected when the timeout elapses.
+        """
+        ...
@@ -5,7 +5,15 @@ from datetime import timedelta
 from ipaddress import IPv4Address, IPv6Address
 from typing import Any, Optional
 
-from .enums import Compression, PoolSize, SelfIdentity, WriteCoalescingDelay
+from .enums import (
+    AllowFilteringPolicy,
+    Compression,
+    Consistency,
+    PoolSize,
+    SelfIdentity,
+    SerialConsistency,
+    WriteCoalescingDelay,
+)
 from .execution_profile import ExecutionProfile
 from .policies import AddressTranslator, AuthenticatorProvider, HostFilter, TimestampGenerator
 from .session import Session
@@ -35,6 +43,18 @@ class SessionBuilderConfig:
     @property
     def shard_aware_local_port_range(self) -> tuple[int, int]: ...
     @property
+    def default_idempotence(self) -> bool: ...
+    @property
+    def prepared_statement_cache_size(self) -> int: ...
+    @property
+    def empty_string_as_null(self) -> bool: ...
+    @property
+    def allow_filtering_policy(self) -> AllowFilteringPolicy: ...
+    @property
+    def wait_for_node_connections(self) -> bool: ...
+    @property
+    def connect_deadline(self) -> Optional[timedelta]: ...
+    @property
     def local_ip_address(self) -> Optional[IPv4Address | IPv6Address]: ...
     @property
     def compression(self) -> Optional[Compression]: ...
@@ -120,6 +140,10 @@ class SessionBuilder:
             - ``tuple[IPv4Address | IPv6Address, int]`` — for example
               ``(IPv4Address("127.0.0.1"), 9042)``
 
+            Hostnames are resolved when `connect` is called. A hostname with
+            several DNS records contributes all of its addresses, and is kept
+            as well, so that it is resolved again when the driver reconnects.
+
         Returns
         -------
         SessionBuilder
@@ -142,6 +166,59 @@ class SessionBuilder:
         """
         ...
 
+    def default_consistency(self, consistency: Consistency | str) -> SessionBuilder:
+        """
+        Set the consistency of the default execution profile.
+
+        Other settings of the default execution profile, whether the builder's
+        defaults or those of a profile set with `execution_profile`, are kept.
+        A profile set with `execution_profile` afterwards replaces this setting.
+
+        Parameters
+        ----------
+        consistency : Consistency | str
+            The consistency level, or its name such as ``"LOCAL_QUORUM"``
+            (matched ignoring case and underscores).
+
+        Returns
+        -------
+        SessionBuilder
+
+        Raises
+        ------
+        ValueError
+            If `consistency` is a string that names no consistency level.
+        TypeError
+            If `consistency` is neither a `Consistency` nor a string.
+        """
+        ...
+
+    def default_serial_consistency(self, serial_consistency: SerialConsistency | None) -> SessionBuilder:
+        """
+        Set the serial consistency of the default execution profile.
+
+        The serial consistency applies to the Paxos phase of lightweight transactions.
+        Other settings of the default execution profile are kept, as with
+        `default_consistency`. A profile set with `execution_profile` afterwards
+        replaces this setting.
+
+        Parameters
+        ----------
+        serial_consistency : SerialConsistency | None
+            ``SerialConsistency.Serial`` or ``SerialConsistency.LocalSerial``.
+            ``None`` sends no serial consistency, leaving the choice to the server.
+
+        Returns
+        -------
+        SessionBuilder
+
+        Raises
+        ------
+        TypeError
+            If `serial_consistency` is neither a `SerialConsistency` nor ``None``.
+        """
+        ...
+
     async def connect(self) -> Session:
         """
         Establish a session using the current builder configuration.
@@ -150,6 +227,27 @@ class SessionBuilder:
         -------
         Session
             A connected session ready to execute queries.
+
+        Raises
+        ------
+        SessionConnectionError
+            If connecting fails or the coroutine is awaited without a running
+            asyncio event loop.
+        """
+        ...
+
+    def connect_blocking(self) -> Session:
+        """
+        Blocking variant of `connect`, for synchronous code.
+
+        Blocks the calling thread until the session is established. The GIL is
+        released while waiting. Must not be called from a thread with a running
+        asyncio event loop; use `connect` there instead.
+
+        Raises
+        ------
+        SessionConnectionError
+            If connecting fails or an event loop is running in the current thread.
         """
         ...
 
@@ -245,12 +343,17 @@ class SessionBuilder:
 
         Parameters
         ----------
-        ip : IPv4Address | IPv6Address | None
+        ip : IPv4Address | IPv6Address | str | None
             The local IP address to bind to, or ``None`` for the default behavior.
 
         Returns
         -------
         SessionBuilder
+
+        Raises
+        ------
+        SessionConfigError
+            If ``ip`` is not a valid IPv4 or IPv6 address.
         """
         ...
 
@@ -266,6 +369,15 @@ class SessionBuilder:
 
         By default the driver uses port range ``(49152, 65535)``.
 
+        To open a connection to a particular shard, the driver picks a source port ``p``
+        such that ``p % shard_count`` equals the shard number. The range must therefore
+        contain at least as many ports as the largest node has shards, with room to spare
+        for ports already in use; otherwise connections to some shards cannot be opened
+        through the shard-aware port and fall back to the regular one.
+
+        The configured range can be read back from
+        ``get_config().shard_aware_local_port_range``.
+
         **Validation Rules:**
         A ``SessionConfigError`` is raised if:
         1. The range is empty (``end`` < ``start``).
@@ -449,6 +561,18 @@ class SessionBuilder:
 
         The default is true.
 
+        Fetching the schema of every keyspace on connect and on each metadata
+        refresh can be costly for clusters with many tables. When disabled, topology
+        (nodes, token ring) is still fetched, but:
+
+        - ``ClusterState.keyspaces``, ``keyspace_names()`` and ``all_tables()`` are empty,
+          and ``get_keyspace()`` returns ``None``;
+        - the replication strategy of keyspaces is unknown, so token-aware load
+          balancing cannot compute replicas and requests are not routed to them.
+
+        To keep routing for a few keyspaces while skipping the rest, use
+        :meth:`keyspaces_to_fetch` instead.
+
         Parameters
         ----------
         fetch : bool
@@ -613,6 +737,163 @@ class SessionBuilder:
         """
         ...
 
+    def default_idempotence(self, is_idempotent: bool) -> SessionBuilder:
+        """
+        Sets the idempotence of statements and batches executed by the session
+        which do not set it themselves with `with_is_idempotent`.
+
+        Useful for workloads that are idempotent by design, so that every
+        statement can be retried (see the `retries` option of `Session.execute`)
+        without being marked individually. Statement-level settings, including
+        ``with_is_idempotent(False)``, still take precedence.
+
+        Default: ``False``
+
+        Parameters
+        ----------
+        is_idempotent : bool
+            Whether statements are idempotent by default.
+
+        Returns
+        -------
+        SessionBuilder
+        """
+        ...
+
+    def prepared_statement_cache_size(self, size: int) -> SessionBuilder:
+        """
+        Sets the size of the session's prepared statement cache.
+
+        The cache holds the statements prepared by `Session.prepare_and_execute`,
+        keyed by query text, and the ids of all statements prepared by the
+        session, used by `Session.execute_by_id`. A statement prepared by
+        `prepare_and_execute` takes up two entries. When the cache is full,
+        the least recently used entry is evicted. ``0`` disables the cache,
+        so `prepare_and_execute` prepares the statement on every call and
+        `execute_by_id` needs its `query` argument.
+
+        Default: ``512``
+
+        Parameters
+        ----------
+        size : int
+            Maximum number of cached prepared statements.
+
+        Returns
+        -------
+        SessionBuilder
+        """
+        ...
+
+    def empty_string_as_null(self, enabled: bool) -> SessionBuilder:
+        """
+        Sets whether an empty `str` bound to an `ascii` or `text` column is
+        written as null.
+
+        CQL keeps empty text and null distinct, and writing a null creates a
+        tombstone, so enable this only for data where the two mean the same.
+        Applies to the bound values of `execute`, `prepare_and_execute`,
+        `batch` and `insert_many`, but not to values nested in collections,
+        tuples or UDTs. See `RowFactory.null_text_as_empty` for the reading
+        side.
+
+        Default: ``False``
+
+        Parameters
+        ----------
+        enabled : bool
+            Whether empty strings are written as nulls.
+
+        Returns
+        -------
+        SessionBuilder
+        """
+        ...
+
+    def allow_filtering_policy(self, policy: AllowFilteringPolicy) -> SessionBuilder:
+        """
+        Sets what the session does when an executed statement contains
+        `ALLOW FILTERING`, which often means an accidental full table scan.
+
+        With `AllowFilteringPolicy.Warn` a warning is logged through the
+        ``scylla`` logger, with `AllowFilteringPolicy.Error` the request fails
+        with `ExecuteError` before it is sent. Applies to `execute`,
+        `execute_blocking` and `prepare_and_execute`, for both unprepared and
+        prepared statements.
+
+        The check is a heuristic on the query text: the two keywords are
+        looked for outside string literals, quoted identifiers and comments.
+        It does not parse the statement, so it cannot tell whether the server
+        would actually need to filter.
+
+        Default: ``AllowFilteringPolicy.Off``
+
+        Parameters
+        ----------
+        policy : AllowFilteringPolicy
+            What to do with statements containing `ALLOW FILTERING`.
+
+        Returns
+        -------
+        SessionBuilder
+        """
+        ...
+
+    def connect_deadline(self, deadline: timedelta | float | None) -> SessionBuilder:
+        """
+        Sets the maximum time `connect` waits for the session to be established.
+
+        When no contact point is reachable, the Rust driver may keep trying for
+        longer than a single connection timeout. With a deadline set, `connect`
+        instead raises `SessionConnectionError` listing the contact points as
+        soon as the deadline elapses. The deadline covers the whole `connect`
+        call: resolving hostnames, connecting, fetching cluster metadata and
+        `wait_for_node_connections`. `connection_timeout` still bounds each
+        individual connection attempt.
+
+        Default: ``None`` (no deadline)
+
+        Parameters
+        ----------
+        deadline : timedelta | float | None
+            The deadline. If a ``float`` is provided, it is interpreted as
+            **seconds**. ``None`` removes the deadline.
+
+        Returns
+        -------
+        SessionBuilder
+        """
+        ...
+
+    def wait_for_node_connections(self, enabled: bool) -> SessionBuilder:
+        """
+        Sets whether `connect` waits until every node has at least one open connection.
+
+        Without it, `connect` returns as soon as the session is established and
+        the connection pools to the other nodes are opened in the background, so
+        the first requests routed to them may be slower. When enabled, `connect`
+        additionally waits, for at most the connection timeout, until every node
+        allowed by the host filter has at least one open connection. This adds
+        the time needed to connect to the slowest node to `connect`.
+
+        The connections to the other shards of a node may still be opening when
+        `connect` returns, as the driver does not expose how many connections a
+        pool holds. Waiting is best-effort: if the timeout elapses, the session
+        is returned anyway and the pools keep filling in the background.
+
+        Default: ``False``
+
+        Parameters
+        ----------
+        enabled : bool
+            Whether to wait for a connection to every node.
+
+        Returns
+        -------
+        SessionBuilder
+        """
+        ...
+
     def custom_identity(self, identity: SelfIdentity) -> SessionBuilder:
         """
         Sets self-identifying information sent by the driver in the STARTUP message.
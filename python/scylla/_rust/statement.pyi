@@ -1,5 +1,10 @@
+from typing import Any, Sequence
+
+from .cluster.metadata import CqlColumnType
 from .enums import Consistency, SerialConsistency
+from .errors import SerializationError
 from .execution_profile import ExecutionProfile
+from .policies import LoadBalancingPolicy
 from .types import UnsetType
 
 class PreparedStatement:
@@ -10,25 +15,119 @@ class PreparedStatement:
     PreparedStatement with the modified configuration. The original instance
     (`self`) retains its old configuration and remains unchanged.
     """
+    @property
+    def id(self) -> bytes:
+        """
+        Id assigned to the statement by the server when it was prepared.
+        It can be passed to `Session.execute_by_id`.
+        """
+        ...
+    def validate_values(self, values: Any | None = None) -> SerializationError | None:
+        """
+        Check that `values` can be bound to the statement, without sending anything.
+
+        The values are serialized against the statement's bind markers exactly
+        as `Session.execute` would. Session options that adjust values, such as
+        `SessionBuilder.empty_string_as_null`, are not applied.
+
+        Returns
+        -------
+        SerializationError | None
+            The first error found, with the offending parameter in its message,
+            or `None` if the values are valid. The error is returned, not raised.
+        """
+        ...
+    @property
+    def variable_columns(self) -> list[tuple[str, CqlColumnType]]:
+        """
+        Names and types of the statement's bind markers, in the order they appear,
+        as reported by the server when the statement was prepared.
+
+        A positional `?` marker is named after the column it is bound to,
+        a named marker after its name.
+        """
+        ...
     def with_execution_profile(self, profile: ExecutionProfile) -> PreparedStatement: ...
     def without_execution_profile(self) -> PreparedStatement: ...
     @property
     def execution_profile(self) -> ExecutionProfile | None: ...
-    def with_consistency(self, c: Consistency) -> PreparedStatement: ...
+    def with_consistency(self, c: Consistency | str) -> PreparedStatement: ...
     def without_consistency(self) -> PreparedStatement: ...
     @property
     def consistency(self) -> Consistency | None: ...
-    def with_serial_consistency(self, sc: SerialConsistency | None) -> PreparedStatement: ...
+    def with_serial_consistency(self, sc: SerialConsistency | None, *, check_lwt: bool = False) -> PreparedStatement:
+        """
+        Set the serial consistency level, used only by lightweight transactions (LWT).
+
+        With `check_lwt=True`, a best-effort check rejects setting a serial
+        consistency level on a statement that is neither confirmed as a lightweight transaction by the server
+        nor contains the `IF` keyword.
+
+        Raises
+        ------
+        StatementConfigError
+            If `check_lwt` is True, `sc` is not None and the statement does not look like an LWT.
+        """
+        ...
     def without_serial_consistency(self) -> PreparedStatement: ...
     @property
     def serial_consistency(self) -> SerialConsistency | None | UnsetType: ...
-    def with_request_timeout(self, timeout: float | None) -> PreparedStatement: ...
+    def with_request_timeout(self, timeout: float | None) -> PreparedStatement:
+        """
+        Set the client-side request timeout, in seconds. `None` disables it.
+
+        This only bounds how long the driver waits for a response; the server
+        keeps its own timeout. To change the server-side timeout, use
+        `USING TIMEOUT` in the CQL text, e.g. `SELECT * FROM t USING TIMEOUT 60s`.
+        """
+        ...
     def without_request_timeout(self) -> PreparedStatement: ...
     @property
     def request_timeout(self) -> float | None | UnsetType: ...
     def with_page_size(self, page_size: int) -> PreparedStatement: ...
     @property
     def page_size(self) -> int: ...
+    def with_load_balancing_policy(self, policy: LoadBalancingPolicy) -> PreparedStatement:
+        """
+        Set the load balancing policy used for this statement only.
+
+        It takes precedence over the policy of the statement's execution
+        profile and of the session's default profile.
+        """
+        ...
+    def without_load_balancing_policy(self) -> PreparedStatement: ...
+    @property
+    def load_balancing_policy(self) -> LoadBalancingPolicy | None: ...
+    def with_timestamp(self, timestamp: int) -> PreparedStatement:
+        """
+        Set the client-side write timestamp, in integer microseconds since the Unix epoch.
+
+        The value is sent as-is, without a round trip through `float`, so it
+        keeps full microsecond precision. It takes precedence over the
+        session's `TimestampGenerator`.
+        """
+        ...
+    def without_timestamp(self) -> PreparedStatement: ...
+    @property
+    def timestamp(self) -> int | None: ...
+    def with_is_idempotent(self, is_idempotent: bool) -> PreparedStatement:
+        """
+        Mark the statement as idempotent, i.e. safe to execute more than once
+        with the same effect. Only idempotent statements may be retried with
+        `Session.execute(..., retries=N)`. If not set, the session's default
+        idempotence applies (see `SessionBuilder.default_idempotence`).
+        """
+        ...
+    @property
+    def is_idempotent(self) -> bool: ...
+    def with_tracing(self, tracing: bool) -> PreparedStatement:
+        """
+        Request tracing of the statement. The result's `tracing_id` can then
+        be passed to `Session.get_tracing_info` to read the trace.
+        """
+        ...
+    @property
+    def tracing(self) -> bool: ...
 
 class Statement:
     """
@@ -41,22 +140,123 @@ class Statement:
 
     contents: str
     def __init__(self, query_str: str) -> None: ...
+    @staticmethod
+    def from_prepared(prepared: PreparedStatement) -> Statement:
+        """
+        Create an unprepared statement with the CQL text of a prepared statement.
+
+        Execution profile, consistency, serial consistency, request timeout,
+        page size, timestamp, load balancing policy and idempotence are copied over from `prepared`.
+        """
+        ...
+    @staticmethod
+    def expand_in_lists(query_str: str, values: Sequence[Any]) -> tuple[Statement, list[Any]]:
+        """
+        Expand `IN ?` markers bound to lists or tuples into `IN (?, ?, ...)`.
+
+        Each positional `?` directly following the `IN` keyword whose value is
+        a `list` or a `tuple` is replaced with one marker per element, and the
+        elements are flattened into the returned values. Other markers and
+        values are left as they are. An empty list becomes `IN ()`.
+
+        Only positional (`?`) markers are supported. Question marks inside
+        string literals, quoted identifiers and comments are ignored.
+
+        Parameters
+        ----------
+        query_str : str
+            CQL statement text with positional bind markers.
+        values : Sequence[Any]
+            One value per bind marker.
+
+        Returns
+        -------
+        tuple[Statement, list[Any]]
+            The rewritten statement and the flattened values.
+
+        Raises
+        ------
+        StatementConfigError
+            If the number of bind markers does not match the number of values.
+        """
+        ...
     def with_execution_profile(self, profile: ExecutionProfile) -> Statement: ...
     def without_execution_profile(self) -> Statement: ...
     @property
     def execution_profile(self) -> ExecutionProfile | None: ...
-    def with_consistency(self, c: Consistency) -> Statement: ...
+    def with_consistency(self, c: Consistency | str) -> Statement: ...
     def without_consistency(self) -> Statement: ...
     @property
     def consistency(self) -> Consistency | None: ...
-    def with_serial_consistency(self, sc: SerialConsistency | None) -> Statement: ...
+    def with_serial_consistency(self, sc: SerialConsistency | None, *, check_lwt: bool = False) -> Statement:
+        """
+        Set the serial consistency level, used only by lightweight transactions (LWT).
+
+        With `check_lwt=True`, a best-effort check rejects setting a serial
+        consistency level on a statement that does not contain the `IF` keyword.
+
+        Raises
+        ------
+        StatementConfigError
+            If `check_lwt` is True, `sc` is not None and the statement does not look like an LWT.
+        """
+        ...
     def without_serial_consistency(self) -> Statement: ...
     @property
     def serial_consistency(self) -> SerialConsistency | None | UnsetType: ...
-    def with_request_timeout(self, timeout: float | None) -> Statement: ...
+    def with_request_timeout(self, timeout: float | None) -> Statement:
+        """
+        Set the client-side request timeout, in seconds. `None` disables it.
+
+        This only bounds how long the driver waits for a response; the server
+        keeps its own timeout. To change the server-side timeout, use
+        `USING TIMEOUT` in the CQL text, e.g. `SELECT * FROM t USING TIMEOUT 60s`.
+        """
+        ...
     def without_request_timeout(self) -> Statement: ...
     @property
     def request_timeout(self) -> float | None | UnsetType: ...
     def with_page_size(self, page_size: int) -> Statement: ...
     @property
     def page_size(self) -> int: ...
+    def with_load_balancing_policy(self, policy: LoadBalancingPolicy) -> Statement:
+        """
+        Set the load balancing policy used for this statement only.
+
+        It takes precedence over the policy of the statement's execution
+        profile and of the session's default profile.
+        """
+        ...
+    def without_load_balancing_policy(self) -> Statement: ...
+    @property
+    def load_balancing_policy(self) -> LoadBalancingPolicy | None: ...
+    def with_timestamp(self, timestamp: int) -> Statement:
+        """
+        Set the client-side write timestamp, in integer microseconds since the Unix epoch.
+
+        The value is sent as-is, without a round trip through `float`, so it
+        keeps full microsecond precision. It takes precedence over the
+        session's `TimestampGenerator`.
+        """
+        ...
+    def without_timestamp(self) -> Statement: ...
+    @property
+    def timestamp(self) -> int | None: ...
+    def with_is_idempotent(self, is_idempotent: bool) -> Statement:
+        """
+        Mark the statement as idempotent, i.e. safe to execute more than once
+        with the same effect. Only idempotent statements may be retried with
+        `Session.execute(..., retries=N)`. If not set, the session's default
+        idempotence applies (see `SessionBuilder.default_idempotence`).
+        """
+        ...
+    @property
+    def is_idempotent(self) -> bool: ...
+    def with_tracing(self, tracing: bool) -> Statement:
+        """
+        Request tracing of the statement. The result's `tracing_id` can then
+        be passed to `Session.get_tracing_info` to read the trace.
+        """
+        ...
+    @property
+    def tracing(self) -> bool: ...
@@ -0,0 +1,66 @@
+from datetime import datetime
+from ipaddress import IPv4Address, IPv6Address
+from uuid import UUID
+
+class TracingEvent:
+    """
+    Single row of `system_traces.events`, describing one step of a traced request.
+    """
+
+    @property
+    def event_id(self) -> UUID: ...
+    @property
+    def activity(self) -> str | None:
+        """
+        Description of the step, e.g. "Parsing a statement".
+        """
+        ...
+    @property
+    def source(self) -> IPv4Address | IPv6Address | None:
+        """
+        Address of the node which performed the step.
+        """
+        ...
+    @property
+    def source_elapsed(self) -> int | None:
+        """
+        Microseconds elapsed on the `source` node since it started handling the request.
+        """
+        ...
+    @property
+    def thread(self) -> str | None: ...
+    def __repr__(self) -> str: ...
+
+class TracingInfo:
+    """
+    Tracing session of a request, read from `system_traces.sessions`,
+    together with its events.
+    """
+
+    @property
+    def client(self) -> IPv4Address | IPv6Address | None: ...
+    @property
+    def command(self) -> str | None: ...
+    @property
+    def coordinator(self) -> IPv4Address | IPv6Address | None: ...
+    @property
+    def duration(self) -> int | None:
+        """
+        Duration of the request on the coordinator, in microseconds.
+        """
+        ...
+    @property
+    def parameters(self) -> dict[str, str] | None: ...
+    @property
+    def request(self) -> str | None: ...
+    @property
+    def started_at(self) -> datetime | None: ...
+    @property
+    def events(self) -> list[TracingEvent]:
+        """
+        Events of the request ordered by `source_elapsed`.
+
+        Events without `source_elapsed` come first.
+        """
+        ...
+    def __repr__(self) -> str: ...
@@ -1,3 +1,243 @@
+import builtins
+from datetime import datetime
+from decimal import Decimal
+from ipaddress import IPv4Address, IPv6Address
+from typing import Any
+from uuid import UUID
+
+from .cluster.metadata import CqlColumnType
+
 class CqlEmpty:
     """Represents an empty CQL value."""
     def __repr__(self) -> str: ...
+
+class CqlCounter:
+    """
+    Explicit value to bind to a `counter` column, e.g. in `SET c = c + ?`.
+
+    Plain `int`s are accepted for counter columns as well. A `CqlCounter`
+    bound to any other column type is rejected with a type mismatch error,
+    which catches counter/non-counter mixups early.
+    """
+    def __init__(self, value: int) -> None: ...
+    @property
+    def value(self) -> int: ...
+    def __int__(self) -> int: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class CqlTinyInt:
+    """
+    Explicit value to bind to a `tinyint` column.
+
+    Plain `int`s are accepted for `tinyint` columns as well. A `CqlTinyInt`
+    bound to any other column type is rejected with a type mismatch error.
+
+    Raises
+    ------
+    ValueOverflowSerializationError
+        If `value` is not between -128 and 127.
+    """
+    def __init__(self, value: int) -> None: ...
+    @property
+    def value(self) -> int: ...
+    def __int__(self) -> int: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class CqlSmallInt:
+    """
+    Explicit value to bind to a `smallint` column.
+
+    Plain `int`s are accepted for `smallint` columns as well. A `CqlSmallInt`
+    bound to any other column type is rejected with a type mismatch error.
+
+    Raises
+    ------
+    ValueOverflowSerializationError
+        If `value` is not between -32768 and 32767.
+    """
+    def __init__(self, value: int) -> None: ...
+    @property
+    def value(self) -> int: ...
+    def __int__(self) -> int: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class CqlInt:
+    """
+    Explicit value to bind to an `int` column.
+
+    Plain `int`s are accepted for `int` columns as well. A `CqlInt`
+    bound to any other column type is rejected with a type mismatch error.
+
+    Raises
+    ------
+    ValueOverflowSerializationError
+        If `value` is not between -2147483648 and 2147483647.
+    """
+    def __init__(self, value: int) -> None: ...
+    @property
+    def value(self) -> int: ...
+    def __int__(self) -> int: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class CqlBigInt:
+    """
+    Explicit value to bind to a `bigint` column.
+
+    Plain `int`s are accepted for `bigint` columns as well. A `CqlBigInt`
+    bound to any other column type is rejected with a type mismatch error.
+
+    Raises
+    ------
+    ValueOverflowSerializationError
+        If `value` is not between -9223372036854775808 and 9223372036854775807.
+    """
+    def __init__(self, value: int) -> None: ...
+    @property
+    def value(self) -> int: ...
+    def __int__(self) -> int: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class CqlValue:
+    """
+    Exact CQL value, bound without inspecting the Python type.
+
+    Each static constructor produces a value of one CQL type. When bound, the
+    value is checked against the column type, so e.g. `CqlValue.smallint(1)`
+    bound to an `int` column is rejected with a type mismatch error.
+
+    Raises
+    ------
+    OverflowError
+        If the value does not fit in the CQL type.
+    ValueError
+        If an `ascii` value contains non-ASCII characters.
+    """
+    @staticmethod
+    def tinyint(value: builtins.int) -> CqlValue: ...
+    @staticmethod
+    def smallint(value: builtins.int) -> CqlValue: ...
+    @staticmethod
+    def int(value: builtins.int) -> CqlValue: ...
+    @staticmethod
+    def bigint(value: builtins.int) -> CqlValue: ...
+    @staticmethod
+    def counter(value: builtins.int) -> CqlValue: ...
+    @staticmethod
+    def varint(value: builtins.int) -> CqlValue: ...
+    @staticmethod
+    def float(value: builtins.float) -> CqlValue: ...
+    @staticmethod
+    def double(value: builtins.float) -> CqlValue: ...
+    @staticmethod
+    def decimal(value: Decimal) -> CqlValue: ...
+    @staticmethod
+    def boolean(value: bool) -> CqlValue: ...
+    @staticmethod
+    def text(value: str) -> CqlValue: ...
+    @staticmethod
+    def ascii(value: str) -> CqlValue: ...
+    @staticmethod
+    def blob(value: bytes) -> CqlValue: ...
+    @staticmethod
+    def uuid(value: UUID) -> CqlValue: ...
+    @staticmethod
+    def timeuuid(value: UUID) -> CqlValue: ...
+    @staticmethod
+    def inet(value: IPv4Address | IPv6Address) -> CqlValue: ...
+    @staticmethod
+    def date(days: builtins.int) -> CqlValue:
+        """Days since the Unix epoch."""
+        ...
+    @staticmethod
+    def time(nanoseconds: builtins.int) -> CqlValue:
+        """Nanoseconds since midnight, from 0 to 86399999999999."""
+        ...
+    @staticmethod
+    def timestamp(milliseconds: builtins.int) -> CqlValue:
+        """Milliseconds since the Unix epoch."""
+        ...
+    @staticmethod
+    def duration(months: builtins.int, days: builtins.int, nanoseconds: builtins.int) -> CqlValue: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __repr__(self) -> str: ...
+
+def timeuuid_from_time(timestamp: datetime | float | None = None) -> UUID:
+    """
+    Generate a version 1 UUID (`timeuuid`) with a random clock sequence and node.
+
+    Without `timestamp`, every call embeds a later timestamp than the previous
+    one, so timeuuids generated one after another sort in generation order in
+    Scylla, e.g. as clustering keys. Timeuuids generated for the same explicit
+    `timestamp` are ordered by their random clock sequence and node.
+
+    Parameters
+    ----------
+    timestamp : datetime | float | None, optional
+        Time to embed, as a `datetime` (naive values are treated as UTC) or
+        seconds since the Unix epoch. Default is None (current time).
+
+    Raises
+    ------
+    TypeError
+        If `timestamp` is neither a `datetime` nor a number.
+    ValueError
+        If `timestamp` cannot be represented in a timeuuid.
+    """
+    ...
+
+def min_timeuuid(timestamp: datetime | float) -> UUID:
+    """
+    Lowest `timeuuid` for the given timestamp, as ordered by the database.
+
+    Useful as a lower bound of a range query over a `timeuuid` column.
+    """
+    ...
+
+def max_timeuuid(timestamp: datetime | float) -> UUID:
+    """
+    Highest `timeuuid` for the given timestamp, as ordered by the database.
+
+    Useful as an upper bound of a range query over a `timeuuid` column.
+    """
+    ...
+
+def datetime_from_timeuuid(uuid: UUID) -> datetime:
+    """
+    Time stored in a `timeuuid`, as a `datetime` in UTC with microsecond precision.
+
+    Raises
+    ------
+    ValueError
+        If `uuid` is not a version 1 UUID.
+    """
+    ...
+
+def serialize_value(value: Any, column_type: CqlColumnType) -> bytes | None:
+    """
+    Serialize `value` as a CQL value of `column_type`.
+
+    The value is converted exactly as it would be when bound to a statement,
+    so column types built in Python (e.g. `CqlMap(CqlText(), CqlInt())`) can
+    be used to check serialization without a live table.
+
+    Returns
+    -------
+    bytes | None
+        The serialized value, without its length prefix, or None for null.
+
+    Raises
+    ------
+    SerializationError
+        If `value` cannot be serialized as `column_type`.
+    """
+    ...
@@ -1,3 +1,3 @@
-from ._rust.enums import Consistency, SerialConsistency, Compression, PoolSize, WriteCoalescingDelay, SelfIdentity  # pyright: ignore[reportMissingModuleSource]
+from ._rust.enums import Consistency, SerialConsistency, Compression, AllowFilteringPolicy, PoolSize, WriteCoalescingDelay, SelfIdentity  # pyright: ignore[reportMissingModuleSource]
 
-__all__ = ["Consistency", "SerialConsistency", "Compression", "PoolSize", "WriteCoalescingDelay", "SelfIdentity"]
+__all__ = ["Consistency", "SerialConsistency", "Compression", "AllowFilteringPolicy", "PoolSize", "WriteCoalescingDelay", "SelfIdentity"]
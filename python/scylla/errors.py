@@ -4,10 +4,14 @@ from ._rust.errors import (  # pyright: ignore[reportMissingModuleSource]
     BadKeyspaceNameError,
     BatchError,
     ClusterStateTokenError,
+    ColumnTypeMismatchError,
     DecodeFailedError,
     DeserializationError,
     ExecuteError,
+    InvalidQueryError,
+    InvalidStatementError,
     KeyspaceNameMismatchError,
+    LwtResultError,
     PrepareError,
     PyConversionFailedError,
     PySerializationFailedError,
@@ -23,16 +27,20 @@ from ._rust.errors import (  # pyright: ignore[reportMissingModuleSource]
     SessionConnectionError,
     StatementConfigError,
     StatementConversionError,
+    TracingError,
     TypeMismatchSerializationError,
     UnsupportedTypeDeserializationError,
     UnsupportedTypeSerializationError,
     UseKeyspaceError,
     ValueOverflowSerializationError,
+    WriteTimeoutError,
 )
 
 __all__ = [
     "ScyllaError",
     "RowIterationError",
+    "LwtResultError",
+    "ColumnTypeMismatchError",
     "DeserializationError",
     "UnsupportedTypeDeserializationError",
     "DecodeFailedError",
@@ -41,8 +49,12 @@ __all__ = [
     "SessionConfigError",
     "StatementConversionError",
     "PrepareError",
+    "InvalidStatementError",
     "ExecuteError",
+    "WriteTimeoutError",
+    "InvalidQueryError",
     "SchemaAgreementError",
+    "TracingError",
     "StatementConfigError",
     "BatchError",
     "SerializationError",
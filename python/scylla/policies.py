@@ -6,6 +6,10 @@ from ._rust.policies import (  # pyright: ignore[reportMissingModuleSource]
     TimestampGenerator,
     HostFilter,
     Peer,
+    LoadBalancingPolicy,
+    RetryPolicy,
+    DefaultRetryPolicy,
+    DowngradingConsistencyRetryPolicy,
 )
 
 __all__ = [
@@ -16,4 +20,8 @@ __all__ = [
     "TimestampGenerator",
     "HostFilter",
     "Peer",
+    "LoadBalancingPolicy",
+    "RetryPolicy",
+    "DefaultRetryPolicy",
+    "DowngradingConsistencyRetryPolicy",
 ]
@@ -6,6 +6,11 @@ from ._rust.results import (  # pyright: ignore[reportMissingModuleSource]
     Column,
     PagingState,
     AsyncRowsIterator,
+    LwtResult,
+    TypedRow,
+    TypedRowFactory,
+    OrderedDictRowFactory,
+    RawBytesRowFactory,
 )
 
 __all__ = [
@@ -16,4 +21,9 @@ __all__ = [
     "ColumnIterator",
     "PagingState",
     "AsyncRowsIterator",
+    "LwtResult",
+    "TypedRowFactory",
+    "TypedRow",
+    "OrderedDictRowFactory",
+    "RawBytesRowFactory",
 ]
@@ -2,6 +2,7 @@ from typing import TypeAlias
 
 from ._rust.routing import (  # pyright: ignore[reportMissingModuleSource]
     ReplicaLocator,
+    Sharder,
     Token,
 )
 
@@ -10,5 +11,6 @@ Shard: TypeAlias = int
 __all__ = [
     "ReplicaLocator",
     "Shard",
+    "Sharder",
     "Token",
 ]
@@ -0,0 +1,9 @@
+from ._rust.runtime import (  # pyright: ignore[reportMissingModuleSource]
+    configure_runtime,
+    runtime_initialized,
+)
+
+__all__ = [
+    "configure_runtime",
+    "runtime_initialized",
+]
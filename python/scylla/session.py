@@ -1,3 +1,8 @@
-from ._rust.session import Session  # pyright: ignore[reportMissingModuleSource]
+from ._rust.session import (  # pyright: ignore[reportMissingModuleSource]
+    InsertManyResult,
+    ProtocolFeatures,
+    Session,
+    SizeEstimate,
+)
 
-__all__ = ["Session"]
+__all__ = ["Session", "ProtocolFeatures", "InsertManyResult", "SizeEstimate"]
@@ -0,0 +1,9 @@
+from ._rust.tracing import (  # pyright: ignore[reportMissingModuleSource]
+    TracingEvent,
+    TracingInfo,
+)
+
+__all__ = [
+    "TracingEvent",
+    "TracingInfo",
+]
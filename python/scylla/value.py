@@ -1,3 +1,29 @@
-from ._rust.value import CqlEmpty  # pyright: ignore[reportMissingModuleSource]
+from ._rust.value import (  # pyright: ignore[reportMissingModuleSource]
+    CqlBigInt,
+    CqlCounter,
+    CqlEmpty,
+    CqlInt,
+    CqlSmallInt,
+    CqlTinyInt,
+    CqlValue,
+    datetime_from_timeuuid,
+    max_timeuuid,
+    min_timeuuid,
+    serialize_value,
+    timeuuid_from_time,
+)
 
-__all__ = ["CqlEmpty"]
+__all__ = [
+    "CqlEmpty",
+    "CqlCounter",
+    "CqlTinyInt",
+    "CqlSmallInt",
+    "CqlInt",
+    "CqlBigInt",
+    "CqlValue",
+    "timeuuid_from_time",
+    "min_timeuuid",
+    "max_timeuuid",
+    "datetime_from_timeuuid",
+    "serialize_value",
+]
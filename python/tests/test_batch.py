@@ -30,6 +30,7 @@ async def session():
     session = await set_up()
     yield session
     await session.execute("DROP KEYSPACE testks")
+    session.shutdown()
 
 
 TableFactory = Callable[[str, str], Awaitable[str]]
@@ -69,6 +70,7 @@ async def session_without_tablets():
     session = await set_up_without_tablets()
     yield session
     await session.execute("DROP KEYSPACE testks_without_tablets")
+    session.shutdown()
 
 
 @pytest_asyncio.fixture
@@ -340,6 +342,38 @@ async def test_batch_add_all(session: Session, table_factory: TableFactory):
     ]
 
 
+# A column left unset keeps its previous value, while a null would overwrite it with a tombstone.
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_batch_unset_values_keep_columns(session: Session, table_factory: TableFactory):
+    table = await table_factory("id int PRIMARY KEY, name text, age int", "users")
+    prepared = await session.prepare(f"INSERT INTO {table} (id, name, age) VALUES (?, ?, ?)")
+
+    initial = Batch()
+    initial.add_all([(prepared, (i, f"user{i}", 20 + i)) for i in range(4)])
+    await session.batch(initial)
+
+    batch = Batch()
+    batch.add(prepared, (0, "renamed0", Unset))
+    batch.add(prepared, {"id": 1, "name": Unset, "age": 99})
+    batch.add(prepared, (2, "renamed2", None), none_as_unset=True)
+    batch.add(prepared, (3, "renamed3", None))
+    await session.batch(batch)
+
+    res = await session.execute(f"SELECT id, name, age FROM {table}")
+    rows = sorted(await res.all(), key=lambda r: r["id"])
+
+    assert rows == [
+        {"id": 0, "name": "renamed0", "age": 20},
+        {"id": 1, "name": "user1", "age": 99},
+        {"id": 2, "name": "renamed2", "age": 22},
+        {"id": 3, "name": "renamed3", "age": None},
+    ]
+
+    res = await session.execute(f"SELECT writetime(age) AS wt FROM {table} WHERE id = 3")
+    assert (await res.first_row())["wt"] is None
+
+
 def test_batch_execution_profile():
     batch = Batch()
     profile = ExecutionProfile()
@@ -386,6 +420,19 @@ def test_batch_request_timeout():
     assert batch.request_timeout is Unset
 
 
+def test_batch_is_idempotent():
+    batch = Batch()
+    assert not batch.is_idempotent
+
+    idempotent = batch.with_is_idempotent(True)
+    assert idempotent.is_idempotent
+    assert not batch.is_idempotent
+
+    # Other settings keep the idempotence.
+    assert idempotent.with_consistency(Consistency.One).is_idempotent
+    assert not idempotent.with_is_idempotent(False).is_idempotent
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_batch_multiple_batch_executions(session: Session, table_factory: TableFactory):
@@ -15,8 +15,11 @@ from scylla.cluster.metadata import (
     CqlList,
     CqlMap,
     CqlNativeType,
+    CqlSet,
     CqlText,
     CqlTuple,
+    CqlUserDefinedType,
+    CqlVector,
     Keyspace,
     StrategyKind,
 )
@@ -27,6 +30,7 @@ from scylla.statement import Statement
 
 KEYSPACE = "cs_test_ks"
 TABLE = "cs_test_table"
+CLUSTERED_TABLE = "cs_test_clustered_table"
 TEST_PARTITION_KEY = 1  # Default partition key value for testing
 
 
@@ -41,6 +45,10 @@ async def set_up() -> Session:
         CREATE TABLE IF NOT EXISTS {KEYSPACE}.{TABLE}
         (id int PRIMARY KEY, name text);
     """)
+    await session.execute(f"""
+        CREATE TABLE IF NOT EXISTS {KEYSPACE}.{CLUSTERED_TABLE}
+        (p1 int, p2 int, c1 int, c2 text, c3 int, v text, s int STATIC, PRIMARY KEY ((p2, p1), c2, c3, c1));
+    """)
 
     # Execute some queries to initialize replica locators for tablets
     # This is needed because replica locators are lazily initialized for tablet-enabled tables
@@ -58,6 +66,7 @@ async def session() -> AsyncGenerator[Session, None]:
     s = await set_up()
     yield s
     await s.execute(f"DROP KEYSPACE IF EXISTS {KEYSPACE}")
+    s.shutdown()
 
 
 @pytest_asyncio.fixture(scope="module")
@@ -82,6 +91,41 @@ async def test_get_keyspaces_returns(cluster_state: ClusterState) -> None:
     assert isinstance(ks[KEYSPACE], Keyspace)
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_keyspace_names(cluster_state: ClusterState) -> None:
+    names = cluster_state.keyspace_names()
+    assert isinstance(names, list)
+    assert "system" in names
+    assert KEYSPACE in names
+    assert sorted(names) == sorted(cluster_state.keyspaces)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_all_tables(cluster_state: ClusterState) -> None:
+    tables = cluster_state.all_tables()
+    assert isinstance(tables, list)
+    assert (KEYSPACE, TABLE) in tables
+    assert tables == sorted(tables)
+    expected = [(ks_name, table_name) for ks_name, ks in cluster_state.keyspaces.items() for table_name in ks.tables]
+    assert tables == sorted(expected)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_size_summary(cluster_state: ClusterState) -> None:
+    summary = cluster_state.size_summary()
+    nodes = list(cluster_state.nodes_info.values())
+    assert summary == {
+        "total_nodes": len(nodes),
+        "up_nodes": sum(1 for node in nodes if node.connected and node.enabled),
+        "datacenters": len({node.datacenter for node in nodes if node.datacenter is not None}),
+    }
+    assert 0 < summary["up_nodes"] <= summary["total_nodes"]
+    assert summary["datacenters"] >= 1
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_get_keyspace(cluster_state: ClusterState) -> None:
@@ -138,6 +182,26 @@ async def test_table_columns_has_expected_names(cluster_state: ClusterState) ->
     assert "name" in cols
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_table_and_column_lookup(cluster_state: ClusterState) -> None:
+    ks = cluster_state.get_keyspace(KEYSPACE)
+    assert ks is not None
+    assert ks.has_table(TABLE)
+    assert not ks.has_table("no_such_table_xyz")
+
+    table = ks.tables[TABLE]
+    assert table.has_column("name")
+    assert not table.has_column("no_such_column_xyz")
+
+    column = table.column("name")
+    assert column is not None
+    assert column is table.columns["name"]
+    assert column.name == "name"
+    assert isinstance(column.typ, CqlText)
+    assert table.column("no_such_column_xyz") is None
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_column_kind_partition_key(cluster_state: ClusterState) -> None:
@@ -146,6 +210,225 @@ async def test_column_kind_partition_key(cluster_state: ClusterState) -> None:
     assert ks.tables[TABLE].columns["id"].kind == ColumnKind.PartitionKey
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_column_key_positions(cluster_state: ClusterState) -> None:
+    ks = cluster_state.get_keyspace(KEYSPACE)
+    assert ks is not None
+    table = ks.tables[CLUSTERED_TABLE]
+
+    assert list(table.clustering_key) == ["c2", "c3", "c1"]
+    for position, column in enumerate(table.clustering_key.values()):
+        assert column.kind == ColumnKind.Clustering
+        assert column.position == position
+    for position, column in enumerate(table.partition_key.values()):
+        assert column.kind == ColumnKind.PartitionKey
+        assert column.position == position
+
+    positions = {name: column.position for name, column in table.columns.items()}
+    assert positions == {"p2": 0, "p1": 1, "c2": 0, "c3": 1, "c1": 2, "v": None, "s": None}
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_table_export_as_string(cluster_state: ClusterState) -> None:
+    ks = cluster_state.get_keyspace(KEYSPACE)
+    assert ks is not None
+
+    assert ks.tables[CLUSTERED_TABLE].export_as_string() == (
+        f"CREATE TABLE {KEYSPACE}.{CLUSTERED_TABLE} (\n"
+        "    p2 int,\n"
+        "    p1 int,\n"
+        "    c2 int,\n"
+        "    c3 int,\n"
+        "    c1 int,\n"
+        "    s int static,\n"
+        "    v text,\n"
+        "    PRIMARY KEY ((p2, p1), c2, c3, c1)\n"
+        ");"
+    )
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_export_schema_of_table(session: Session) -> None:
+    assert await session.export_schema(KEYSPACE, CLUSTERED_TABLE) == (
+        f"CREATE TABLE {KEYSPACE}.{CLUSTERED_TABLE} (\n"
+        "    p2 int,\n"
+        "    p1 int,\n"
+        "    c2 text,\n"
+        "    c3 int,\n"
+        "    c1 int,\n"
+        "    s int static,\n"
+        "    v text,\n"
+        "    PRIMARY KEY ((p2, p1), c2, c3, c1)\n"
+        ") WITH CLUSTERING ORDER BY (c2 ASC, c3 ASC, c1 ASC);"
+    )
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_export_schema_includes_clustering_order(session: Session) -> None:
+    keyspace = "cs_export_order_ks"
+    await session.execute(f"DROP KEYSPACE IF EXISTS {keyspace}")
+    await session.execute(
+        f"CREATE KEYSPACE {keyspace} WITH replication = {{'class': 'NetworkTopologyStrategy', 'datacenter1': '1'}}"
+    )
+    await session.execute(f"""
+        CREATE TABLE {keyspace}.desc_table (p int, c1 int, c2 text, v int, PRIMARY KEY (p, c1, c2))
+        WITH CLUSTERING ORDER BY (c1 DESC, c2 ASC)
+    """)
+    await session.execute(f"CREATE TABLE {keyspace}.plain_table (id int PRIMARY KEY, v int)")
+
+    try:
+        await session.await_schema_agreement()
+        assert await session.export_schema(keyspace, "desc_table") == (
+            f"CREATE TABLE {keyspace}.desc_table (\n"
+            "    p int,\n"
+            "    c1 int,\n"
+            "    c2 text,\n"
+            "    v int,\n"
+            "    PRIMARY KEY ((p), c1, c2)\n"
+            ") WITH CLUSTERING ORDER BY (c1 DESC, c2 ASC);"
+        )
+
+        # Tables without clustering columns have no clause.
+        ks = session.cluster_state.get_keyspace(keyspace)
+        assert ks is not None
+        assert await session.export_schema(keyspace, "plain_table") == ks.tables["plain_table"].export_as_string()
+        # The schema metadata does not know the clustering order, so it is left out.
+        assert "CLUSTERING ORDER" not in ks.tables["desc_table"].export_as_string()
+
+        exported = await session.export_schema(keyspace)
+        assert exported is not None
+        assert ") WITH CLUSTERING ORDER BY (c1 DESC, c2 ASC);" in exported
+
+        assert await session.export_schema(keyspace, "no_such_table") is None
+        assert await session.export_schema("cs_no_such_ks") is None
+    finally:
+        await session.execute(f"DROP KEYSPACE IF EXISTS {keyspace}")
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_keyspace_export_as_string(cluster_state: ClusterState) -> None:
+    ks = cluster_state.get_keyspace(KEYSPACE)
+    assert ks is not None
+
+    statements = ks.export_as_string().split("\n\n")
+    assert statements[0] == (
+        f"CREATE KEYSPACE {KEYSPACE} WITH replication = "
+        "{'class': 'NetworkTopologyStrategy', 'datacenter1': '1'};"
+    )
+    assert statements[1:] == [
+        ks.tables[CLUSTERED_TABLE].export_as_string(),
+        ks.tables[TABLE].export_as_string(),
+    ]
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_export_schema_of_keyspace(session: Session) -> None:
+    exported = await session.export_schema(KEYSPACE)
+    assert exported is not None
+
+    statements = exported.split("\n\n")
+    assert statements[0] == (
+        f"CREATE KEYSPACE {KEYSPACE} WITH replication = "
+        "{'class': 'NetworkTopologyStrategy', 'datacenter1': '1'};"
+    )
+    assert statements[1:] == [
+        await session.export_schema(KEYSPACE, CLUSTERED_TABLE),
+        await session.export_schema(KEYSPACE, TABLE),
+    ]
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_keyspace_export_recreates_schema(session: Session) -> None:
+    source = "cs_export_src_ks"
+    target = "cs_export_dst_ks"
+    await session.execute(f"DROP KEYSPACE IF EXISTS {source}")
+    await session.execute(f"DROP KEYSPACE IF EXISTS {target}")
+    await session.execute(
+        f"CREATE KEYSPACE {source} WITH replication = {{'class': 'NetworkTopologyStrategy', 'datacenter1': '1'}}"
+    )
+    await session.execute(f"CREATE TYPE {source}.inner_t (a int, b text)")
+    await session.execute(f"CREATE TYPE {source}.outer_t (i frozen<inner_t>, l list<int>)")
+    await session.execute(f"""
+        CREATE TABLE {source}."MixedCase" (
+            id int PRIMARY KEY,
+            "select" text,
+            o frozen<outer_t>,
+            m map<text, frozen<list<int>>>,
+            t tuple<int, text>
+        )
+    """)
+
+    try:
+        await session.await_schema_agreement()
+        exported = await session.export_schema(source)
+        assert exported is not None
+        assert exported.index("CREATE TYPE") < exported.index(f"{source}.outer_t")
+        assert f'CREATE TABLE {source}."MixedCase"' in exported
+        assert '"select" text' in exported
+
+        ks = session.cluster_state.get_keyspace(source)
+        assert ks is not None
+        outer = ks.tables["MixedCase"].columns["o"].typ
+        assert isinstance(outer, CqlUserDefinedType)
+        assert outer.export_as_string() in exported
+
+        for statement in exported.replace(source, target).split("\n\n"):
+            await session.execute(statement)
+        await session.await_schema_agreement()
+        assert await session.export_schema(target) == exported.replace(source, target)
+    finally:
+        await session.execute(f"DROP KEYSPACE IF EXISTS {source}")
+        await session.execute(f"DROP KEYSPACE IF EXISTS {target}")
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_case_sensitive_identifiers(session: Session) -> None:
+    quoted = "CsQuotedKs"
+    unquoted = "CsUnquotedKs"
+    replication = "{'class': 'NetworkTopologyStrategy', 'datacenter1': '1'}"
+    await session.execute(f'CREATE KEYSPACE IF NOT EXISTS "{quoted}" WITH replication = {replication}')
+    await session.execute(f"CREATE KEYSPACE IF NOT EXISTS {unquoted} WITH replication = {replication}")
+    await session.execute(f'CREATE TABLE IF NOT EXISTS "{quoted}"."MixedTable" (id int PRIMARY KEY, "Name" text)')
+
+    try:
+        await session.await_schema_agreement()
+        cluster_state = session.cluster_state
+
+        # Unquoted identifiers are stored lowercased; `normalize_case=True` lowercases the name first.
+        assert cluster_state.get_keyspace(unquoted) is None
+        assert cluster_state.get_keyspace(unquoted, normalize_case=True) is cluster_state.keyspaces[unquoted.lower()]
+
+        # Quoted identifiers keep their case and are found by their exact name.
+        assert cluster_state.get_keyspace(quoted, normalize_case=True) is None
+        ks = cluster_state.get_keyspace(quoted)
+        assert ks is not None
+        assert ks.has_table("MixedTable")
+        assert not ks.has_table("MixedTable", normalize_case=True)
+
+        table = ks.tables["MixedTable"]
+        assert not table.has_column("ID")
+        assert table.has_column("ID", normalize_case=True)
+        assert table.column("Name") is table.columns["Name"]
+        assert table.column("Name", normalize_case=True) is None
+
+        # `use_keyspace` lowercases the name like CQL unless `case_sensitive=True` is passed.
+        other = await SessionBuilder().contact_points(["127.0.0.2:9042"]).connect()
+        with other:
+            await other.use_keyspace(quoted, case_sensitive=True)
+            await other.execute('INSERT INTO "MixedTable" (id, "Name") VALUES (1, \'Alice\')')
+    finally:
+        await session.execute(f'DROP KEYSPACE IF EXISTS "{quoted}"')
+        await session.execute(f"DROP KEYSPACE IF EXISTS {unquoted}")
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_node_type(cluster_state: ClusterState) -> None:
@@ -165,6 +448,18 @@ async def test_node_host_address(cluster_state: ClusterState) -> None:
     assert isinstance(list(cluster_state.nodes_info.items())[0][1].address[1], int)
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_node_sharder(cluster_state: ClusterState) -> None:
+    node = next(iter(cluster_state.nodes_info.values()))
+    sharder = node.sharder
+    assert sharder is not None
+    assert sharder.nr_shards == node.nr_shards
+    assert sharder.shard_of_source_ports(list(range(1024, 1024 + 2 * sharder.nr_shards))) == [
+        port % sharder.nr_shards for port in range(1024, 1024 + 2 * sharder.nr_shards)
+    ]
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_compute_token_returns_token(cluster_state: ClusterState) -> None:
@@ -187,6 +482,25 @@ async def test_replica_locator_unique_nodes_in_global_ring(cluster_state: Cluste
     assert all(isinstance(n, Node) for n in nodes)
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_node_shard_pairs_deduplicate(cluster_state: ClusterState) -> None:
+    other_session = await SessionBuilder().contact_points(["127.0.0.2:9042"]).connect()
+    with other_session:
+        other_state = other_session.cluster_state
+        for host_id, node in cluster_state.nodes_info.items():
+            other_node = other_state.nodes_info[host_id]
+            assert other_node is not node
+            assert other_node == node
+            assert hash(other_node) == hash(node)
+
+        token = cluster_state.compute_token(KEYSPACE, TABLE, (TEST_PARTITION_KEY,))
+        replicas = cluster_state.get_token_endpoints(KEYSPACE, TABLE, token)
+        other_replicas = other_state.get_token_endpoints(KEYSPACE, TABLE, token)
+        assert set(replicas + other_replicas) == set(replicas)
+        assert len(set(replicas)) == len(replicas)
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_replica_locator_primary_replica(cluster_state: ClusterState) -> None:
@@ -278,6 +592,27 @@ async def test_get_token_endpoints_matches_replica_locator(
     assert replicas_from_get_token == replicas_from_locator
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_get_token_range_endpoints(cluster_state: ClusterState) -> None:
+    token = cluster_state.compute_token(KEYSPACE, TABLE, [TEST_PARTITION_KEY])
+
+    # A range without ring tokens inside is a single part.
+    parts = cluster_state.get_token_range_endpoints(KEYSPACE, TABLE, Token(token.value - 1), token)
+    assert parts == [(Token(token.value - 1), token, cluster_state.get_token_endpoints(KEYSPACE, TABLE, token))]
+
+    # `start == end` covers the whole ring, split into consecutive parts in ring order.
+    parts = cluster_state.get_token_range_endpoints(KEYSPACE, TABLE, token, token)
+    assert len(parts) >= 1
+    assert parts[0][0] == token
+    assert parts[-1][1] == token
+    for (_, prev_end, _), (next_start, _, _) in zip(parts, parts[1:]):
+        assert prev_end == next_start
+    for _, end, replicas in parts:
+        assert len(replicas) > 0
+        assert replicas == cluster_state.get_token_endpoints(KEYSPACE, TABLE, end)
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_node_identity(cluster_state: ClusterState) -> None:
@@ -502,3 +837,44 @@ async def test_complex_column_type(session: Session) -> None:
     assert isinstance(complex_col.typ.key_type.column_type.element_types[1], CqlColumnType)
     assert isinstance(complex_col.typ.key_type.column_type.element_types[1], CqlNativeType)
     assert isinstance(complex_col.typ.key_type.column_type.element_types[1], CqlInt)
+
+
+def test_column_types_can_be_constructed():
+    address = CqlUserDefinedType(
+        "address",
+        "testks",
+        [("street", CqlText()), ("numbers", CqlList(CqlInt(), frozen=True))],
+        frozen=True,
+    )
+    assert address.name == "address"
+    assert address.keyspace == "testks"
+    assert address.frozen
+    assert [name for name, _ in address.field_types] == ["street", "numbers"]
+    assert isinstance(address.field_types[1][1], CqlList)
+    assert address.field_types[1][1].frozen
+
+    typ = CqlMap(CqlText(), CqlSet(CqlTuple([CqlInt(), address])))
+    assert isinstance(typ, CqlCollectionType)
+    assert not typ.frozen
+    assert isinstance(typ.key_type, CqlText)
+    assert isinstance(typ.value_type, CqlSet)
+    assert isinstance(typ.value_type.column_type, CqlTuple)
+    assert isinstance(typ.value_type.column_type.element_types[1], CqlUserDefinedType)
+
+    vector = CqlVector(CqlInt(), 3)
+    assert vector.dimensions == 3
+    assert isinstance(vector.typ, CqlNativeType)
+
+    with pytest.raises(TypeError):
+        CqlList(1)  # type: ignore[arg-type]
+
+
+def test_user_defined_type_export_as_string():
+    address = CqlUserDefinedType(
+        "Address",
+        "testks",
+        [("street", CqlText()), ("numbers", CqlList(CqlInt(), frozen=True))],
+    )
+    assert address.export_as_string() == (
+        'CREATE TYPE testks."Address" (\n    street text,\n    numbers frozen<list<int>>\n);'
+    )
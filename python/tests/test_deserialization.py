@@ -2,6 +2,7 @@ import datetime
 import ipaddress
 import math
 import uuid
+from collections import OrderedDict
 from datetime import time
 from decimal import Decimal
 from typing import Any, AsyncGenerator, Awaitable, Callable, Dict, List, Set, Tuple
@@ -9,11 +10,24 @@ from typing import Any, AsyncGenerator, Awaitable, Callable, Dict, List, Set, Tu
 import pytest
 import pytest_asyncio
 from dateutil.relativedelta import relativedelta
-from scylla._rust.errors import DeserializationError, RowIterationError  # pyright: ignore[reportMissingModuleSource]
-from scylla._rust.results import ColumnIterator, RowFactory  # pyright: ignore[reportMissingModuleSource]
+from scylla._rust.errors import (  # pyright: ignore[reportMissingModuleSource]
+    ColumnTypeMismatchError,
+    DeserializationError,
+    RowIterationError,
+)
+from scylla._rust.results import (  # pyright: ignore[reportMissingModuleSource]
+    ColumnIterator,
+    OrderedDictRowFactory,
+    RawBytesRowFactory,
+    RowFactory,
+    TypedRow,
+    TypedRowFactory,
+)
 from scylla._rust.session import Session  # pyright: ignore[reportMissingModuleSource]
 from scylla._rust.session_builder import SessionBuilder  # pyright: ignore[reportMissingModuleSource]
 from scylla._rust.value import CqlEmpty  # pyright: ignore[reportMissingModuleSource]
+from scylla.cluster.metadata import CqlBigInt, CqlInt, CqlList, CqlMap, CqlSet, CqlText
+from scylla.statement import Statement
 
 
 async def set_up() -> Session:
@@ -35,6 +49,7 @@ async def session():
     session = await set_up()
     yield session
     await session.execute("DROP KEYSPACE testks")
+    session.shutdown()
 
 
 TableFactory = Callable[[str, str], Awaitable[str]]
@@ -686,6 +701,39 @@ async def test_time_deserialization(
     assert row["value"] == value
 
 
+class NanosecondTimeRowFactory(RowFactory):
+    def __init__(self) -> None:
+        self.time_as_nanoseconds = True
+
+
+# Verifies that `time_as_nanoseconds` keeps full precision, also for nested values
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_time_as_nanoseconds_deserialization(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, value time, times list<time>, pair tuple<int, time>",
+        "time_nanoseconds_table",
+    )
+    await session.execute(
+        f"INSERT INTO {table} (id, value, times, pair) "
+        "VALUES (1, '01:30:45.123456789', ['00:00:00.000000001', '23:59:59.999999999'], (7, '00:00:01'))"
+    )
+
+    query = f"SELECT value, times, pair FROM {table} WHERE id = 1"
+    assert RowFactory.time_as_nanoseconds is False
+
+    row = await (await session.execute(query, factory=NanosecondTimeRowFactory())).first_row()
+    assert row == {
+        "value": 5_445_123_456_789,
+        "times": [1, 86_399_999_999_999],
+        "pair": (7, 1_000_000_000),
+    }
+
+    row = await (await session.execute(query)).first_row()
+    assert row["value"] == time(1, 30, 45, 123456)
+    assert row["times"] == [time(0), time(23, 59, 59, 999999)]
+
+
 # Verifies correct deserialization of CQL duration into relativedelta
 @pytest.mark.asyncio
 @pytest.mark.requires_db
@@ -702,6 +750,17 @@ async def test_time_deserialization(
             relativedelta(months=11, days=0, microseconds=123_456),
             "11mo0d123456000ns",
         ),
+        (
+            3,
+            relativedelta(months=-14, days=-5, hours=-25),
+            "-14mo5d25h",
+        ),
+        # relativedelta has microsecond precision, so nanoseconds are truncated.
+        (
+            4,
+            relativedelta(microseconds=-1),
+            "-1999ns",
+        ),
     ],
 )
 async def test_duration_deserialization(
@@ -1195,6 +1254,29 @@ async def test_timestamp_overflow_raises_deserialization_error(session: Session,
     assert "column" in str(exc_info.value).lower()
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_raw_bytes_row_factory(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, name text, scores list<int>, missing bigint, value timestamp",
+        "raw_bytes_table",
+    )
+    # The timestamp is out of range of `datetime`, so regular deserialization fails.
+    await session.execute(f"INSERT INTO {table} (id, name, scores, value) VALUES (1, 'Alice', [7], {10**16})")
+
+    query = f"SELECT id, name, scores, missing, value FROM {table} WHERE id = 1"
+    row = await (await session.execute(query, factory=RawBytesRowFactory())).first_row()
+
+    assert row == {
+        "id": (1).to_bytes(4, "big"),
+        "name": b"Alice",
+        # Element count, then the length and bytes of each element.
+        "scores": (1).to_bytes(4, "big") + (4).to_bytes(4, "big") + (7).to_bytes(4, "big"),
+        "missing": None,
+        "value": (10**16).to_bytes(8, "big"),
+    }
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_row_factory_python_error_is_wrapped(session: Session, table_factory: TableFactory):
@@ -1211,3 +1293,148 @@ async def test_row_factory_python_error_is_wrapped(session: Session, table_facto
         await result.first_row()
 
     assert exc_info.value.__cause__ is not None
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_rows_preserve_column_order(session: Session, table_factory: TableFactory):
+    table = await table_factory("id int PRIMARY KEY, b int, a int, c int", "column_order_table")
+    await session.execute(f"INSERT INTO {table} (id, b, a, c) VALUES (1, 2, 3, 4)")
+
+    # The order of the selected columns differs from both the schema and the alphabetical order.
+    query = f"SELECT c, id, a, b FROM {table} WHERE id = 1"
+
+    row = await (await session.execute(query)).first_row()
+    assert type(row) is dict
+    assert list(row) == ["c", "id", "a", "b"]
+
+    row = await (await session.execute(query, factory=OrderedDictRowFactory())).first_row()
+    assert isinstance(row, OrderedDict)
+    assert row == OrderedDict([("c", 4), ("id", 1), ("a", 3), ("b", 2)])
+    assert row != OrderedDict([("id", 1), ("a", 3), ("b", 2), ("c", 4)])
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_typed_row_factory_getters(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, name text, score double, active boolean, token uuid, data blob",
+        "typed_row_table",
+    )
+    token = uuid.uuid4()
+    await session.execute(
+        f"INSERT INTO {table} (id, name, score, active, token, data) VALUES (1, 'Alice', 1.5, true, {token}, 0xCAFE)"
+    )
+
+    result = await session.execute(f"SELECT * FROM {table} WHERE id = 1", factory=TypedRowFactory())
+    row = await result.first_row()
+
+    assert isinstance(row, TypedRow)
+    assert row.get_int("id") == 1
+    assert row.get_str("name") == "Alice"
+    assert row.get_float("score") == 1.5
+    assert row.get_bool("active") is True
+    assert row.get_uuid("token") == token
+    assert row.get_bytes("data") == b"\xca\xfe"
+    assert row["name"] == "Alice"
+    assert "name" in row
+    assert len(row) == 6
+    assert row.as_dict()["id"] == 1
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_typed_row_getter_type_mismatch(session: Session, table_factory: TableFactory):
+    table = await table_factory("id int PRIMARY KEY, name text", "typed_row_mismatch_table")
+    await session.execute(f"INSERT INTO {table} (id, name) VALUES (1, null)")
+
+    result = await session.execute(f"SELECT * FROM {table} WHERE id = 1", factory=TypedRowFactory())
+    row = await result.first_row()
+
+    assert row.get_str("name") is None
+    with pytest.raises(ColumnTypeMismatchError, match="Column 'name' has CQL type text, "):
+        row.get_int("name")
+    with pytest.raises(ColumnTypeMismatchError, match="Column 'id' has CQL type int, "):
+        row.get_str("id")
+    with pytest.raises(KeyError):
+        row.get_int("missing")
+
+
+# Verifies that a custom RowFactory sees the CQL types of columns, including collection element types
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_custom_row_factory_reads_column_types(session: Session, table_factory: TableFactory):
+    class TypeCollectingFactory(RowFactory):
+        def build(self, column_iterator: ColumnIterator) -> Any:
+            return {col.column_name: col.column_type for col in column_iterator}
+
+    table = await table_factory(
+        "id int PRIMARY KEY, tags set<text>, scores list<int>, history map<text, frozen<list<bigint>>>",
+        "column_types_table",
+    )
+    await session.execute(f"INSERT INTO {table} (id, tags, scores) VALUES (1, {{'a'}}, [1, 2]);")
+
+    result = await session.execute(f"SELECT id, tags, scores, history FROM {table}", factory=TypeCollectingFactory())
+    types = (await result.all())[0]
+
+    assert isinstance(types["id"], CqlInt)
+
+    assert isinstance(types["tags"], CqlSet)
+    assert isinstance(types["tags"].column_type, CqlText)
+
+    assert isinstance(types["scores"], CqlList)
+    assert not types["scores"].frozen
+    assert isinstance(types["scores"].column_type, CqlInt)
+
+    history = types["history"]
+    assert isinstance(history, CqlMap)
+    assert isinstance(history.key_type, CqlText)
+    assert isinstance(history.value_type, CqlList)
+    assert history.value_type.frozen
+    assert isinstance(history.value_type.column_type, CqlBigInt)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_group_by_aggregate_columns(session: Session, table_factory: TableFactory):
+    table = await table_factory("p int, c int, v int, PRIMARY KEY (p, c)", "group_by_table")
+    for p in range(3):
+        for c in range(p + 1):
+            await session.execute(f"INSERT INTO {table} (p, c, v) VALUES ({p}, {c}, {c * 10})")
+
+    query = f"SELECT p, count(*), max(v) AS max_v, sum(v) AS total FROM {table} GROUP BY p"
+
+    rows = await (await session.execute(query)).all()
+    assert sorted((row["p"], row["count"], row["max_v"], row["total"]) for row in rows) == [
+        (0, 1, 0, 0),
+        (1, 2, 10, 10),
+        (2, 3, 20, 30),
+    ]
+
+    class TypeCollectingFactory(RowFactory):
+        def build(self, column_iterator: ColumnIterator) -> Any:
+            return {col.column_name: col.column_type for col in column_iterator}
+
+    types = await (await session.execute(query, factory=TypeCollectingFactory())).first_row()
+    assert list(types) == ["p", "count", "max_v", "total"]
+    assert isinstance(types["p"], CqlInt)
+    assert isinstance(types["count"], CqlBigInt)
+    assert isinstance(types["max_v"], CqlInt)
+    assert isinstance(types["total"], CqlInt)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_per_partition_limit_rows(session: Session, table_factory: TableFactory):
+    table = await table_factory("p int, c int, v text, PRIMARY KEY (p, c)", "per_partition_limit_table")
+    for p in range(3):
+        for c in range(4):
+            await session.execute(f"INSERT INTO {table} (p, c, v) VALUES ({p}, {c}, 'v{p}{c}')")
+
+    # A small page size makes the limited partitions span several pages.
+    statement = Statement(f"SELECT p, c, v FROM {table} PER PARTITION LIMIT 2").with_page_size(2)
+    rows = await (await session.execute(statement)).all()
+
+    assert sorted((row["p"], row["c"], row["v"]) for row in rows) == [
+        (p, c, f"v{p}{c}") for p in range(3) for c in range(2)
+    ]
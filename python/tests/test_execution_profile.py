@@ -1,7 +1,9 @@
 import pytest
+from scylla.batch import Batch
 from scylla.enums import Consistency, SerialConsistency
 from scylla.errors import ExecuteError, StatementConfigError
 from scylla.execution_profile import ExecutionProfile
+from scylla.policies import DefaultRetryPolicy, DowngradingConsistencyRetryPolicy, LoadBalancingPolicy, RetryPolicy
 from scylla.session_builder import SessionBuilder
 from scylla.statement import PreparedStatement, Statement
 from scylla.types import Unset
@@ -42,6 +44,52 @@ def test_execution_profile_builder_consistency():
     assert actual_consistency == expected_consistency
 
 
+CONSISTENCY_NAMES = [
+    ("ANY", Consistency.Any),
+    ("ONE", Consistency.One),
+    ("TWO", Consistency.Two),
+    ("THREE", Consistency.Three),
+    ("QUORUM", Consistency.Quorum),
+    ("ALL", Consistency.All),
+    ("LOCAL_QUORUM", Consistency.LocalQuorum),
+    ("EACH_QUORUM", Consistency.EachQuorum),
+    ("LOCAL_ONE", Consistency.LocalOne),
+    ("SERIAL", Consistency.Serial),
+    ("LOCAL_SERIAL", Consistency.LocalSerial),
+]
+
+
+@pytest.mark.parametrize(("name", "expected"), CONSISTENCY_NAMES)
+def test_consistency_from_string(name: str, expected: Consistency):
+    assert Consistency.from_string(name) == expected
+    assert Consistency.from_string(name.lower()) == expected
+    assert Consistency.from_string("".join(part.capitalize() for part in name.split("_"))) == expected
+
+
+@pytest.mark.parametrize(("name", "expected"), CONSISTENCY_NAMES)
+def test_consistency_accepted_as_string(name: str, expected: Consistency):
+    assert ExecutionProfile(consistency=name).consistency == expected
+    assert Statement("SELECT * FROM system.local").with_consistency(name).consistency == expected
+    assert Batch().with_consistency(name).consistency == expected
+
+
+@pytest.mark.parametrize("name", ["", "LOCAL", "QUORUM_LOCAL", "LOCAL-QUORUM"])
+def test_consistency_unknown_name(name: str):
+    with pytest.raises(ValueError, match="Unknown consistency level"):
+        Consistency.from_string(name)
+
+    with pytest.raises(ValueError, match="Unknown consistency level"):
+        ExecutionProfile(consistency=name)
+
+    with pytest.raises(ValueError, match="Unknown consistency level"):
+        Statement("SELECT * FROM system.local").with_consistency(name)
+
+
+def test_consistency_invalid_type():
+    with pytest.raises(TypeError):
+        Statement("SELECT * FROM system.local").with_consistency(1)
+
+
 def test_execution_profile_builder_serial_consistency():
     expected_serial_consistency = SerialConsistency.Serial
     profile = ExecutionProfile(serial_consistency=expected_serial_consistency)
@@ -59,6 +107,40 @@ def test_execution_profile_timeout():
     assert actual_timeout == expected_timeout
 
 
+def test_execution_profile_load_balancing_policy():
+    policy = LoadBalancingPolicy(preferred_datacenter="datacenter1", permit_dc_failover=True)
+    profile = ExecutionProfile(load_balancing_policy=policy)
+    assert profile.load_balancing_policy == policy
+    assert profile.load_balancing_policy != LoadBalancingPolicy(preferred_datacenter="datacenter1")
+
+    # Without a policy, the profile uses the driver's default one.
+    default = ExecutionProfile().load_balancing_policy
+    assert isinstance(default, LoadBalancingPolicy)
+    assert default != policy
+
+
+def test_execution_profile_retry_policy():
+    for policy in [DefaultRetryPolicy(), DowngradingConsistencyRetryPolicy()]:
+        assert isinstance(policy, RetryPolicy)
+        assert repr(policy) == f"{type(policy).__name__}()"
+        assert isinstance(ExecutionProfile(retry_policy=policy), ExecutionProfile)
+
+    with pytest.raises(TypeError):
+        ExecutionProfile(retry_policy=LoadBalancingPolicy())  # pyright: ignore[reportArgumentType]
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_downgrading_consistency_retry_policy():
+    # The test cluster has a single node, so THREE fails with Unavailable
+    # and the policy retries at ONE.
+    profile = ExecutionProfile(consistency=Consistency.Three, retry_policy=DowngradingConsistencyRetryPolicy())
+    with await SessionBuilder().contact_points([("127.0.0.2", 9042)]).execution_profile(profile).connect() as session:
+        statement = Statement("SELECT * FROM system.local").with_is_idempotent(True)
+        result = await session.execute(statement)
+        assert await result.all()
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_create_session_with_profile():
@@ -195,6 +277,25 @@ def test_statement_with_and_get_execution_profile():
     assert actual_profile.request_timeout == expected_timeout
 
 
+def test_execution_profile_shared_by_many_statements():
+    profile = ExecutionProfile(timeout=4.0, consistency=Consistency.One)
+    statements = [Statement(f"SELECT * FROM system.local WHERE key = '{i}'") for i in range(1000)]
+    statements = [stmt.with_execution_profile(profile) for stmt in statements]
+
+    for stmt in statements:
+        actual_profile = stmt.execution_profile
+        assert actual_profile is not None
+        assert actual_profile.request_timeout == 4.0
+        assert actual_profile.consistency == Consistency.One
+
+    # A profile read back from a statement can be applied to other statements as well.
+    reused = statements[0].execution_profile
+    assert reused is not None
+    batch = Batch().with_execution_profile(reused)
+    assert batch.execution_profile is not None
+    assert batch.execution_profile.request_timeout == 4.0
+
+
 def test_statement_without_execution_profile():
     stmt = Statement("SELECT * FROM system.local")
     profile = ExecutionProfile(timeout=3.0)
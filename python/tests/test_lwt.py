@@ -0,0 +1,76 @@
+from typing import AsyncGenerator, Awaitable, Callable
+
+import pytest
+import pytest_asyncio
+from scylla.errors import LwtResultError
+from scylla.session import Session
+from scylla.session_builder import SessionBuilder
+
+
+async def set_up() -> Session:
+    session = await SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect()
+
+    # Lightweight transactions are not supported on tablet keyspaces.
+    await session.execute("""
+            CREATE KEYSPACE IF NOT EXISTS testks_lwt
+            WITH replication = {'class': 'NetworkTopologyStrategy', 'replication_factor': 1}
+            AND tablets = {'enabled': false};
+        """)
+
+    await session.use_keyspace("testks_lwt")
+
+    return session
+
+
+@pytest_asyncio.fixture(scope="module")
+async def session():
+    session = await set_up()
+    yield session
+    await session.execute("DROP KEYSPACE testks_lwt")
+    session.shutdown()
+
+
+TableFactory = Callable[[str, str], Awaitable[str]]
+
+
+@pytest_asyncio.fixture
+async def table_factory(session: Session) -> AsyncGenerator[TableFactory, None]:
+    created_tables: list[str] = []
+
+    async def create_table(schema: str, name: str) -> str:
+        await session.execute(f"CREATE TABLE IF NOT EXISTS {name} ({schema});")
+        created_tables.append(name)
+        return name
+
+    yield create_table
+
+    for table in created_tables:
+        await session.execute(f"DROP TABLE IF EXISTS {table};")
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_lwt_result(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, name text",
+        "lwt_users",
+    )
+
+    query_str = f"INSERT INTO {table} (id, name) VALUES (?, ?) IF NOT EXISTS"
+
+    res = await session.execute(query_str, (1, "Alice"))
+    lwt = res.lwt_result()
+    assert lwt.applied
+    assert lwt
+    # The row returned for an applied statement depends on the server.
+    assert set(lwt.current_row) <= {"id", "name"}
+
+    res = await session.execute(query_str, (1, "Bob"))
+    lwt = res.lwt_result()
+    assert not lwt.applied
+    assert not lwt
+    assert lwt.current_row == {"id": 1, "name": "Alice"}
+
+    res = await session.execute(f"SELECT * FROM {table}")
+    with pytest.raises(LwtResultError):
+        res.lwt_result()
@@ -1,7 +1,10 @@
+import zlib
+from datetime import datetime, timezone
 from typing import Any, AsyncGenerator, Awaitable, Callable
 
 import pytest
 import pytest_asyncio
+from scylla.errors import ExecuteError
 from scylla.results import PagingState
 from scylla.session import Session
 from scylla.session_builder import SessionBuilder
@@ -27,6 +30,7 @@ async def session():
     session = await set_up()
     yield session
     await session.execute("DROP KEYSPACE testks")
+    session.shutdown()
 
 
 TableFactory = Callable[[str, str], Awaitable[str]]
@@ -234,6 +238,145 @@ async def test_paging_state_resume(
     assert ids_first.isdisjoint(ids_second)
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_manual_pagination_loop(
+    session: Session,
+    table_factory: TableFactory,
+):
+    table = await table_factory(
+        "id int PRIMARY KEY, x int",
+        "manual_pagination_table",
+    )
+
+    await insert_rows(session, table, 7)
+
+    prepared = (await session.prepare(f"SELECT * FROM {table}")).with_page_size(3)
+
+    seen_ids: list[int] = []
+    pages = 0
+    state: PagingState | None = None
+    while True:
+        result = await session.execute(prepared, paging_state=state)
+        seen_ids.extend(row["id"] for row in result.iter_current_page())
+        pages += 1
+
+        state = result.paging_state()
+        if not result.has_more_pages():
+            assert state is None
+            break
+        assert state is not None
+
+    assert pages >= 3
+    assert sorted(seen_ids) == list(range(7))
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_pagination_resumes_from_serialized_paging_state(
+    session: Session,
+    table_factory: TableFactory,
+):
+    table = await table_factory(
+        "id int PRIMARY KEY, x int",
+        "serialized_paging_state_table",
+    )
+
+    await insert_rows(session, table, 7)
+
+    prepared = (await session.prepare(f"SELECT * FROM {table}")).with_page_size(3)
+
+    seen_ids: list[int] = []
+    stored = PagingState().to_bytes()
+    while True:
+        result = await session.execute(prepared, paging_state=PagingState.from_bytes(stored))
+        seen_ids.extend(row["id"] for row in result.iter_current_page())
+
+        state = result.paging_state()
+        if state is None:
+            break
+        stored = state.to_bytes()
+
+    assert sorted(seen_ids) == list(range(7))
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_async_iterator_paging_state_checkpoint(
+    session: Session,
+    table_factory: TableFactory,
+):
+    table = await table_factory(
+        "id int PRIMARY KEY, x int",
+        "async_iterator_checkpoint_table",
+    )
+
+    await insert_rows(session, table, 7)
+
+    prepared = (await session.prepare(f"SELECT * FROM {table}")).with_page_size(3)
+
+    # Consume exactly the first page and take a checkpoint.
+    rows = aiter(await session.execute(prepared))
+    seen_ids = [(await anext(rows))["id"] for _ in range(3)]
+    checkpoint = rows.paging_state()
+    assert checkpoint is not None
+
+    # Resume from the checkpoint, e.g. after a restart.
+    stored = checkpoint.to_bytes()
+    resumed = aiter(await session.execute(prepared, paging_state=PagingState.from_bytes(stored)))
+    seen_ids.extend([row["id"] async for row in resumed])
+
+    assert sorted(seen_ids) == list(range(7))
+    # A fully consumed iterator has no paging state.
+    assert resumed.paging_state() is None
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_paging_state_of_other_statement_is_rejected(
+    session: Session,
+    table_factory: TableFactory,
+):
+    table = await table_factory(
+        "id int PRIMARY KEY, x int",
+        "foreign_paging_state_table",
+    )
+
+    await insert_rows(session, table, 7)
+
+    prepared = (await session.prepare(f"SELECT * FROM {table}")).with_page_size(3)
+    state = (await session.execute(prepared)).paging_state()
+    assert state is not None
+    restored = PagingState.from_bytes(state.to_bytes())
+
+    other = Statement(f"SELECT id FROM {table}").with_page_size(3)
+    with pytest.raises(ExecuteError, match="different statement"):
+        await session.execute(other, paging_state=restored)
+
+    # The same statement text resumes the state, prepared or not.
+    result = await session.execute(Statement(f"SELECT * FROM {table}").with_page_size(3), paging_state=restored)
+    assert len(list(result.iter_current_page())) == 3
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_unpaged_result_has_no_more_pages(
+    session: Session,
+    table_factory: TableFactory,
+):
+    table = await table_factory(
+        "id int PRIMARY KEY, x int",
+        "unpaged_has_more_pages_table",
+    )
+
+    await insert_rows(session, table, 5)
+
+    result = await session.execute(f"SELECT * FROM {table}", paged=False)
+
+    assert result.has_more_pages() is False
+    assert result.paging_state() is None
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 @pytest.mark.parametrize("total_rows,page_size", [(0, 10), (5, 2), (25, 10), (1000, 10)])
@@ -326,43 +469,210 @@ async def test_first_for_non_rows_result_returns_none(
     assert row_all == []
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_execute_unpaged_max_rows(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, x int",
+        "paging_max_rows_table",
+    )
+
+    await insert_rows(session, table, 10)
+
+    result = await session.execute(f"SELECT * FROM {table}", paged=False, max_rows=10)
+    assert len(await result.all()) == 10
+
+    with pytest.raises(ExecuteError, match="max_rows=9"):
+        await session.execute(f"SELECT * FROM {table}", paged=False, max_rows=9)
+
+    with pytest.raises(ExecuteError):
+        await session.execute(f"SELECT * FROM {table}", max_rows=10)
+
+
 def test_paging_state_new_is_start_state():
     state = PagingState()
 
     assert state.as_bytes() is None
 
 
+def serialized_paging_state(raw: bytes | None, query: str | None = None) -> bytes:
+    """Builds `PagingState.to_bytes()` output by hand, as if obtained for `query`."""
+    flags = (1 if raw is not None else 0) | (2 if query is not None else 0)
+    data = b"SCPS\x01" + bytes([flags])
+    if query is not None:
+        data += zlib.crc32(query.encode()).to_bytes(4, "big")
+    data += raw or b""
+    return data + zlib.crc32(data).to_bytes(4, "big")
+
+
 def test_paging_state_from_bytes_roundtrip():
     raw = b"\x01\x02\x03\x04"
 
-    state = PagingState.from_bytes(raw)
+    state = PagingState.from_bytes(serialized_paging_state(raw))
 
     assert state.as_bytes() == raw
 
 
 def test_paging_state_start_and_from_bytes_are_not_equal():
     start_state = PagingState()
-    resumed_state = PagingState.from_bytes(b"\x01\x02\x03")
+    resumed_state = PagingState.from_bytes(serialized_paging_state(b"\x01\x02\x03"))
 
     assert start_state != resumed_state
 
 
 def test_paging_state_equal_for_same_raw_bytes():
-    state1 = PagingState.from_bytes(b"\x01\x02\x03")
-    state2 = PagingState.from_bytes(b"\x01\x02\x03")
+    state1 = PagingState.from_bytes(serialized_paging_state(b"\x01\x02\x03"))
+    state2 = PagingState.from_bytes(serialized_paging_state(b"\x01\x02\x03"))
 
     assert state1 == state2
 
 
 def test_paging_state_not_equal_for_different_raw_bytes():
-    state1 = PagingState.from_bytes(b"\x01\x02\x03")
-    state2 = PagingState.from_bytes(b"\x04\x05\x06")
+    state1 = PagingState.from_bytes(serialized_paging_state(b"\x01\x02\x03"))
+    state2 = PagingState.from_bytes(serialized_paging_state(b"\x04\x05\x06"))
+
+    assert state1 != state2
+
+
+def test_paging_state_not_equal_for_different_statements():
+    state1 = PagingState.from_bytes(serialized_paging_state(b"\x01\x02\x03", "SELECT * FROM t1"))
+    state2 = PagingState.from_bytes(serialized_paging_state(b"\x01\x02\x03", "SELECT * FROM t2"))
 
     assert state1 != state2
 
 
 def test_paging_state_from_empty_bytes_is_not_start_state():
-    state = PagingState.from_bytes(b"")
+    state = PagingState.from_bytes(serialized_paging_state(b""))
 
     assert state.as_bytes() == b""
     assert state != PagingState()
+
+
+@pytest.mark.parametrize(
+    "data",
+    [
+        serialized_paging_state(None),
+        serialized_paging_state(b""),
+        serialized_paging_state(b"\x01\x02", "SELECT * FROM t"),
+    ],
+)
+def test_paging_state_to_bytes_roundtrip(data: bytes):
+    state = PagingState.from_bytes(data)
+
+    assert state.to_bytes() == data
+    assert PagingState.from_bytes(state.to_bytes()) == state
+
+
+def test_paging_state_from_bytes_rejects_raw_bytes():
+    with pytest.raises(ValueError, match="to_bytes"):
+        PagingState.from_bytes(b"\x01\x02\x03")
+
+
+def test_paging_state_from_bytes_rejects_corrupted_bytes():
+    data = serialized_paging_state(b"\x01\x02\x03", "SELECT * FROM t")
+
+    with pytest.raises(ValueError, match="corrupted"):
+        PagingState.from_bytes(data[:-1])
+    with pytest.raises(ValueError, match="corrupted"):
+        PagingState.from_bytes(data[:-5] + b"\xff" + data[-4:])
+
+
+def test_paging_state_from_bytes_rejects_unknown_version():
+    data = PagingState().to_bytes()
+
+    with pytest.raises(ValueError, match="version 2"):
+        PagingState.from_bytes(data[:4] + b"\x02" + data[5:])
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_request_result_bool_and_len(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, x int",
+        "paging_bool_len_table",
+    )
+
+    empty = await session.execute(f"SELECT * FROM {table}")
+    assert not empty
+    assert len(empty) == 0
+
+    await insert_rows(session, table, 3)
+
+    result = await session.execute(f"SELECT * FROM {table}")
+    assert result
+    assert len(result) == 3
+    # Neither check consumes the rows.
+    assert len(await result.all()) == 3
+
+    paged = await session.execute(Statement(f"SELECT * FROM {table}").with_page_size(2))
+    assert paged
+    with pytest.raises(TypeError, match="materialize"):
+        len(paged)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_request_result_bool_of_empty_pages(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, x int",
+        "paging_bool_empty_pages_table",
+    )
+    await insert_rows(session, table, 5)
+
+    # No row matches, but the server may return empty pages with a paging state.
+    page = await session.execute(Statement(f"SELECT * FROM {table} WHERE x = -1 ALLOW FILTERING").with_page_size(1))
+    while page is not None:
+        if page.has_more_pages():
+            with pytest.raises(TypeError, match="more pages"):
+                bool(page)
+        else:
+            assert not page
+        page = await page.fetch_next_page()
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_to_pandas(session: Session, table_factory: TableFactory):
+    pd = pytest.importorskip("pandas")
+
+    table = await table_factory(
+        "id int PRIMARY KEY, big bigint, ratio double, name text, created timestamp, tags list<text>",
+        "to_pandas_table",
+    )
+    created = datetime(2024, 5, 6, 7, 8, 9, 123000, tzinfo=timezone.utc)
+    insert = f"INSERT INTO {table} (id, big, ratio, name, created, tags) VALUES (?, ?, ?, ?, ?, ?)"
+    await session.execute(insert, [1, 2**40, 0.5, "a", created, ["x"]])
+    await session.execute(insert, [2, None, None, None, None, None])
+    await session.execute(insert, [3, -1, 1.5, "c", created, ["y", "z"]])
+
+    statement = Statement(f"SELECT id, big, ratio, name, created, tags FROM {table}").with_page_size(2)
+    df = await (await session.execute(statement)).to_pandas()
+    df = df.sort_values("id").reset_index(drop=True)
+
+    assert list(df.columns) == ["id", "big", "ratio", "name", "created", "tags"]
+    assert str(df["id"].dtype) == "Int32"
+    assert str(df["big"].dtype) == "Int64"
+    assert str(df["ratio"].dtype) == "Float64"
+    assert str(df["name"].dtype) == "string"
+    assert str(df["created"].dtype) == "datetime64[ms, UTC]"
+    assert df["tags"].dtype == object
+
+    assert df["id"].tolist() == [1, 2, 3]
+    assert df["big"][0] == 2**40
+    assert df["created"][0] == pd.Timestamp(created)
+    assert df["tags"][2] == ["y", "z"]
+    assert df.iloc[1][["big", "ratio", "name", "created"]].isna().all()
+    assert df["tags"][1] is None
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_to_pandas_for_non_rows_result(session: Session, table_factory: TableFactory):
+    pd = pytest.importorskip("pandas")
+
+    table = await table_factory("id int PRIMARY KEY, x int", "to_pandas_non_rows_table")
+    result = await session.execute(f"INSERT INTO {table} (id, x) VALUES (1, 1)")
+
+    df = await result.to_pandas()
+    assert isinstance(df, pd.DataFrame)
+    assert df.empty
@@ -1,6 +1,9 @@
 import pytest
+from scylla.cluster.metadata import CqlText
+from scylla.enums import Consistency
+from scylla.errors import SerializationError
 from scylla.session_builder import SessionBuilder
-from scylla.statement import PreparedStatement
+from scylla.statement import PreparedStatement, Statement
 
 
 @pytest.mark.asyncio
@@ -46,3 +49,34 @@ async def test_prepared_with_and_get_page_size():
 
     assert isinstance(actual_page_size, int)
     assert actual_page_size == expected_page_size
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_prepare_statement_keeps_configuration_and_variable_columns():
+    with await SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect() as session:
+        statement = Statement("SELECT * FROM system.local WHERE key = :k").with_consistency(Consistency.One)
+
+        prepared = await session.prepare(statement)
+
+        assert prepared.consistency == Consistency.One
+        [(name, typ)] = prepared.variable_columns
+        assert name == "k"
+        assert isinstance(typ, CqlText)
+        assert (await session.prepare("SELECT * FROM system.local")).variable_columns == []
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_prepared_validate_values():
+    with await SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect() as session:
+        prepared = await session.prepare("SELECT * FROM system.local WHERE key = ? LIMIT ?")
+
+        assert prepared.validate_values(["local", 1]) is None
+
+        error = prepared.validate_values(["local", "one"])
+        assert isinstance(error, SerializationError)
+        assert "parameter_index=1" in str(error)
+
+        assert isinstance(prepared.validate_values(["local"]), SerializationError)
+        assert isinstance(prepared.validate_values(), SerializationError)
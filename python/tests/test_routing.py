@@ -0,0 +1,82 @@
+import pytest
+from scylla.routing import Sharder, Token
+
+MIN_TOKEN = -(2**63)
+MAX_TOKEN = 2**63 - 1
+
+
+def test_token_ordering():
+    assert Token(-1) < Token(0) < Token(1)
+    assert Token(0) <= Token(0)
+    assert Token(0) >= Token(0)
+    assert Token(1) > Token(-1)
+    assert not Token(0) < Token(0)
+    assert not Token(1) <= Token(0)
+
+
+def test_token_ordering_at_ring_boundaries():
+    lowest = Token(MIN_TOKEN)
+    highest = Token(MAX_TOKEN)
+
+    assert lowest < Token(MIN_TOKEN + 1) < Token(0) < Token(MAX_TOKEN - 1) < highest
+    # The ring wraps around between the highest and the lowest token,
+    # but the ordering itself does not.
+    assert highest > lowest
+    assert min(highest, lowest) == lowest
+    assert max(highest, lowest) == highest
+
+
+def test_tokens_sort_by_value():
+    values = [MAX_TOKEN, 0, MIN_TOKEN, -1, 1, MIN_TOKEN + 1, MAX_TOKEN - 1]
+    tokens = sorted(Token(value) for value in values)
+    assert [token.value for token in tokens] == sorted(values)
+
+
+def test_token_equality_and_hash():
+    assert Token(42) == Token(42)
+    assert Token(42) != Token(43)
+    assert Token(MIN_TOKEN) != Token(MAX_TOKEN)
+    assert hash(Token(42)) == hash(Token(42))
+    assert Token(0) != 0
+
+    owners = {Token(value): value for value in [MIN_TOKEN, -2, -1, 0, MAX_TOKEN]}
+    assert len(owners) == 5
+    assert owners[Token(-1)] == -1
+    assert owners[Token(MIN_TOKEN)] == MIN_TOKEN
+    assert owners[Token(MAX_TOKEN)] == MAX_TOKEN
+
+
+def test_token_repr():
+    assert repr(Token(MIN_TOKEN)) == f"Token({MIN_TOKEN})"
+    assert repr(Token(MAX_TOKEN)) == f"Token({MAX_TOKEN})"
+
+
+def test_token_comparison_with_other_types():
+    with pytest.raises(TypeError):
+        _ = Token(0) < 1  # pyright: ignore[reportOperatorIssue]
+
+
+@pytest.mark.parametrize("value", [MIN_TOKEN - 1, MAX_TOKEN + 1])
+def test_token_out_of_range(value: int):
+    with pytest.raises(OverflowError):
+        Token(value)
+
+
+def test_sharder_bulk_source_ports_match_single():
+    sharder = Sharder(nr_shards=8, msb_ignore=12)
+    ports = [0, 1, 7, 8, 9, 1024, 49151, 65535]
+
+    assert sharder.shard_of_source_ports(ports) == [sharder.shard_of_source_port(port) for port in ports]
+    assert sharder.shard_of_source_ports([]) == []
+    assert sharder.shard_of_source_port(9) == 1
+
+
+def test_sharder_rejects_zero_shards():
+    with pytest.raises(ValueError):
+        Sharder(nr_shards=0, msb_ignore=12)
+
+
+@pytest.mark.parametrize("msb_ignore", [64, 255])
+def test_sharder_rejects_msb_ignore_out_of_range(msb_ignore: int):
+    with pytest.raises(ValueError, match="msb_ignore"):
+        Sharder(nr_shards=8, msb_ignore=msb_ignore)
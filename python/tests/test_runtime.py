@@ -0,0 +1,18 @@
+import pytest
+from _pytest.logging import LogCaptureFixture
+from scylla.runtime import configure_runtime, runtime_initialized
+from scylla.session_builder import SessionBuilder
+
+
+def test_configure_runtime_rejects_zero_worker_threads():
+    with pytest.raises(ValueError):
+        configure_runtime(worker_threads=0)
+
+
+@pytest.mark.requires_db
+def test_configure_runtime_after_initialization_warns(caplog: LogCaptureFixture):
+    with SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect_blocking():
+        assert runtime_initialized()
+
+    configure_runtime(worker_threads=2)
+    assert "configure_runtime() has no effect" in caplog.text
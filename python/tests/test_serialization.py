@@ -1,7 +1,7 @@
 import ipaddress
 import uuid
 from dataclasses import asdict, dataclass
-from datetime import date, datetime, time, timezone
+from datetime import date, datetime, time, timedelta, timezone
 from decimal import Decimal
 from typing import AsyncGenerator, Awaitable, Callable, List, Optional, Tuple
 
@@ -11,9 +11,21 @@ from dateutil.relativedelta import relativedelta
 
 # SerializationError is never raised directly, but it shapes the error message.
 # We import ExecuteError which is raised for serialization issues during query execution.
-from scylla.errors import ExecuteError
+from scylla.cluster.metadata import CqlInt as CqlIntType
+from scylla.cluster.metadata import CqlList, CqlMap, CqlText, CqlTuple, CqlUserDefinedType
+from scylla.errors import (
+    DeserializationError,
+    ExecuteError,
+    PySerializationFailedError,
+    SerializationError,
+    ValueOverflowSerializationError,
+)
+from scylla.results import RowFactory
 from scylla.session import Session
 from scylla.session_builder import SessionBuilder
+from scylla.statement import Statement
+from scylla.types import Unset
+from scylla.value import CqlBigInt, CqlCounter, CqlInt, CqlSmallInt, CqlTinyInt, CqlValue, serialize_value
 
 
 async def set_up() -> Session:
@@ -34,6 +46,7 @@ async def session():
     session = await set_up()
     yield session
     await session.execute("DROP KEYSPACE testks")
+    session.shutdown()
 
 
 TableFactory = Callable[[str, str], Awaitable[str]]
@@ -108,6 +121,28 @@ async def test_ascii_serialization(session: Session, table_factory: TableFactory
     await session.execute(f"SELECT * from {table}")
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize("value,index", [("café", 3), ("żółw", 0), ("tab\there 🚀", 9)])
+async def test_ascii_serialization_rejects_non_ascii(
+    session: Session, table_factory: TableFactory, value: str, index: int
+):
+    table = await table_factory(
+        "id int PRIMARY KEY, col ascii, tags list<ascii>",
+        "ascii_non_ascii_table",
+    )
+
+    with pytest.raises(ExecuteError, match=rf"non-ASCII character at index {index}"):
+        await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, value))
+
+    # Nested values are validated as well.
+    with pytest.raises(ExecuteError, match=r"non-ASCII character"):
+        await session.execute(f"INSERT INTO {table} (id, tags) VALUES (?, ?)", (1, ["ok", value]))
+
+    # The full ASCII range, including control characters, is accepted.
+    await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, "".join(map(chr, range(128)))))
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_boolean_serialization(session: Session, table_factory: TableFactory):
@@ -136,6 +171,20 @@ async def test_blob_serialization(session: Session, table_factory: TableFactory)
     await session.execute(f"SELECT * from {table}")
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_blob_serialization_rejects_str(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, col blob",
+        "blob_table",
+    )
+
+    with pytest.raises(ExecuteError, match=r"encode the text first"):
+        await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, "hello world"))
+
+    await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, "hello world".encode("utf-8")))
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_date_serialization(session: Session, table_factory: TableFactory):
@@ -164,6 +213,19 @@ async def test_decimal_serialization(session: Session, table_factory: TableFacto
     await session.execute(f"SELECT * from {table}")
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize("value", ["NaN", "sNaN", "Infinity", "-Infinity"])
+async def test_decimal_special_values_rejected(session: Session, table_factory: TableFactory, value: str):
+    table = await table_factory(
+        "id int PRIMARY KEY, col decimal",
+        "decimal_special_table",
+    )
+
+    with pytest.raises(ExecuteError, match="no representation of NaN or infinity"):
+        await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, Decimal(value)))
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_double_serialization(session: Session, table_factory: TableFactory):
@@ -192,6 +254,70 @@ async def test_duration_serialization(session: Session, table_factory: TableFact
     await session.execute(f"SELECT * from {table}")
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize(
+    "val",
+    [
+        relativedelta(months=2, days=5, microseconds=36),
+        relativedelta(months=-2, days=-5, microseconds=-36),
+        relativedelta(months=14, hours=5, minutes=6, seconds=7, microseconds=8),
+        relativedelta(years=-3, months=-1, days=-40, hours=-23, minutes=-59, seconds=-59, microseconds=-999_999),
+        relativedelta(years=1000, days=2_000_000_000, microseconds=9_000_000_000_000_000),
+        relativedelta(microseconds=-1),
+    ],
+)
+async def test_duration_roundtrip(session: Session, table_factory: TableFactory, val: relativedelta):
+    table = await table_factory(
+        "id int PRIMARY KEY, col duration",
+        "duration_roundtrip_table",
+    )
+
+    await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, val))
+    result = await session.execute(f"SELECT col FROM {table} WHERE id = 1")
+
+    assert (await result.all())[0]["col"] == val
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize(
+    "val",
+    [
+        timedelta(days=3, hours=4, minutes=5, seconds=6, microseconds=7),
+        timedelta(seconds=-1),
+        timedelta(days=-2, microseconds=1),
+        timedelta(0),
+    ],
+)
+async def test_timedelta_duration_roundtrip(session: Session, table_factory: TableFactory, val: timedelta):
+    table = await table_factory(
+        "id int PRIMARY KEY, col duration",
+        "timedelta_duration_table",
+    )
+
+    await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, val))
+    result = await session.execute(f"SELECT col FROM {table} WHERE id = 1")
+
+    # Durations are read as `relativedelta`, which has no months here, so both
+    # shift a point in time by the same amount.
+    read = (await result.all())[0]["col"]
+    assert read.months == 0
+    assert datetime(2000, 1, 1) + read == datetime(2000, 1, 1) + val
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_duration_serialization_overflow(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, col duration",
+        "duration_overflow_table",
+    )
+
+    with pytest.raises(ExecuteError):
+        await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, relativedelta(years=200_000_000)))
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_float_serialization(session: Session, table_factory: TableFactory):
@@ -266,6 +392,38 @@ async def test_bigint_serialization_overflow(session: Session, table_factory: Ta
     assert "value overflow during serialization" in str(exc_info.value).lower()
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize("val", [2**63 - 1, -(2**63)])
+async def test_bigint_serialization_boundaries(session: Session, table_factory: TableFactory, val: int):
+    table = await table_factory(
+        "id int PRIMARY KEY, col bigint",
+        "bigint_table_boundaries",
+    )
+
+    await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, val))
+    result = await session.execute(f"SELECT col FROM {table} WHERE id = 1")
+
+    assert (await result.all())[0]["col"] == val
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize("val", [2**63, -(2**63) - 1])
+async def test_bigint_serialization_overflow_states_range(session: Session, table_factory: TableFactory, val: int):
+    table = await table_factory(
+        "id int PRIMARY KEY, col bigint",
+        "bigint_table_overflow_range",
+    )
+
+    with pytest.raises(ExecuteError) as exc_info:
+        await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, val))
+
+    message = str(exc_info.value)
+    assert f"{val} is out of range for CQL bigint" in message
+    assert "from -9223372036854775808 to 9223372036854775807" in message
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_text_serialization(session: Session, table_factory: TableFactory):
@@ -294,6 +452,75 @@ async def test_timestamp_serialization(session: Session, table_factory: TableFac
     await session.execute(f"SELECT * from {table}")
 
 
+class CustomDate(date):
+    pass
+
+
+class CustomDatetime(datetime):
+    pass
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_date_subclass_serialization(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, col date",
+        "date_subclass_table",
+    )
+
+    await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, CustomDate(2004, 6, 16)))
+    result = await session.execute(f"SELECT col FROM {table} WHERE id = 1")
+    row = await result.first_row()
+
+    assert row["col"] == date(2004, 6, 16)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_datetime_subclass_serialization(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, col timestamp",
+        "timestamp_subclass_table",
+    )
+
+    val = CustomDatetime(2024, 3, 1, 12, 30, 15, 123000, tzinfo=timezone.utc)
+
+    await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, val))
+    result = await session.execute(f"SELECT col FROM {table} WHERE id = 1")
+    row = await result.first_row()
+
+    assert row["col"] == datetime(2024, 3, 1, 12, 30, 15, 123000, tzinfo=timezone.utc)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_timestamp_with_non_utc_tzinfo_serialization(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, col timestamp",
+        "timestamp_offset_table",
+    )
+
+    val = CustomDatetime(2024, 3, 1, 14, 30, tzinfo=timezone(timedelta(hours=2)))
+
+    await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, val))
+    result = await session.execute(f"SELECT col FROM {table} WHERE id = 1")
+    row = await result.first_row()
+
+    assert row["col"] == datetime(2024, 3, 1, 12, 30, tzinfo=timezone.utc)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_naive_datetime_subclass_is_rejected(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, col timestamp",
+        "timestamp_naive_subclass_table",
+    )
+
+    with pytest.raises(ExecuteError):
+        await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, CustomDatetime(2024, 3, 1)))
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_inet_serialization(session: Session, table_factory: TableFactory):
@@ -310,6 +537,23 @@ async def test_inet_serialization(session: Session, table_factory: TableFactory)
     await session.execute(f"SELECT * from {table}")
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize("network", [ipaddress.ip_network("10.0.0.0/8"), ipaddress.ip_network("::1/128")])
+async def test_inet_rejects_ip_network(
+    session: Session, table_factory: TableFactory, network: ipaddress.IPv4Network | ipaddress.IPv6Network
+):
+    table = await table_factory(
+        "id int PRIMARY KEY, col inet",
+        "inet_network_table",
+    )
+
+    with pytest.raises(ExecuteError, match=r"pass an ipaddress\.ip_address"):
+        await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, network))
+
+    await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, network.network_address))
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_smallint_serialization(session: Session, table_factory: TableFactory):
@@ -358,6 +602,142 @@ async def test_counter_serialization(session: Session, table_factory: TableFacto
     await session.execute(f"SELECT * from {table}")
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.skip(reason="Counter currently does not support NetworkTopologyStrategy; enable when supported")
+async def test_cql_counter_serialization(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, col counter",
+        "cql_counter_table",
+    )
+
+    await session.execute(f"UPDATE {table} SET col = col + ? WHERE id = ?", (CqlCounter(5), 1))
+    await session.execute(f"UPDATE {table} SET col = col + ? WHERE id = ?", (3, 1))
+    result = await session.execute(f"SELECT col FROM {table} WHERE id = 1")
+    row = await result.first_row()
+
+    assert row["col"] == 8
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.skip(reason="Counter currently does not support NetworkTopologyStrategy; enable when supported")
+async def test_counter_column_rejects_non_integer(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, col counter",
+        "counter_rejects_float_table",
+    )
+
+    with pytest.raises(ExecuteError) as exc_info:
+        await session.execute(f"UPDATE {table} SET col = col + ? WHERE id = ?", (1.5, 1))
+
+    assert "type mismatch" in str(exc_info.value).lower()
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_cql_counter_rejected_for_non_counter_column(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, col bigint",
+        "cql_counter_mismatch_table",
+    )
+
+    with pytest.raises(ExecuteError) as exc_info:
+        await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, CqlCounter(5)))
+
+    assert "type mismatch" in str(exc_info.value).lower()
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_explicit_integer_width_serialization(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, t tinyint, s smallint, b bigint",
+        "explicit_integer_width_table",
+    )
+
+    await session.execute(
+        f"INSERT INTO {table} (id, t, s, b) VALUES (?, ?, ?, ?)",
+        (CqlInt(1), CqlTinyInt(-128), CqlSmallInt(32767), CqlBigInt(-(2**63))),
+    )
+    result = await session.execute(f"SELECT t, s, b FROM {table} WHERE id = 1")
+
+    assert await result.first_row() == {"t": -128, "s": 32767, "b": -(2**63)}
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_explicit_integer_width_rejected_for_other_column(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, col bigint",
+        "explicit_integer_width_mismatch_table",
+    )
+
+    with pytest.raises(ExecuteError) as exc_info:
+        await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, CqlSmallInt(5)))
+
+    assert "type mismatch" in str(exc_info.value).lower()
+
+
+@pytest.mark.parametrize(
+    "cls,low,high",
+    [
+        (CqlTinyInt, -(2**7), 2**7 - 1),
+        (CqlSmallInt, -(2**15), 2**15 - 1),
+        (CqlInt, -(2**31), 2**31 - 1),
+        (CqlBigInt, -(2**63), 2**63 - 1),
+    ],
+)
+def test_explicit_integer_width_validates_range(cls: type[CqlTinyInt], low: int, high: int):
+    assert cls(low).value == low
+    assert int(cls(high)) == high
+    assert cls(high) == cls(high)
+
+    with pytest.raises(ValueOverflowSerializationError, match=f"from {low} to {high}"):
+        cls(high + 1)
+    with pytest.raises(ValueOverflowSerializationError):
+        cls(low - 1)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_cql_value_binds_exact_values(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, s smallint, a ascii, ts timestamp, d date",
+        "cql_value_table",
+    )
+
+    await session.execute(
+        f"INSERT INTO {table} (id, s, a, ts, d) VALUES (?, ?, ?, ?, ?)",
+        (CqlValue.int(1), CqlValue.smallint(-5), CqlValue.ascii("abc"), CqlValue.timestamp(0), CqlValue.date(1)),
+    )
+    result = await session.execute(f"SELECT s, a, ts, d FROM {table} WHERE id = 1")
+
+    assert await result.first_row() == {
+        "s": -5,
+        "a": "abc",
+        "ts": datetime(1970, 1, 1, tzinfo=timezone.utc),
+        "d": date(1970, 1, 2),
+    }
+
+    with pytest.raises(ExecuteError) as exc_info:
+        await session.execute(f"INSERT INTO {table} (id, s) VALUES (?, ?)", (1, CqlValue.int(5)))
+
+    assert "type mismatch" in str(exc_info.value).lower()
+
+
+def test_cql_value_validates_on_construction():
+    assert CqlValue.smallint(5) == CqlValue.smallint(5)
+    assert CqlValue.smallint(5) != CqlValue.int(5)
+
+    with pytest.raises(OverflowError):
+        CqlValue.tinyint(128)
+    with pytest.raises(OverflowError):
+        CqlValue.time(86_400_000_000_000)
+    with pytest.raises(ValueError, match="non-ASCII"):
+        CqlValue.ascii("zażółć")
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_tinyint_serialization(session: Session, table_factory: TableFactory):
@@ -402,6 +782,154 @@ async def test_time_serialization(session: Session, table_factory: TableFactory)
     await session.execute(f"SELECT * from {table}")
 
 
+class NanosecondTimeRowFactory(RowFactory):
+    time_as_nanoseconds = True
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize(
+    "nanoseconds",
+    [0, 1, 999, 5_445_123_456_789, 86_399_999_999_999],
+)
+async def test_time_nanoseconds_roundtrip(session: Session, table_factory: TableFactory, nanoseconds: int):
+    table = await table_factory(
+        "id int PRIMARY KEY, col time",
+        "time_nanoseconds_table",
+    )
+
+    await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, nanoseconds))
+
+    query = f"SELECT col FROM {table} WHERE id = 1"
+    row = await (await session.execute(query, factory=NanosecondTimeRowFactory())).first_row()
+    assert row["col"] == nanoseconds
+
+    # `datetime.time` truncates the value to microseconds.
+    row = await (await session.execute(query)).first_row()
+    micros = nanoseconds // 1000
+    assert row["col"] == time(
+        micros // 3_600_000_000,
+        micros // 60_000_000 % 60,
+        micros // 1_000_000 % 60,
+        micros % 1_000_000,
+    )
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize("nanoseconds", [-1, 86_400_000_000_000, 2**63])
+async def test_time_nanoseconds_out_of_range(session: Session, table_factory: TableFactory, nanoseconds: int):
+    table = await table_factory(
+        "id int PRIMARY KEY, col time",
+        "time_nanoseconds_range_table",
+    )
+
+    with pytest.raises(ExecuteError) as exc_info:
+        await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, nanoseconds))
+
+    assert "value overflow during serialization" in str(exc_info.value).lower()
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_time_rejects_bool(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, col time",
+        "time_bool_table",
+    )
+
+    # `bool` is an `int` subclass, but must not be taken for nanoseconds since midnight.
+    with pytest.raises(ExecuteError):
+        await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, True))
+
+
+class DaysDateRowFactory(RowFactory):
+    date_as_days = True
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize(
+    "days,value",
+    [
+        (0, date(1970, 1, 1)),
+        (-1, date(1969, 12, 31)),
+        (19_783, date(2024, 3, 1)),
+        ((date.max - date(1970, 1, 1)).days, date.max),
+        ((date.min - date(1970, 1, 1)).days, date.min),
+    ],
+)
+async def test_date_days_roundtrip(session: Session, table_factory: TableFactory, days: int, value: date):
+    table = await table_factory(
+        "id int PRIMARY KEY, col date",
+        "date_days_table",
+    )
+
+    await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, days))
+
+    query = f"SELECT col FROM {table} WHERE id = 1"
+    row = await (await session.execute(query, factory=DaysDateRowFactory())).first_row()
+    assert row["col"] == days
+
+    row = await (await session.execute(query)).first_row()
+    assert row["col"] == value
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize(
+    "days",
+    [
+        (date.max - date(1970, 1, 1)).days + 1,
+        (date.min - date(1970, 1, 1)).days - 1,
+        2**31 - 1,
+        -(2**31),
+    ],
+)
+async def test_date_days_outside_python_date_range(session: Session, table_factory: TableFactory, days: int):
+    table = await table_factory(
+        "id int PRIMARY KEY, col date",
+        "date_days_extreme_table",
+    )
+
+    await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, days))
+
+    query = f"SELECT col FROM {table} WHERE id = 1"
+    row = await (await session.execute(query, factory=DaysDateRowFactory())).first_row()
+    assert row["col"] == days
+
+    with pytest.raises(DeserializationError):
+        await (await session.execute(query)).first_row()
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize("days", [2**31, -(2**31) - 1])
+async def test_date_days_out_of_range(session: Session, table_factory: TableFactory, days: int):
+    table = await table_factory(
+        "id int PRIMARY KEY, col date",
+        "date_days_range_table",
+    )
+
+    with pytest.raises(ExecuteError) as exc_info:
+        await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, days))
+
+    assert "value overflow during serialization" in str(exc_info.value).lower()
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_date_rejects_bool(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, col date",
+        "date_bool_table",
+    )
+
+    # `bool` is an `int` subclass, but must not be taken for days since the Unix epoch.
+    with pytest.raises(ExecuteError):
+        await session.execute(f"INSERT INTO {table} (id, col) VALUES (?, ?)", (1, True))
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_timeuuid_serialization(session: Session, table_factory: TableFactory):
@@ -476,6 +1004,87 @@ async def test_basic_serialization_using_dict(session: Session, table_factory: T
     await session.execute(f"SELECT * from {table}")
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_dict_serialization_with_named_markers_for_unprepared_statements(
+    session: Session, table_factory: TableFactory
+):
+    table = await table_factory(
+        "id int PRIMARY KEY, name text",
+        "row_dict_named_markers_table",
+    )
+    insert = f"INSERT INTO {table} (id, name) VALUES (:user_id, :user_name)"
+
+    await session.execute(insert, {"user_name": "Alice", "user_id": 1})
+    await session.execute(Statement(insert), {"user_name": "Bob", "user_id": 2})
+
+    result = await session.execute(f"SELECT name FROM {table} WHERE id IN :ids", {"ids": [1, 2]})
+    assert sorted(row["name"] for row in await result.all()) == ["Alice", "Bob"]
+
+    with pytest.raises(ExecuteError):
+        await session.execute(insert, {"user_id": 3})
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_mixed_positional_and_named_markers(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, name text",
+        "row_mixed_markers_table",
+    )
+    insert = f"INSERT INTO {table} (id, name) VALUES (?, :name)"
+
+    # A sequence binds every marker by position.
+    await session.execute(insert, (1, "Alice"))
+    result = await session.execute(f"SELECT name FROM {table} WHERE id = 1")
+    assert (await result.all())[0]["name"] == "Alice"
+
+    with pytest.raises(ExecuteError, match="mixes positional"):
+        await session.execute(insert, {"id": 2, "name": "Bob"})
+
+    # Markers-like text inside literals does not count.
+    await session.execute(f"UPDATE {table} SET name = ':x' WHERE id = ?", {"id": 1})
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_dict_keys_differing_in_case_from_markers(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, name text",
+        "row_dict_marker_case_table",
+    )
+
+    # Unquoted marker names are lowercased by the server, quoted ones keep their case.
+    await session.execute(f'INSERT INTO {table} (id, name) VALUES (:Id, :"UserName")', {"id": 1, "UserName": "Alice"})
+
+    with pytest.raises(ExecuteError, match="has key 'Id', which differs only in case"):
+        await session.execute(f"INSERT INTO {table} (id, name) VALUES (:Id, :name)", {"Id": 2, "name": "Bob"})
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_dict_serialization_distinguishes_none_and_unset(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, name text, score double, level int",
+        "row_unset_table",
+    )
+    insert = f"INSERT INTO {table} (id, name, score, level) VALUES (?, ?, ?, ?)"
+
+    await session.execute(insert, {"id": 1, "name": "Test Name", "score": 95.5, "level": 3})
+    await session.execute(insert, {"id": 1, "name": "New Name", "score": None, "level": Unset})
+
+    result = await session.execute(f"SELECT name, score, level FROM {table} WHERE id = 1")
+    row = await result.first_row()
+    assert row == {"name": "New Name", "score": None, "level": 3}
+
+    # Positional values accept `Unset` as well.
+    await session.execute(insert, (1, Unset, 42.0, Unset))
+
+    result = await session.execute(f"SELECT name, score, level FROM {table} WHERE id = 1")
+    row = await result.first_row()
+    assert row == {"name": "New Name", "score": 42.0, "level": 3}
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_map_serialization(session: Session, table_factory: TableFactory):
@@ -832,3 +1441,149 @@ async def test_nested_udts(session: Session, table_factory: TableFactory):
 
     await session.execute(f"INSERT INTO {table} (id, person) VALUES (?, ?)", (2, asdict(person)))
     await session.execute(f"SELECT * from {table}")
+
+
+class NullAsEmptyRowFactory(RowFactory):
+    null_text_as_empty = True
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_empty_string_is_distinct_from_null_by_default(session: Session, table_factory: TableFactory):
+    table = await table_factory("id int PRIMARY KEY, a text, b ascii", "empty_string_default_table")
+
+    await session.execute(f"INSERT INTO {table} (id, a, b) VALUES (?, ?, ?)", (1, "", ""))
+    await session.execute(f"INSERT INTO {table} (id, a, b) VALUES (?, ?, ?)", (2, None, None))
+
+    rows = await (await session.execute(f"SELECT id, a, b FROM {table}")).all()
+    assert sorted(rows, key=lambda row: row["id"]) == [
+        {"id": 1, "a": "", "b": ""},
+        {"id": 2, "a": None, "b": None},
+    ]
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_empty_string_as_null(table_factory: TableFactory):
+    with await SessionBuilder().contact_points([("127.0.0.2", 9042)]).empty_string_as_null(True).connect() as session:
+        await session.use_keyspace("testks")
+        table = await table_factory(
+            "id int PRIMARY KEY, a text, b ascii, tags list<text>, t tuple<text, int>", "empty_string_as_null_table"
+        )
+
+        prepared = await session.prepare(f"INSERT INTO {table} (id, a, b, tags, t) VALUES (?, ?, ?, ?, ?)")
+        await session.execute(prepared, (1, "", "x", [""], ("", 1)))
+        await session.execute(f"INSERT INTO {table} (id, a, b) VALUES (?, ?, ?)", {"id": 2, "a": "y", "b": ""})
+
+        rows = await (await session.execute(f"SELECT id, a, b, tags, t FROM {table}")).all()
+        # Strings nested in collections and tuples are kept as they are.
+        assert sorted(rows, key=lambda row: row["id"]) == [
+            {"id": 1, "a": None, "b": "x", "tags": [""], "t": ("", 1)},
+            {"id": 2, "a": "y", "b": None, "tags": None, "t": None},
+        ]
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_null_text_as_empty(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, a text, b ascii, n int, t tuple<text, int>", "null_text_as_empty_table"
+    )
+
+    await session.execute(f"INSERT INTO {table} (id, t) VALUES (?, ?)", (1, (None, 1)))
+
+    query = f"SELECT a, b, n, t FROM {table} WHERE id = 1"
+    row = await (await session.execute(query, factory=NullAsEmptyRowFactory())).first_row()
+    # Only text columns are affected, and, as with `empty_string_as_null`, not fields of tuples.
+    assert row == {"a": "", "b": "", "n": None, "t": (None, 1)}
+
+    row = await (await session.execute(query)).first_row()
+    assert row == {"a": None, "b": None, "n": None, "t": (None, 1)}
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_iterator_serialization_as_collection(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, tags set<text>, scores list<int>",
+        "iterator_collection_table",
+    )
+    prepared = await session.prepare(f"INSERT INTO {table} (id, tags, scores) VALUES (?, ?, ?)")
+
+    await session.execute(prepared, (1, (f"tag{i}" for i in range(3)), (i * i for i in range(4))))
+    await session.execute(prepared, (2, iter(["a", "a"]), map(int, "123")))
+    # An empty generator serializes as an empty collection, which CQL stores as null.
+    await session.execute(prepared, (3, (tag for tag in ()), (score for score in ())))
+
+    rows = await (await session.execute(f"SELECT id, tags, scores FROM {table}")).all()
+    assert sorted(rows, key=lambda row: row["id"]) == [
+        {"id": 1, "tags": {"tag0", "tag1", "tag2"}, "scores": [0, 1, 4, 9]},
+        {"id": 2, "tags": {"a"}, "scores": [1, 2, 3]},
+        {"id": 3, "tags": None, "scores": None},
+    ]
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_iterator_serialization_across_pages(session: Session, table_factory: TableFactory):
+    table = await table_factory("id int PRIMARY KEY", "iterator_paging_table")
+    prepared = await session.prepare(f"INSERT INTO {table} (id) VALUES (?)")
+    for i in range(5):
+        await session.execute(prepared, (i,))
+
+    # The values of an unprepared statement are serialized again for every page,
+    # so the generator must not be exhausted after the first one.
+    statement = Statement(f"SELECT id FROM {table} WHERE id IN :ids").with_page_size(1)
+    rows = await (await session.execute(statement, ((i for i in range(5)),))).all()
+    assert sorted(row["id"] for row in rows) == list(range(5))
+
+    rows = await (await session.execute(statement, {"ids": (i for i in range(5))})).all()
+    assert sorted(row["id"] for row in rows) == list(range(5))
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_iterator_serialization_errors(session: Session, table_factory: TableFactory):
+    table = await table_factory(
+        "id int PRIMARY KEY, scores list<int>",
+        "iterator_collection_errors_table",
+    )
+    prepared = await session.prepare(f"INSERT INTO {table} (id, scores) VALUES (?, ?)")
+
+    def failing_scores():
+        yield 1
+        raise RuntimeError("sensor unavailable")
+
+    # Iterators are drained when the values are bound, before the request is executed.
+    with pytest.raises(PySerializationFailedError):
+        await session.execute(prepared, (1, failing_scores()))
+
+    # Elements are still type-checked.
+    with pytest.raises(ExecuteError):
+        await session.execute(prepared, (2, (str(i) for i in range(3))))
+
+
+def test_serialize_value_with_constructed_column_types():
+    assert serialize_value(1, CqlIntType()) == b"\x00\x00\x00\x01"
+    assert serialize_value("ab", CqlText()) == b"ab"
+    assert serialize_value(None, CqlText()) is None
+
+    # A list is its element count followed by length-prefixed elements.
+    assert serialize_value([1, 2], CqlList(CqlIntType())) == (
+        b"\x00\x00\x00\x02" + b"\x00\x00\x00\x04\x00\x00\x00\x01" + b"\x00\x00\x00\x04\x00\x00\x00\x02"
+    )
+    assert serialize_value({"a": 1}, CqlMap(CqlText(), CqlIntType())) == (
+        b"\x00\x00\x00\x01" + b"\x00\x00\x00\x01a" + b"\x00\x00\x00\x04\x00\x00\x00\x01"
+    )
+
+    point = CqlUserDefinedType("point", "testks", [("x", CqlIntType()), ("label", CqlText())])
+    assert serialize_value({"x": 7, "label": "p"}, point) == (
+        b"\x00\x00\x00\x04\x00\x00\x00\x07" + b"\x00\x00\x00\x01p"
+    )
+    assert serialize_value((7, "p"), CqlTuple([CqlIntType(), CqlText()])) == serialize_value(
+        {"x": 7, "label": "p"}, point
+    )
+
+    with pytest.raises(SerializationError):
+        serialize_value("not an int", CqlIntType())
+
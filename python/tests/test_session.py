@@ -1,9 +1,25 @@
+import asyncio
 import uuid
 
 import pytest
 import pytest_asyncio
+from _pytest.logging import LogCaptureFixture
+from scylla.batch import Batch
+from scylla.enums import AllowFilteringPolicy, Consistency
+from scylla.errors import (
+    ExecuteError,
+    InvalidQueryError,
+    InvalidStatementError,
+    PrepareError,
+    SchemaAgreementError,
+    SessionConnectionError,
+    UseKeyspaceError,
+)
+from scylla.execution_profile import ExecutionProfile
 from scylla.session import Session
 from scylla.session_builder import SessionBuilder
+from scylla.statement import PreparedStatement, Statement
+from scylla.tracing import TracingEvent, TracingInfo
 
 
 async def set_up() -> Session:
@@ -24,6 +40,7 @@ async def session():
     session = await set_up()
     yield session
     await session.execute("DROP KEYSPACE testks")
+    session.shutdown()
 
 
 @pytest.mark.asyncio
@@ -41,3 +58,570 @@ async def test_await_schema_agreement_returns_schema_version(session: Session):
 
     assert isinstance(schema_version, uuid.UUID)
     assert schema_version
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_wait_for_nodes(session: Session):
+    connected = sum(node.connected for node in session.cluster_state.nodes_info.values())
+    assert connected > 0
+
+    await session.wait_for_nodes(connected, timeout=5.0)
+
+    with pytest.raises(RuntimeError, match="Timed out"):
+        await session.wait_for_nodes(connected + 1000, timeout=0.3)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_get_tracing_info(session: Session):
+    untraced = await session.execute("SELECT * FROM system.local")
+    assert untraced.tracing_id is None
+
+    statement = Statement("SELECT * FROM system.local").with_tracing(True)
+    assert statement.tracing
+    result = await session.execute(statement)
+    assert isinstance(result.tracing_id, uuid.UUID)
+
+    info = await session.get_tracing_info(result.tracing_id)
+    assert isinstance(info, TracingInfo)
+    assert info.coordinator is not None
+    assert len(info.events) > 0
+    assert all(isinstance(event, TracingEvent) for event in info.events)
+
+    elapsed = [event.source_elapsed for event in info.events if event.source_elapsed is not None]
+    assert elapsed == sorted(elapsed)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_cancelled_execute_does_not_break_session(session: Session):
+    task = asyncio.ensure_future(session.execute("SELECT * FROM system.local"))
+    task.cancel()
+
+    with pytest.raises(asyncio.CancelledError):
+        await task
+
+    result = await session.execute("SELECT cluster_name FROM system.local")
+    assert await result.first_row() is not None
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_protocol_features(session: Session):
+    features = session.protocol_features()
+
+    assert features.shard_aware
+    assert repr(features) == "ProtocolFeatures(shard_aware=True)"
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize("timeout", [12.5, None])
+async def test_default_request_timeout(timeout: float | None):
+    with (
+        await SessionBuilder()
+        .contact_points([("127.0.0.2", 9042)])
+        .execution_profile(ExecutionProfile(timeout=timeout))
+        .connect()
+    ) as session:
+        assert session.default_request_timeout() == timeout
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_server_version_and_supported_features(session: Session):
+    version = await session.server_version()
+    result = await session.execute("SELECT release_version FROM system.local WHERE key = 'local'")
+    assert version == (await result.first_row())["release_version"]
+
+    features = await session.supported_features()
+    assert features["release_version"] == version
+    assert isinstance(features["cql_version"], str)
+    assert features["shard_aware"]
+    assert all(isinstance(feature, str) and feature for feature in features["features"])
+
+    # Cached after the first call.
+    assert await session.server_version() == version
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_size_estimates_of_unknown_table_are_empty(session: Session):
+    assert await session.size_estimates("testks", "no_such_table") == []
+
+
+@pytest.mark.requires_db
+def test_blocking_api():
+    with SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect_blocking() as session:
+        prepared = session.prepare_blocking("SELECT release_version FROM system.local")
+        result = session.execute_blocking(prepared)
+
+        rows = list(result.iter_current_page())
+        assert len(rows) == 1
+
+
+@pytest.mark.requires_db
+def test_sync_context_manager_shuts_down_session():
+    with SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect_blocking() as session:
+        result = session.execute_blocking("SELECT release_version FROM system.local")
+        assert len(list(result.iter_current_page())) == 1
+        assert not session.is_shut_down
+
+    assert session.is_shut_down
+    with pytest.raises(ExecuteError, match="shut down"):
+        session.execute_blocking("SELECT release_version FROM system.local")
+    assert session.cluster_state.nodes_info
+
+    # Shutting down again has no effect.
+    session.shutdown()
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_shutdown_rejects_later_requests():
+    session = await SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect()
+    result = await session.execute("SELECT * FROM system.local")
+
+    session.shutdown()
+
+    with pytest.raises(ExecuteError, match="shut down"):
+        await session.execute("SELECT release_version FROM system.local")
+    with pytest.raises(PrepareError, match="shut down"):
+        await session.prepare("SELECT release_version FROM system.local")
+    # Rows already received are still available.
+    assert list(result.iter_current_page())
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_blocking_api_refuses_running_event_loop(session: Session):
+    with pytest.raises(ExecuteError):
+        session.execute_blocking("SELECT release_version FROM system.local")
+    with pytest.raises(PrepareError):
+        session.prepare_blocking("SELECT release_version FROM system.local")
+    with pytest.raises(SessionConnectionError):
+        SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect_blocking()
+
+
+def test_coroutines_require_running_event_loop():
+    with pytest.raises(SessionConnectionError):
+        SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect().send(None)
+
+
+@pytest.mark.requires_db
+def test_session_coroutines_require_running_event_loop():
+    with SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect_blocking() as session:
+        with pytest.raises(ExecuteError):
+            session.execute("SELECT release_version FROM system.local").send(None)
+        with pytest.raises(PrepareError):
+            session.prepare("SELECT release_version FROM system.local").send(None)
+        with pytest.raises(UseKeyspaceError):
+            session.use_keyspace("system").send(None)
+        with pytest.raises(SchemaAgreementError):
+            session.check_schema_agreement().send(None)
+        with pytest.raises(ExecuteError):
+            session.server_version().send(None)
+
+        # Paging through a result goes through the same check.
+        result = session.execute_blocking(
+            Statement("SELECT keyspace_name FROM system_schema.keyspaces").with_page_size(1)
+        )
+        with pytest.raises(ExecuteError):
+            result.fetch_next_page().send(None)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize("kind", ["str", "statement", "prepared"])
+async def test_execute_accepts_each_statement_type(session: Session, kind: str):
+    query = "SELECT cluster_name FROM system.local"
+    statement: str | Statement | PreparedStatement = query
+    if kind == "statement":
+        statement = Statement(query)
+    elif kind == "prepared":
+        statement = await session.prepare(query)
+
+    result = await session.execute(statement)
+    rows = await result.all()
+
+    assert len(rows) == 1
+    assert "cluster_name" in rows[0]
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_execute_honors_statement_configuration(session: Session):
+    query = "SELECT cluster_name FROM system.local"
+
+    with pytest.raises(ExecuteError):
+        await session.execute(Statement(query).with_consistency(Consistency.Three))
+
+    prepared = await session.prepare(query)
+    with pytest.raises(ExecuteError):
+        await session.execute(prepared.with_consistency(Consistency.Three))
+
+    # The original objects keep their configuration and still execute fine.
+    await session.execute(Statement(query))
+    await session.execute(prepared)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_execute_retries_require_idempotent_statement(session: Session):
+    query = "SELECT cluster_name FROM system.local"
+
+    with pytest.raises(ExecuteError, match="idempotent"):
+        await session.execute(query, retries=2)
+    with pytest.raises(ExecuteError, match="idempotent"):
+        await session.execute(Statement(query), retries=2)
+
+    result = await session.execute(Statement(query).with_is_idempotent(True), retries=2)
+    assert len(await result.all()) == 1
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize("paged", [True, False])
+async def test_execute_retries_report_attempt_count(session: Session, paged: bool):
+    await session.execute("CREATE TABLE IF NOT EXISTS retries_tbl (id int PRIMARY KEY)")
+
+    # With replication factor 1, consistency THREE cannot be satisfied, so every attempt fails with `Unavailable`.
+    statement = (
+        Statement("SELECT * FROM retries_tbl WHERE id = 1").with_consistency(Consistency.Three).with_is_idempotent(True)
+    )
+
+    with pytest.raises(ExecuteError, match="after 3 attempts"):
+        await session.execute(statement, paged=paged, retries=2)
+
+    prepared = (await session.prepare(statement)).with_is_idempotent(True)
+    with pytest.raises(ExecuteError, match="after 2 attempts"):
+        await session.execute(prepared, paged=paged, retries=1)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_default_idempotence_applies_unless_set_on_statement():
+    with await SessionBuilder().contact_points([("127.0.0.2", 9042)]).default_idempotence(True).connect() as session:
+        query = "SELECT cluster_name FROM system.local"
+
+        # Retries are only accepted for idempotent statements.
+        await session.execute(query, retries=1)
+        await session.execute(Statement(query), retries=1)
+        await session.execute(await session.prepare(query), retries=1)
+
+        with pytest.raises(ExecuteError, match="idempotent"):
+            await session.execute(Statement(query).with_is_idempotent(False), retries=1)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_prepare_keeps_statement_idempotence_over_default():
+    session = await SessionBuilder().contact_points([("127.0.0.2", 9042)]).default_idempotence(True).connect()
+    query = "SELECT cluster_name FROM system.local"
+
+    with session:
+        prepared = await session.prepare(Statement(query).with_is_idempotent(False).with_serial_consistency(None))
+        assert not prepared.is_idempotent
+        assert prepared.serial_consistency is None
+
+        # The explicit setting takes precedence over the session's default idempotence.
+        with pytest.raises(ExecuteError, match="idempotent"):
+            await session.execute(prepared, retries=1)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_prepare_and_execute(session: Session):
+    await session.execute("CREATE TABLE IF NOT EXISTS prepare_and_execute_tbl (id int PRIMARY KEY, name text)")
+
+    # The second insert reuses the statement prepared by the first one.
+    for i, name in enumerate(["Alice", "Bob"]):
+        await session.prepare_and_execute("INSERT INTO prepare_and_execute_tbl (id, name) VALUES (?, ?)", [i, name])
+
+    result = await session.prepare_and_execute("SELECT name FROM prepare_and_execute_tbl WHERE id = ?", [1])
+    assert await result.all() == [{"name": "Bob"}]
+
+    with pytest.raises(PrepareError):
+        await session.prepare_and_execute("SELECT * FROM no_such_table")
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+@pytest.mark.parametrize("cache_size", [0, 1])
+async def test_prepare_and_execute_with_bounded_cache(session: Session, cache_size: int):
+    await session.execute("CREATE TABLE IF NOT EXISTS prepare_and_execute_tbl (id int PRIMARY KEY, name text)")
+    await session.execute("INSERT INTO prepare_and_execute_tbl (id, name) VALUES (1, 'Bob')")
+    with await (
+        SessionBuilder()
+        .contact_points([("127.0.0.2", 9042)])
+        .use_keyspace("testks", case_sensitive=False)
+        .prepared_statement_cache_size(cache_size)
+        .connect()
+    ) as session:
+        # Alternating between more queries than the cache holds evicts and re-prepares them.
+        for _ in range(3):
+            result = await session.prepare_and_execute("SELECT name FROM prepare_and_execute_tbl WHERE id = ?", [1])
+            assert await result.all() == [{"name": "Bob"}]
+            result = await session.prepare_and_execute("SELECT id FROM prepare_and_execute_tbl WHERE id = ?", [1])
+            assert await result.all() == [{"id": 1}]
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_execute_by_id(session: Session):
+    await session.execute("CREATE TABLE IF NOT EXISTS execute_by_id_tbl (id int PRIMARY KEY, name text)")
+    await session.execute("INSERT INTO execute_by_id_tbl (id, name) VALUES (1, 'Alice')")
+    query = "SELECT name FROM execute_by_id_tbl WHERE id = ?"
+
+    prepared = await session.prepare(query)
+    statement_id = prepared.id
+    del prepared
+
+    result = await session.execute_by_id(statement_id, [1])
+    assert await result.all() == [{"name": "Alice"}]
+
+    with pytest.raises(ExecuteError, match="pass `query` to prepare it"):
+        await session.execute_by_id(b"unknown id", [1])
+
+    # An id unknown to the session is prepared again from the query.
+    result = await session.execute_by_id(b"unknown id", [1], query=query)
+    assert await result.all() == [{"name": "Alice"}]
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_execute_by_id_after_eviction(session: Session):
+    await session.execute("CREATE TABLE IF NOT EXISTS execute_by_id_tbl (id int PRIMARY KEY, name text)")
+    await session.execute("INSERT INTO execute_by_id_tbl (id, name) VALUES (1, 'Alice')")
+    with await (
+        SessionBuilder()
+        .contact_points([("127.0.0.2", 9042)])
+        .use_keyspace("testks", case_sensitive=False)
+        .prepared_statement_cache_size(1)
+        .connect()
+    ) as session:
+        query = "SELECT name FROM execute_by_id_tbl WHERE id = ?"
+
+        statement_id = (await session.prepare(query)).id
+        # Preparing another statement evicts the first one from the cache.
+        await session.prepare("SELECT id FROM execute_by_id_tbl WHERE id = ?")
+
+        with pytest.raises(ExecuteError, match="pass `query` to prepare it"):
+            await session.execute_by_id(statement_id, [1])
+
+        result = await session.execute_by_id(statement_id, [1], query=query)
+        assert await result.all() == [{"name": "Alice"}]
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_missing_table_and_keyspace_raise_invalid_query_error(session: Session):
+    with pytest.raises(InvalidQueryError) as exc_info:
+        await session.execute("SELECT * FROM no_such_table_for_invalid_query")
+    assert exc_info.value.reason == "table_not_found"
+
+    with pytest.raises(InvalidQueryError) as exc_info:
+        await session.execute("SELECT * FROM no_such_keyspace_for_invalid_query.tbl")
+    assert exc_info.value.reason == "keyspace_not_found"
+
+    with pytest.raises(InvalidQueryError) as exc_info:
+        await session.execute("SELECT no_such_column FROM system.local")
+    assert exc_info.value.reason is None
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_missing_table_is_classified_when_preparing(session: Session):
+    query = "SELECT * FROM no_such_table_for_invalid_query WHERE id = ?"
+
+    with pytest.raises(InvalidStatementError) as prepare_info:
+        await session.prepare(query)
+    assert prepare_info.value.reason == "table_not_found"
+
+    with pytest.raises(InvalidStatementError) as prepare_and_execute_info:
+        await session.prepare_and_execute(query, [1])
+    assert prepare_and_execute_info.value.reason == "table_not_found"
+
+    # An unprepared statement with values is prepared implicitly by the driver.
+    with pytest.raises(InvalidQueryError) as execute_info:
+        await session.execute(query, [1])
+    assert execute_info.value.reason == "table_not_found"
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_insert_many_reports_failed_rows_by_index(session: Session):
+    await session.execute("CREATE TABLE IF NOT EXISTS insert_many_tbl (id int PRIMARY KEY, name text)")
+    prepared = (await session.prepare("INSERT INTO insert_many_tbl (id, name) VALUES (?, ?)")).with_is_idempotent(True)
+
+    rows: list[object] = [(i, f"name{i}") for i in range(20)]
+    rows[3] = ("not an int", "name3")
+    rows[11] = (11,)
+
+    result = await session.insert_many(prepared, rows, concurrency=4)
+
+    assert result.succeeded == 18
+    assert result.failed == 2
+    assert [index for index, _ in result.failures] == [3, 11]
+    assert all(isinstance(error, ExecuteError) for _, error in result.failures)
+    assert repr(result) == "InsertManyResult(succeeded=18, failed=2)"
+
+    count = await (await session.execute("SELECT COUNT(*) FROM insert_many_tbl")).all()
+    assert count == [{"count": 18}]
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_insert_many_rejects_zero_concurrency(session: Session):
+    await session.execute("CREATE TABLE IF NOT EXISTS insert_many_tbl (id int PRIMARY KEY, name text)")
+    prepared = await session.prepare("INSERT INTO insert_many_tbl (id, name) VALUES (?, ?)")
+
+    with pytest.raises(ValueError):
+        await session.insert_many(prepared, [], concurrency=0)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_request_hook(session: Session):
+    calls: list[tuple[str, float, bool]] = []
+
+    def hook(query: str, duration: float, succeeded: bool) -> None:
+        calls.append((query, duration, succeeded))
+
+    session.set_request_hook(hook)
+    try:
+        await session.execute("SELECT release_version FROM system.local")
+        with pytest.raises(ExecuteError):
+            await session.execute("SELECT * FROM no_such_table_for_request_hook")
+        batch = Batch()
+        batch.add("INSERT INTO request_hook_tbl (id) VALUES (1)")
+        batch.add("INSERT INTO request_hook_tbl (id) VALUES (2)")
+        await session.execute("CREATE TABLE IF NOT EXISTS request_hook_tbl (id int PRIMARY KEY)")
+        await session.batch(batch)
+    finally:
+        session.set_request_hook(None)
+
+    assert [(query, succeeded) for query, _, succeeded in calls] == [
+        ("SELECT release_version FROM system.local", True),
+        ("SELECT * FROM no_such_table_for_request_hook", False),
+        ("CREATE TABLE IF NOT EXISTS request_hook_tbl (id int PRIMARY KEY)", True),
+        ("INSERT INTO request_hook_tbl (id) VALUES (1); INSERT INTO request_hook_tbl (id) VALUES (2)", True),
+    ]
+    assert all(duration >= 0 for _, duration, _ in calls)
+
+    # Once removed, the hook is no longer called.
+    await session.execute("SELECT release_version FROM system.local")
+    assert len(calls) == 4
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_request_hook_reports_insert_many_rows(session: Session):
+    await session.execute("CREATE TABLE IF NOT EXISTS insert_many_tbl (id int PRIMARY KEY, name text)")
+    prepared = await session.prepare("INSERT INTO insert_many_tbl (id, name) VALUES (?, ?)")
+    calls: list[tuple[str, float, bool]] = []
+
+    def hook(query: str, duration: float, succeeded: bool) -> None:
+        calls.append((query, duration, succeeded))
+
+    session.set_request_hook(hook)
+    try:
+        result = await session.insert_many(prepared, [(i, f"name{i}") for i in range(5)], concurrency=2)
+    finally:
+        session.set_request_hook(None)
+
+    assert result.succeeded == 5
+    assert [(query, succeeded) for query, _, succeeded in calls] == [
+        ("INSERT INTO insert_many_tbl (id, name) VALUES (?, ?)", True)
+    ] * 5
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_request_hook_reports_rejected_requests(session: Session):
+    await session.execute("CREATE TABLE IF NOT EXISTS insert_many_tbl (id int PRIMARY KEY, name text)")
+    prepared = await session.prepare("INSERT INTO insert_many_tbl (id, name) VALUES (?, ?)")
+    calls: list[tuple[str, float, bool]] = []
+
+    def hook(query: str, duration: float, succeeded: bool) -> None:
+        calls.append((query, duration, succeeded))
+
+    session.set_request_hook(hook)
+    try:
+        # Rejected before being sent: retries need an idempotent statement.
+        with pytest.raises(ExecuteError):
+            await session.execute("SELECT release_version FROM system.local", retries=1)
+        result = await session.insert_many(prepared, [(1, "a"), ("not an int", "b")])
+    finally:
+        session.set_request_hook(None)
+
+    assert result.failed == 1
+    assert [(query, succeeded) for query, _, succeeded in calls] == [
+        ("SELECT release_version FROM system.local", False),
+        ("INSERT INTO insert_many_tbl (id, name) VALUES (?, ?)", True),
+        ("INSERT INTO insert_many_tbl (id, name) VALUES (?, ?)", False),
+    ]
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_request_hook_exceptions_do_not_fail_requests(session: Session):
+    def hook(query: str, duration: float, succeeded: bool) -> None:
+        raise RuntimeError("hook failure")
+
+    session.set_request_hook(hook)
+    try:
+        result = await session.execute("SELECT release_version FROM system.local")
+        assert await result.all()
+    finally:
+        session.set_request_hook(None)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_request_hook_must_be_callable(session: Session):
+    with pytest.raises(TypeError):
+        session.set_request_hook(42)  # pyright: ignore[reportArgumentType]
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_allow_filtering_policy_error():
+    with (
+        await SessionBuilder()
+        .contact_points([("127.0.0.2", 9042)])
+        .allow_filtering_policy(AllowFilteringPolicy.Error)
+        .connect()
+    ) as session:
+        with pytest.raises(ExecuteError, match="ALLOW FILTERING"):
+            await session.execute("SELECT * FROM system.local WHERE key = 'local' allow  filtering")
+        prepared = await session.prepare("SELECT * FROM system.local WHERE key = ? ALLOW FILTERING")
+        with pytest.raises(ExecuteError, match="ALLOW FILTERING"):
+            await session.execute(prepared, ["local"])
+        with pytest.raises(ExecuteError, match="ALLOW FILTERING"):
+            await session.insert_many(prepared, [["local"]])
+
+        # Statements without the clause, or with it only inside a literal, are executed.
+        result = await session.execute("SELECT release_version FROM system.local")
+        assert await result.all()
+        result = await session.execute("SELECT * FROM system.local WHERE key = 'ALLOW FILTERING'")
+        assert await result.all() == []
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_allow_filtering_policy_warn(caplog: LogCaptureFixture):
+    with (
+        await SessionBuilder()
+        .contact_points([("127.0.0.2", 9042)])
+        .allow_filtering_policy(AllowFilteringPolicy.Warn)
+        .connect()
+    ) as session:
+        result = await session.execute("SELECT release_version FROM system.local")
+        assert await result.all()
+        assert "ALLOW FILTERING" not in caplog.text
+
+        result = await session.execute("SELECT * FROM system.local WHERE key = 'local' ALLOW FILTERING")
+        assert await result.all()
+        assert "Executing a statement with ALLOW FILTERING" in caplog.text
@@ -1,11 +1,20 @@
 import ipaddress
+import time
 from datetime import timedelta
 from typing import Any, Generator, Optional, Sequence
 
 import pytest
 from _pytest.logging import LogCaptureFixture
-from scylla.enums import Compression, Consistency, PoolSize, SelfIdentity, SerialConsistency, WriteCoalescingDelay
-from scylla.errors import SessionConfigError
+from scylla.enums import (
+    AllowFilteringPolicy,
+    Compression,
+    Consistency,
+    PoolSize,
+    SelfIdentity,
+    SerialConsistency,
+    WriteCoalescingDelay,
+)
+from scylla.errors import SessionConfigError, SessionConnectionError
 from scylla.execution_profile import ExecutionProfile
 from scylla.policies import (
     AddressTranslator,
@@ -365,6 +374,12 @@ def test_local_ip_address_valid_formats(ip: Any):
     assert isinstance(builder, SessionBuilder)
 
 
+@pytest.mark.parametrize("ip", ["not-an-ip", "127.0.0.256", 1234])
+def test_local_ip_address_invalid(ip: Any):
+    with pytest.raises(SessionConfigError, match="Invalid local IP address"):
+        SessionBuilder().local_ip_address(ip)
+
+
 @pytest.mark.parametrize(
     "bad_range",
     [
@@ -392,6 +407,18 @@ def test_port_range_validation_logic(bad_range: tuple[int, int]):
 async def test_port_range_boundary_valid(valid_range: tuple[int, int]):
     builder = SessionBuilder().shard_aware_local_port_range(valid_range)
     assert isinstance(builder, SessionBuilder)
+    assert builder.get_config().shard_aware_local_port_range == valid_range
+
+
+def test_port_range_rejected_range_keeps_previous():
+    builder = SessionBuilder()
+    assert builder.get_config().shard_aware_local_port_range == (49152, 65535)
+
+    builder.shard_aware_local_port_range((50000, 51000))
+    with pytest.raises(SessionConfigError):
+        builder.shard_aware_local_port_range((51000, 50000))
+
+    assert builder.get_config().shard_aware_local_port_range == (50000, 51000)
 
 
 @pytest.mark.asyncio
@@ -584,3 +611,135 @@ async def test_session_builder_complex_types_and_identity():
     assert config.identity.application_name == "Scylla-Validation-Suite"
 
     assert config.authenticator is auth_provider
+
+
+def test_default_idempotence():
+    builder = SessionBuilder()
+    assert not builder.get_config().default_idempotence
+
+    builder.default_idempotence(True)
+    assert builder.get_config().default_idempotence
+
+
+def test_prepared_statement_cache_size():
+    builder = SessionBuilder()
+    assert builder.get_config().prepared_statement_cache_size == 512
+
+    builder.prepared_statement_cache_size(2)
+    assert builder.get_config().prepared_statement_cache_size == 2
+
+
+@pytest.mark.parametrize("consistency", [Consistency.One, "one", "LOCAL_ONE", "EachQuorum"])
+def test_default_consistency(consistency: Consistency | str):
+    builder = SessionBuilder().execution_profile(ExecutionProfile(timeout=5.0, consistency=Consistency.All))
+    builder.default_consistency(consistency)
+
+    profile = builder.get_config().execution_profile
+    expected = consistency if isinstance(consistency, Consistency) else Consistency.from_string(consistency)
+    assert profile.consistency == expected
+    # The rest of the default profile is preserved.
+    assert profile.request_timeout == 5.0
+
+
+@pytest.mark.parametrize("consistency", ["quorum_ish", "", 1, None])
+def test_default_consistency_invalid(consistency: Any):
+    builder = SessionBuilder()
+    with pytest.raises((ValueError, TypeError)):
+        builder.default_consistency(consistency)
+    assert builder.get_config().execution_profile.consistency == Consistency.LocalQuorum
+
+
+@pytest.mark.parametrize("serial_consistency", [SerialConsistency.Serial, SerialConsistency.LocalSerial, None])
+def test_default_serial_consistency(serial_consistency: SerialConsistency | None):
+    builder = SessionBuilder().execution_profile(ExecutionProfile(timeout=5.0, consistency=Consistency.All))
+    builder.default_serial_consistency(serial_consistency)
+
+    profile = builder.get_config().execution_profile
+    assert profile.serial_consistency == serial_consistency
+    # The rest of the default profile is preserved.
+    assert profile.consistency == Consistency.All
+    assert profile.request_timeout == 5.0
+
+
+@pytest.mark.parametrize("serial_consistency", ["serial", Consistency.Quorum, 1])
+def test_default_serial_consistency_invalid(serial_consistency: Any):
+    builder = SessionBuilder()
+    with pytest.raises(TypeError):
+        builder.default_serial_consistency(serial_consistency)
+    assert builder.get_config().execution_profile.serial_consistency == SerialConsistency.LocalSerial
+
+
+def test_empty_string_as_null():
+    builder = SessionBuilder()
+    assert not builder.get_config().empty_string_as_null
+
+    builder.empty_string_as_null(True)
+    assert builder.get_config().empty_string_as_null
+
+
+def test_allow_filtering_policy():
+    builder = SessionBuilder()
+    assert builder.get_config().allow_filtering_policy == AllowFilteringPolicy.Off
+
+    builder.allow_filtering_policy(AllowFilteringPolicy.Warn)
+    assert builder.get_config().allow_filtering_policy == AllowFilteringPolicy.Warn
+
+
+def test_connect_deadline():
+    builder = SessionBuilder()
+    assert builder.get_config().connect_deadline is None
+
+    builder.connect_deadline(1.5)
+    assert builder.get_config().connect_deadline == timedelta(seconds=1.5)
+
+    builder.connect_deadline(None)
+    assert builder.get_config().connect_deadline is None
+
+
+@pytest.mark.asyncio
+async def test_connect_fails_fast_for_unreachable_contact_points():
+    # 192.0.2.0/24 (TEST-NET-1) is reserved for documentation and never routed.
+    builder = (
+        SessionBuilder()
+        .contact_points([("192.0.2.1", 9042), ("192.0.2.2", 9042)])
+        .connection_timeout(0.5)
+        .connect_deadline(1.0)
+    )
+
+    started = time.monotonic()
+    with pytest.raises(SessionConnectionError) as exc_info:
+        await builder.connect()
+
+    assert time.monotonic() - started < 5.0
+    assert "192.0.2.1:9042" in str(exc_info.value)
+    assert "192.0.2.2:9042" in str(exc_info.value)
+
+
+def test_wait_for_node_connections():
+    builder = SessionBuilder()
+    assert not builder.get_config().wait_for_node_connections
+
+    builder.wait_for_node_connections(True)
+    assert builder.get_config().wait_for_node_connections
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_wait_for_node_connections_connects_all_nodes():
+    with await SessionBuilder().contact_points(["127.0.0.2:9042"]).wait_for_node_connections(True).connect() as session:
+        nodes = session.cluster_state.nodes_info.values()
+        assert all(node.connected for node in nodes if node.enabled)
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_fetch_schema_metadata_disabled():
+    with await SessionBuilder().contact_points(["127.0.0.2:9042"]).fetch_schema_metadata(False).connect() as session:
+        cluster_state = session.cluster_state
+        assert len(cluster_state.keyspaces) == 0
+        assert cluster_state.keyspace_names() == []
+        assert cluster_state.get_keyspace("system") is None
+        assert len(cluster_state.nodes_info) > 0
+
+        result = await session.execute("SELECT release_version FROM system.local")
+        assert await result.first_row() is not None
@@ -4,6 +4,7 @@ import pytest
 from scylla.enums import Consistency, SerialConsistency
 from scylla.errors import PrepareError, StatementConfigError, StatementConversionError
 from scylla.execution_profile import ExecutionProfile
+from scylla.policies import LoadBalancingPolicy
 from scylla.session_builder import SessionBuilder
 from scylla.statement import PreparedStatement, Statement
 from scylla.types import Unset
@@ -70,6 +71,63 @@ async def test_prepare_and_str():
     assert cluster_name_str == row_statement["cluster_name"]
 
 
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_statement_from_prepared():
+    with await SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect() as session:
+        query_str = "SELECT cluster_name FROM system.local"
+        prepared = await session.prepare(query_str)
+        prepared = (
+            prepared.with_consistency(Consistency.One)
+            .with_serial_consistency(None)
+            .with_request_timeout(5.0)
+            .with_page_size(100)
+        )
+
+        statement = Statement.from_prepared(prepared)
+
+        assert statement.contents == query_str
+        assert statement.consistency == Consistency.One
+        assert statement.serial_consistency is None
+        assert statement.request_timeout == 5.0
+        assert statement.page_size == 100
+
+        result = await session.execute(statement)
+        assert await result.first_row() is not None
+
+
+def test_expand_in_lists():
+    statement, values = Statement.expand_in_lists(
+        "SELECT * FROM t WHERE pk = ? AND ck IN ? AND v = ?",
+        (1, [10, 20, 30], "x"),
+    )
+
+    assert statement.contents == "SELECT * FROM t WHERE pk = ? AND ck IN (?, ?, ?) AND v = ?"
+    assert values == [1, 10, 20, 30, "x"]
+
+
+def test_expand_in_lists_empty_list():
+    statement, values = Statement.expand_in_lists("SELECT * FROM t WHERE pk IN ?", [[]])
+
+    assert statement.contents == "SELECT * FROM t WHERE pk IN ()"
+    assert values == []
+
+
+def test_expand_in_lists_ignores_literals_and_non_in_markers():
+    statement, values = Statement.expand_in_lists(
+        "SELECT * FROM t WHERE s = 'in ?' AND l = ? AND pk in ?",
+        ([1, 2], (3, 4)),
+    )
+
+    assert statement.contents == "SELECT * FROM t WHERE s = 'in ?' AND l = ? AND pk in (?, ?)"
+    assert values == [[1, 2], 3, 4]
+
+
+def test_expand_in_lists_marker_count_mismatch():
+    with pytest.raises(StatementConfigError):
+        Statement.expand_in_lists("SELECT * FROM t WHERE pk IN ?", [])
+
+
 def test_statement_with_page_size():
     query_str = "SELECT cluster_name FROM system.local;"
     statement = Statement(query_str)
@@ -191,6 +249,39 @@ def test_statement_serial_consistency():
     assert statement.serial_consistency is Unset
 
 
+@pytest.mark.parametrize(
+    "query_str",
+    [
+        "UPDATE t SET v = 1 WHERE k = 0 IF v = 0",
+        "INSERT INTO t (k, v) VALUES (0, 0) if not exists",
+        "DELETE FROM t WHERE k = 0 IF EXISTS",
+    ],
+)
+def test_statement_serial_consistency_check_lwt_accepts_conditional_statements(query_str: str):
+    statement = Statement(query_str).with_serial_consistency(SerialConsistency.Serial, check_lwt=True)
+
+    assert statement.serial_consistency == SerialConsistency.Serial
+
+
+@pytest.mark.parametrize(
+    "query_str",
+    [
+        "SELECT * FROM t WHERE k = 0",
+        "UPDATE t SET v = 'IF' WHERE k = 0 -- IF",
+        "INSERT INTO t (k, notif) VALUES (0, 1)",
+    ],
+)
+def test_statement_serial_consistency_check_lwt_rejects_other_statements(query_str: str):
+    statement = Statement(query_str)
+
+    with pytest.raises(StatementConfigError, match="no IF condition"):
+        statement.with_serial_consistency(SerialConsistency.Serial, check_lwt=True)
+
+    # The check is opt-in, and unsetting serial consistency is always allowed.
+    statement.with_serial_consistency(SerialConsistency.Serial)
+    statement.with_serial_consistency(None, check_lwt=True)
+
+
 @pytest.mark.asyncio
 @pytest.mark.requires_db
 async def test_prepared_serial_consistency():
@@ -211,6 +302,9 @@ async def test_prepared_serial_consistency():
     prepared = prepared.without_serial_consistency()
     assert prepared.serial_consistency is Unset
 
+    with pytest.raises(StatementConfigError, match="no IF condition"):
+        prepared.with_serial_consistency(SerialConsistency.Serial, check_lwt=True)
+
 
 @pytest.mark.asyncio
 @pytest.mark.requires_db
@@ -244,3 +338,79 @@ async def test_statement_preserves_settings_after_prepare():
 
     prepared_c = prepared.consistency
     assert prepared_c == Consistency.EachQuorum
+
+
+def test_statement_timestamp():
+    statement = Statement("SELECT * FROM system.local")
+    assert statement.timestamp is None
+
+    # Exceeds float precision at microsecond resolution.
+    timestamp = 1_700_000_000_123_457
+    statement = statement.with_timestamp(timestamp)
+    assert statement.timestamp == timestamp
+
+    statement = statement.without_timestamp()
+    assert statement.timestamp is None
+
+
+def test_statement_is_idempotent():
+    statement = Statement("SELECT * FROM system.local")
+    assert not statement.is_idempotent
+
+    idempotent = statement.with_is_idempotent(True)
+    assert idempotent.is_idempotent
+    assert not statement.is_idempotent
+
+    assert not idempotent.with_is_idempotent(False).is_idempotent
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_statement_timestamp_is_used_as_writetime():
+    with await SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect() as session:
+        await session.execute("""
+                CREATE KEYSPACE IF NOT EXISTS testks
+                WITH replication = {'class': 'NetworkTopologyStrategy', 'replication_factor': 1};
+            """)
+        await session.execute("CREATE TABLE IF NOT EXISTS testks.timestamp_tbl (id int PRIMARY KEY, v int)")
+
+        timestamp = 1_700_000_000_123_457
+        insert = await session.prepare("INSERT INTO testks.timestamp_tbl (id, v) VALUES (?, ?)")
+        await session.execute(insert.with_timestamp(timestamp), (1, 1))
+
+        result = await session.execute("SELECT writetime(v) AS wt FROM testks.timestamp_tbl WHERE id = 1")
+        row = await result.first_row()
+        assert row is not None
+        assert row["wt"] == timestamp
+
+        await session.execute("DROP TABLE testks.timestamp_tbl")
+
+
+def test_statement_load_balancing_policy():
+    policy = LoadBalancingPolicy(preferred_datacenter="datacenter1", token_aware=False)
+
+    statement = Statement("SELECT * FROM system.local")
+    assert statement.load_balancing_policy is None
+
+    with_policy = statement.with_load_balancing_policy(policy)
+    assert with_policy.load_balancing_policy == policy
+    assert statement.load_balancing_policy is None
+
+    assert with_policy.without_load_balancing_policy().load_balancing_policy is None
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_prepared_load_balancing_policy():
+    with await SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect() as session:
+        policy = LoadBalancingPolicy(preferred_datacenter="datacenter1")
+
+        prepared = await session.prepare("SELECT * FROM system.local")
+        assert prepared.load_balancing_policy is None
+
+        prepared = prepared.with_load_balancing_policy(policy)
+        assert prepared.load_balancing_policy == policy
+        assert Statement.from_prepared(prepared).load_balancing_policy == policy
+
+        result = await session.execute(prepared)
+        assert len(await result.all()) == 1
@@ -0,0 +1,122 @@
+import uuid
+from datetime import datetime, timedelta, timezone
+
+import pytest
+from scylla.session_builder import SessionBuilder
+from scylla.value import datetime_from_timeuuid, max_timeuuid, min_timeuuid, timeuuid_from_time
+
+
+def test_timeuuid_from_time_roundtrip():
+    ts = datetime(2024, 3, 1, 12, 30, 15, 123456, tzinfo=timezone.utc)
+
+    value = timeuuid_from_time(ts)
+
+    assert isinstance(value, uuid.UUID)
+    assert value.version == 1
+    assert value.variant == uuid.RFC_4122
+    assert datetime_from_timeuuid(value) == ts
+
+
+def test_timeuuid_from_time_defaults_to_now():
+    before = datetime.now(timezone.utc) - timedelta(seconds=1)
+    value = timeuuid_from_time()
+    after = datetime.now(timezone.utc) + timedelta(seconds=1)
+
+    assert before <= datetime_from_timeuuid(value) <= after
+    assert timeuuid_from_time() != value
+
+
+def test_timeuuid_from_time_now_is_strictly_increasing():
+    times = [timeuuid_from_time().time for _ in range(1000)]
+
+    assert times == sorted(set(times))
+
+
+def test_timeuuid_from_seconds_and_naive_datetime():
+    ts = datetime(2024, 3, 1, 12, 0, tzinfo=timezone.utc)
+
+    assert datetime_from_timeuuid(timeuuid_from_time(ts.timestamp())) == ts
+    assert datetime_from_timeuuid(timeuuid_from_time(ts.replace(tzinfo=None))) == ts
+    assert datetime_from_timeuuid(timeuuid_from_time(ts.astimezone(timezone(timedelta(hours=2))))) == ts
+
+
+def test_min_max_timeuuid():
+    ts = datetime(2024, 3, 1, 12, 0, tzinfo=timezone.utc)
+
+    low = min_timeuuid(ts)
+    high = max_timeuuid(ts)
+
+    assert datetime_from_timeuuid(low) == ts
+    assert datetime_from_timeuuid(high) == ts
+    assert low.clock_seq_hi_variant == 0x80
+    assert low.node == 0x808080808080
+    assert high.clock_seq_hi_variant == 0xBF
+    assert high.node == 0x7F7F7F7F7F7F
+
+
+def test_timeuuid_invalid_arguments():
+    with pytest.raises(TypeError):
+        timeuuid_from_time("2024-03-01")  # pyright: ignore[reportArgumentType]
+    with pytest.raises(ValueError):
+        min_timeuuid(float("inf"))
+    with pytest.raises(ValueError):
+        datetime_from_timeuuid(uuid.uuid4())
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_timeuuid_range_query():
+    with await SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect() as session:
+        await session.execute("""
+                CREATE KEYSPACE IF NOT EXISTS testks
+                WITH replication = {'class': 'NetworkTopologyStrategy', 'replication_factor': 1};
+            """)
+        await session.execute(
+            "CREATE TABLE IF NOT EXISTS testks.timeuuid_range (pk int, ck timeuuid, PRIMARY KEY (pk, ck))"
+        )
+
+        base = datetime(2024, 3, 1, 12, 0, tzinfo=timezone.utc)
+        for minutes in range(5):
+            await session.execute(
+                "INSERT INTO testks.timeuuid_range (pk, ck) VALUES (?, ?)",
+                (1, timeuuid_from_time(base + timedelta(minutes=minutes))),
+            )
+
+        result = await session.execute(
+            "SELECT ck FROM testks.timeuuid_range WHERE pk = ? AND ck >= ? AND ck <= ?",
+            (1, min_timeuuid(base + timedelta(minutes=1)), max_timeuuid(base + timedelta(minutes=3))),
+        )
+        rows = await result.all()
+
+        assert len(rows) == 3
+
+        await session.execute("DROP TABLE testks.timeuuid_range")
+
+
+@pytest.mark.asyncio
+@pytest.mark.requires_db
+async def test_generated_timeuuid_clustering_key_order():
+    with await SessionBuilder().contact_points([("127.0.0.2", 9042)]).connect() as session:
+        await session.execute("""
+                CREATE KEYSPACE IF NOT EXISTS testks
+                WITH replication = {'class': 'NetworkTopologyStrategy', 'replication_factor': 1};
+            """)
+        await session.execute(
+            "CREATE TABLE IF NOT EXISTS testks.timeuuid_order (pk int, ck timeuuid, PRIMARY KEY (pk, ck))"
+        )
+        await session.execute("TRUNCATE testks.timeuuid_order")
+
+        generated = [timeuuid_from_time() for _ in range(50)]
+        base = datetime(2024, 3, 1, 12, 0, tzinfo=timezone.utc)
+        explicit = [timeuuid_from_time(base + timedelta(microseconds=i)) for i in range(50)]
+
+        # Insert in an order that is neither the generation nor the `uuid.UUID` order.
+        for pk, values in ((1, generated), (2, explicit)):
+            for value in reversed(values):
+                await session.execute("INSERT INTO testks.timeuuid_order (pk, ck) VALUES (?, ?)", (pk, value))
+
+        for pk, values in ((1, generated), (2, explicit)):
+            result = await session.execute("SELECT ck FROM testks.timeuuid_order WHERE pk = ?", (pk,))
+            assert [row["ck"] for row in await result.all()] == values
+
+        await session.execute("DROP TABLE testks.timeuuid_order")
@@ -48,6 +48,9 @@ pub(crate) struct PyBatch {
     // between `Unset` and `None` in a different way. To preserve this distinction, an additional
     // flag `is_serial_consistency_set` is required.
     is_serial_consistency_set: bool,
+    // Likewise, `is_idempotent` is a plain `bool`, so this flag tells whether it was set
+    // explicitly, in which case it takes precedence over the session's default idempotence.
+    is_idempotence_set: bool,
 }
 
 impl PyBatch {
@@ -55,13 +58,24 @@ impl PyBatch {
         _inner: Batch,
         values: Vec<PyValueList>,
         is_serial_consistency_set: bool,
+        is_idempotence_set: bool,
     ) -> Self {
         Self {
             _inner,
             values,
             is_serial_consistency_set,
+            is_idempotence_set,
         }
     }
+
+    /// Marks the batch as idempotent if `default_idempotence` is set and the
+    /// batch does not set its idempotence itself.
+    pub(crate) fn with_default_idempotence(mut self, default_idempotence: bool) -> Self {
+        if default_idempotence && !self.is_idempotence_set {
+            self._inner.set_is_idempotent(true);
+        }
+        self
+    }
 }
 
 #[pymethods]
@@ -69,19 +83,33 @@ impl PyBatch {
     #[new]
     #[pyo3(signature = (batch_type=PyBatchType::Logged))]
     fn py_new(batch_type: PyBatchType) -> Self {
-        Self::new(Batch::new(batch_type.into()), vec![], false)
+        Self::new(Batch::new(batch_type.into()), vec![], false, false)
     }
 
-    #[pyo3(signature = (statement, values=None))]
-    fn add(&mut self, statement: ExecutableStatement, values: Option<PyValueList>) {
+    #[pyo3(signature = (statement, values=None, *, none_as_unset=false))]
+    fn add(
+        &mut self,
+        statement: ExecutableStatement,
+        values: Option<PyValueList>,
+        none_as_unset: bool,
+    ) {
         self._inner.append_statement(statement);
-        self.values.push(values.unwrap_or(PyValueList::Empty));
+        self.values.push(
+            values
+                .unwrap_or(PyValueList::Empty)
+                .with_none_as_unset(none_as_unset),
+        );
     }
 
-    fn add_all(&mut self, items: Vec<(ExecutableStatement, Option<PyValueList>)>) {
+    #[pyo3(signature = (items, *, none_as_unset=false))]
+    fn add_all(
+        &mut self,
+        items: Vec<(ExecutableStatement, Option<PyValueList>)>,
+        none_as_unset: bool,
+    ) {
         self.values.reserve_exact(items.len());
         for (statement, values) in items {
-            self.add(statement, values);
+            self.add(statement, values, none_as_unset);
         }
     }
 
@@ -90,37 +118,56 @@ impl PyBatch {
         self._inner.get_type().into()
     }
 
-    fn with_execution_profile(&self, profile: ExecutionProfile) -> Self {
+    fn with_execution_profile(&self, profile: &ExecutionProfile) -> Self {
         let mut batch = self._inner.clone();
-        batch.set_execution_profile_handle(Some(profile._inner.into_handle()));
-        Self::new(batch, self.values.clone(), self.is_serial_consistency_set)
+        batch.set_execution_profile_handle(Some(profile.handle.clone()));
+        Self::new(
+            batch,
+            self.values.clone(),
+            self.is_serial_consistency_set,
+            self.is_idempotence_set,
+        )
     }
 
     fn without_execution_profile(&self) -> Self {
         let mut batch = self._inner.clone();
         batch.set_execution_profile_handle(None);
-        Self::new(batch, self.values.clone(), self.is_serial_consistency_set)
+        Self::new(
+            batch,
+            self.values.clone(),
+            self.is_serial_consistency_set,
+            self.is_idempotence_set,
+        )
     }
 
     #[getter]
     fn get_execution_profile(&self) -> Option<ExecutionProfile> {
         self._inner
             .get_execution_profile_handle()
-            .map(|h| ExecutionProfile {
-                _inner: h.to_profile(),
-            })
+            .cloned()
+            .map(ExecutionProfile::from)
     }
 
     fn with_consistency(&self, c: PyConsistency) -> Self {
         let mut batch = self._inner.clone();
         batch.set_consistency(c.into());
-        Self::new(batch, self.values.clone(), self.is_serial_consistency_set)
+        Self::new(
+            batch,
+            self.values.clone(),
+            self.is_serial_consistency_set,
+            self.is_idempotence_set,
+        )
     }
 
     fn without_consistency(&self) -> Self {
         let mut batch = self._inner.clone();
         batch.unset_consistency();
-        Self::new(batch, self.values.clone(), self.is_serial_consistency_set)
+        Self::new(
+            batch,
+            self.values.clone(),
+            self.is_serial_consistency_set,
+            self.is_idempotence_set,
+        )
     }
 
     #[getter]
@@ -131,13 +178,13 @@ impl PyBatch {
     fn with_serial_consistency(&self, sc: Option<PySerialConsistency>) -> Self {
         let mut batch = self._inner.clone();
         batch.set_serial_consistency(sc.map(SerialConsistency::from));
-        Self::new(batch, self.values.clone(), true)
+        Self::new(batch, self.values.clone(), true, self.is_idempotence_set)
     }
 
     fn without_serial_consistency(&self) -> Self {
         let mut batch = self._inner.clone();
         batch.unset_serial_consistency();
-        Self::new(batch, self.values.clone(), false)
+        Self::new(batch, self.values.clone(), false, self.is_idempotence_set)
     }
 
     #[getter]
@@ -168,13 +215,19 @@ impl PyBatch {
             batch,
             self.values.clone(),
             self.is_serial_consistency_set,
+            self.is_idempotence_set,
         ))
     }
 
     fn without_request_timeout(&self) -> Self {
         let mut batch = self._inner.clone();
         batch.set_request_timeout(None);
-        Self::new(batch, self.values.clone(), self.is_serial_consistency_set)
+        Self::new(
+            batch,
+            self.values.clone(),
+            self.is_serial_consistency_set,
+            self.is_idempotence_set,
+        )
     }
 
     #[getter]
@@ -185,6 +238,49 @@ impl PyBatch {
             None => UnsetType::get_instance(py).into(),
         }
     }
+
+    fn with_timestamp(&self, timestamp: i64) -> Self {
+        let mut batch = self._inner.clone();
+        batch.set_timestamp(Some(timestamp));
+        Self::new(
+            batch,
+            self.values.clone(),
+            self.is_serial_consistency_set,
+            self.is_idempotence_set,
+        )
+    }
+
+    fn without_timestamp(&self) -> Self {
+        let mut batch = self._inner.clone();
+        batch.set_timestamp(None);
+        Self::new(
+            batch,
+            self.values.clone(),
+            self.is_serial_consistency_set,
+            self.is_idempotence_set,
+        )
+    }
+
+    #[getter]
+    fn get_timestamp(&self) -> Option<i64> {
+        self._inner.get_timestamp()
+    }
+
+    fn with_is_idempotent(&self, is_idempotent: bool) -> Self {
+        let mut batch = self._inner.clone();
+        batch.set_is_idempotent(is_idempotent);
+        Self::new(
+            batch,
+            self.values.clone(),
+            self.is_serial_consistency_set,
+            true,
+        )
+    }
+
+    #[getter]
+    fn get_is_idempotent(&self) -> bool {
+        self._inner.get_is_idempotent()
+    }
 }
 
 #[pymodule]
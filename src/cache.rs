@@ -1,4 +1,9 @@
-use std::{borrow::Borrow, collections::HashMap, hash::Hash, sync::RwLock};
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    hash::Hash,
+    sync::{Mutex, RwLock},
+};
 
 use pyo3::{
     PyTypeInfo,
@@ -280,3 +285,80 @@ impl<K, V> Cache<K, V> {
         }
     }
 }
+
+/// Bounded cache evicting the least recently used entry when full.
+///
+/// Used for state that grows with the number of distinct requests made by
+/// the user, such as prepared statements, so that it cannot grow without bound.
+///
+/// Lookups and updates of existing keys take constant time. Eviction scans
+/// all entries to find the least recently used one, which keeps the structure
+/// simple and is cheap for the capacities it is used with. A capacity of `0`
+/// disables caching: `insert` then stores nothing.
+pub(crate) struct LruCache<K, V> {
+    capacity: usize,
+    state: Mutex<LruCacheState<K, V>>,
+}
+
+struct LruCacheState<K, V> {
+    /// Values with the tick of their last use.
+    entries: HashMap<K, (V, u64)>,
+    /// Incremented on every use, ordering entries by recency.
+    tick: u64,
+}
+
+impl<K, V> LruCacheState<K, V> {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> LruCache<K, V> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(LruCacheState {
+                entries: HashMap::new(),
+                tick: 0,
+            }),
+        }
+    }
+
+    /// Returns a clone of the value cached for `key`, marking it as most recently used.
+    pub(crate) fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let mut state = self.state.lock().unwrap();
+        let tick = state.next_tick();
+        state.entries.get_mut(key).map(|(value, last_used)| {
+            *last_used = tick;
+            value.clone()
+        })
+    }
+
+    /// Caches `value` for `key`, replacing the previous value if any.
+    ///
+    /// If the cache is full and `key` is new, the least recently used entry is evicted.
+    pub(crate) fn insert(&self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let tick = state.next_tick();
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.capacity {
+            let least_recently_used = state
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(evicted) = least_recently_used {
+                state.entries.remove(&evicted);
+            }
+        }
+        state.entries.insert(key, (value, tick));
+    }
+}
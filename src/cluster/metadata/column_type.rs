@@ -1,5 +1,10 @@
-use pyo3::{Py, PyClassInitializer, PyResult, Python, pyclass};
-use scylla::cluster::metadata::{CollectionType, ColumnType, NativeType};
+use pyo3::PyClassInitializer;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use scylla::cluster::metadata::{CollectionType, ColumnType, NativeType, UserDefinedType};
+use std::sync::Arc;
+
+use super::ddl;
 
 /// Macro to generate native type subclasses
 macro_rules! native_type_class {
@@ -12,6 +17,14 @@ macro_rules! native_type_class {
                 PyCqlNativeType::new().add_subclass(Self {})
             }
         }
+
+        #[pymethods]
+        impl $py_class {
+            #[new]
+            fn py_new() -> PyClassInitializer<Self> {
+                Self::new()
+            }
+        }
     };
 }
 
@@ -85,6 +98,19 @@ impl PyCqlMap {
     }
 }
 
+#[pymethods]
+impl PyCqlMap {
+    #[new]
+    #[pyo3(signature = (key_type, value_type, *, frozen=false))]
+    fn py_new(
+        key_type: Py<PyCqlColumnType>,
+        value_type: Py<PyCqlColumnType>,
+        frozen: bool,
+    ) -> PyClassInitializer<Self> {
+        Self::new(frozen, key_type, value_type)
+    }
+}
+
 #[pyclass(name = "CqlSet", extends=PyCqlCollectionType, frozen, get_all)]
 pub(crate) struct PyCqlSet {
     pub(crate) column_type: Py<PyCqlColumnType>,
@@ -96,6 +122,15 @@ impl PyCqlSet {
     }
 }
 
+#[pymethods]
+impl PyCqlSet {
+    #[new]
+    #[pyo3(signature = (column_type, *, frozen=false))]
+    fn py_new(column_type: Py<PyCqlColumnType>, frozen: bool) -> PyClassInitializer<Self> {
+        Self::new(frozen, column_type)
+    }
+}
+
 #[pyclass(name = "CqlList", extends=PyCqlCollectionType, frozen, get_all)]
 pub(crate) struct PyCqlList {
     pub(crate) column_type: Py<PyCqlColumnType>,
@@ -107,6 +142,15 @@ impl PyCqlList {
     }
 }
 
+#[pymethods]
+impl PyCqlList {
+    #[new]
+    #[pyo3(signature = (column_type, *, frozen=false))]
+    fn py_new(column_type: Py<PyCqlColumnType>, frozen: bool) -> PyClassInitializer<Self> {
+        Self::new(frozen, column_type)
+    }
+}
+
 #[pyclass(name = "CqlTuple", extends=PyCqlColumnType, frozen, get_all)]
 pub(crate) struct PyCqlTuple {
     pub(crate) element_types: Vec<Py<PyCqlColumnType>>,
@@ -118,6 +162,14 @@ impl PyCqlTuple {
     }
 }
 
+#[pymethods]
+impl PyCqlTuple {
+    #[new]
+    fn py_new(element_types: Vec<Py<PyCqlColumnType>>) -> PyClassInitializer<Self> {
+        Self::new(element_types)
+    }
+}
+
 #[pyclass(name = "CqlVector", extends=PyCqlColumnType, frozen, get_all)]
 pub(crate) struct PyCqlVector {
     pub(crate) typ: Py<PyCqlColumnType>,
@@ -130,6 +182,14 @@ impl PyCqlVector {
     }
 }
 
+#[pymethods]
+impl PyCqlVector {
+    #[new]
+    fn py_new(typ: Py<PyCqlColumnType>, dimensions: u16) -> PyClassInitializer<Self> {
+        Self::new(typ, dimensions)
+    }
+}
+
 #[pyclass(name = "CqlUserDefinedType", extends=PyCqlColumnType, frozen, get_all)]
 pub(crate) struct PyCqlUserDefinedType {
     pub(crate) name: String,
@@ -139,6 +199,24 @@ pub(crate) struct PyCqlUserDefinedType {
 }
 
 impl PyCqlUserDefinedType {
+    fn definition(&self, py: Python<'_>) -> PyResult<UserDefinedType<'static>> {
+        let field_types = self
+            .field_types
+            .iter()
+            .map(|(field_name, field_type)| {
+                Ok((
+                    field_name.clone().into(),
+                    column_type_from_py(field_type.bind(py))?,
+                ))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(UserDefinedType {
+            name: self.name.clone().into(),
+            keyspace: self.keyspace.clone().into(),
+            field_types,
+        })
+    }
+
     pub(crate) fn new(
         name: String,
         frozen: bool,
@@ -153,6 +231,24 @@ impl PyCqlUserDefinedType {
         })
     }
 }
+
+#[pymethods]
+impl PyCqlUserDefinedType {
+    #[new]
+    #[pyo3(signature = (name, keyspace, field_types, *, frozen=false))]
+    fn py_new(
+        name: String,
+        keyspace: String,
+        field_types: Vec<(String, Py<PyCqlColumnType>)>,
+        frozen: bool,
+    ) -> PyClassInitializer<Self> {
+        Self::new(name, frozen, keyspace, field_types)
+    }
+
+    fn export_as_string(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(ddl::create_type(&self.definition(py)?))
+    }
+}
 #[pyclass(name = "CqlColumnType", subclass, frozen)]
 pub(crate) struct PyCqlColumnType {}
 
@@ -249,3 +345,101 @@ pub(crate) fn extract_column_type(
         _ => unreachable!("clippy testifies that the match is exhaustive"),
     }
 }
+
+/// Builds the driver's `ColumnType` from a column type tree constructed in Python.
+/// This is the inverse of `extract_column_type`.
+pub(crate) fn column_type_from_py(
+    column_type: &Bound<'_, PyCqlColumnType>,
+) -> PyResult<ColumnType<'static>> {
+    let py = column_type.py();
+
+    if let Some(native_type) = native_type_from_py(column_type) {
+        return Ok(ColumnType::Native(native_type));
+    }
+    if let Ok(list) = column_type.cast::<PyCqlList>() {
+        let element_type = column_type_from_py(list.get().column_type.bind(py))?;
+        return Ok(ColumnType::Collection {
+            frozen: list.as_super().get().frozen,
+            typ: CollectionType::List(Box::new(element_type)),
+        });
+    }
+    if let Ok(set) = column_type.cast::<PyCqlSet>() {
+        let element_type = column_type_from_py(set.get().column_type.bind(py))?;
+        return Ok(ColumnType::Collection {
+            frozen: set.as_super().get().frozen,
+            typ: CollectionType::Set(Box::new(element_type)),
+        });
+    }
+    if let Ok(map) = column_type.cast::<PyCqlMap>() {
+        let key_type = column_type_from_py(map.get().key_type.bind(py))?;
+        let value_type = column_type_from_py(map.get().value_type.bind(py))?;
+        return Ok(ColumnType::Collection {
+            frozen: map.as_super().get().frozen,
+            typ: CollectionType::Map(Box::new(key_type), Box::new(value_type)),
+        });
+    }
+    if let Ok(tuple) = column_type.cast::<PyCqlTuple>() {
+        let element_types = tuple
+            .get()
+            .element_types
+            .iter()
+            .map(|element_type| column_type_from_py(element_type.bind(py)))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(ColumnType::Tuple(element_types));
+    }
+    if let Ok(vector) = column_type.cast::<PyCqlVector>() {
+        let vector = vector.get();
+        return Ok(ColumnType::Vector {
+            typ: Box::new(column_type_from_py(vector.typ.bind(py))?),
+            dimensions: vector.dimensions,
+        });
+    }
+    if let Ok(udt) = column_type.cast::<PyCqlUserDefinedType>() {
+        let udt = udt.get();
+        return Ok(ColumnType::UserDefinedType {
+            frozen: udt.frozen,
+            definition: Arc::new(udt.definition(py)?),
+        });
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "unsupported column type: {}",
+        column_type.get_type().name()?
+    )))
+}
+
+fn native_type_from_py(column_type: &Bound<'_, PyCqlColumnType>) -> Option<NativeType> {
+    macro_rules! match_native_type {
+        ($($py_class:ident => $native_type:ident),* $(,)?) => {
+            $(
+                if column_type.is_instance_of::<$py_class>() {
+                    return Some(NativeType::$native_type);
+                }
+            )*
+        };
+    }
+
+    match_native_type!(
+        PyCqlAscii => Ascii,
+        PyCqlBigInt => BigInt,
+        PyCqlBlob => Blob,
+        PyCqlBoolean => Boolean,
+        PyCqlCounter => Counter,
+        PyCqlDate => Date,
+        PyCqlDecimal => Decimal,
+        PyCqlDouble => Double,
+        PyCqlDuration => Duration,
+        PyCqlFloat => Float,
+        PyCqlInet => Inet,
+        PyCqlInt => Int,
+        PyCqlSmallInt => SmallInt,
+        PyCqlText => Text,
+        PyCqlTime => Time,
+        PyCqlTimestamp => Timestamp,
+        PyCqlTimeuuid => Timeuuid,
+        PyCqlTinyInt => TinyInt,
+        PyCqlUuid => Uuid,
+        PyCqlVarint => Varint,
+    );
+    None
+}
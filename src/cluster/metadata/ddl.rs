@@ -0,0 +1,383 @@
+//! Rendering of schema metadata as CQL DDL statements.
+//!
+//! The Rust driver's metadata does not carry clustering order, table options,
+//! `durable_writes` or secondary indexes, so the generated statements only
+//! describe what is known: replication, user-defined types, columns and keys.
+//! Clustering order is rendered when it is passed in as `DescendingColumns`,
+//! read separately from `system_schema.columns`.
+
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+
+use scylla::cluster::metadata::{
+    CollectionType, ColumnKind, ColumnType, Keyspace, NativeType, Strategy, Table, UserDefinedType,
+};
+
+/// Clustering columns sorted in descending order, by table name.
+pub(crate) type DescendingColumns = HashMap<String, HashSet<String>>;
+
+/// Keywords which cannot be used as unquoted identifiers.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "add",
+    "allow",
+    "alter",
+    "and",
+    "apply",
+    "asc",
+    "authorize",
+    "batch",
+    "begin",
+    "by",
+    "columnfamily",
+    "create",
+    "delete",
+    "desc",
+    "describe",
+    "drop",
+    "entries",
+    "execute",
+    "from",
+    "full",
+    "grant",
+    "if",
+    "in",
+    "index",
+    "infinity",
+    "insert",
+    "into",
+    "keyspace",
+    "limit",
+    "modify",
+    "nan",
+    "norecursive",
+    "not",
+    "null",
+    "of",
+    "on",
+    "or",
+    "order",
+    "primary",
+    "rename",
+    "replace",
+    "revoke",
+    "schema",
+    "select",
+    "set",
+    "table",
+    "to",
+    "token",
+    "truncate",
+    "unlogged",
+    "update",
+    "use",
+    "using",
+    "view",
+    "where",
+    "with",
+];
+
+/// Quotes the identifier unless it is a lowercase, non-reserved word.
+fn quote_identifier(name: &str) -> Cow<'_, str> {
+    let is_plain = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && !RESERVED_KEYWORDS.contains(&name);
+
+    if is_plain {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(format!("\"{}\"", name.replace('"', "\"\"")))
+    }
+}
+
+fn quote_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn frozen_if(frozen: bool, typ: String) -> String {
+    if frozen {
+        format!("frozen<{typ}>")
+    } else {
+        typ
+    }
+}
+
+/// Returns the CQL name of the type, as it would be written in a `CREATE` statement.
+pub(crate) fn cql_type(typ: &ColumnType) -> String {
+    #[deny(clippy::wildcard_enum_match_arm)]
+    match typ {
+        ColumnType::Native(native) => {
+            #[deny(clippy::wildcard_enum_match_arm)]
+            let name = match native {
+                NativeType::Ascii => "ascii",
+                NativeType::BigInt => "bigint",
+                NativeType::Blob => "blob",
+                NativeType::Boolean => "boolean",
+                NativeType::Counter => "counter",
+                NativeType::Date => "date",
+                NativeType::Decimal => "decimal",
+                NativeType::Double => "double",
+                NativeType::Duration => "duration",
+                NativeType::Float => "float",
+                NativeType::Inet => "inet",
+                NativeType::Int => "int",
+                NativeType::SmallInt => "smallint",
+                NativeType::Text => "text",
+                NativeType::Time => "time",
+                NativeType::Timestamp => "timestamp",
+                NativeType::Timeuuid => "timeuuid",
+                NativeType::TinyInt => "tinyint",
+                NativeType::Uuid => "uuid",
+                NativeType::Varint => "varint",
+                _ => unreachable!("clippy testifies that the match is exhaustive"),
+            };
+            name.to_owned()
+        }
+        ColumnType::Collection { frozen, typ } => {
+            let collection = match typ {
+                CollectionType::List(element) => format!("list<{}>", cql_type(element)),
+                CollectionType::Set(element) => format!("set<{}>", cql_type(element)),
+                CollectionType::Map(key, value) => {
+                    format!("map<{}, {}>", cql_type(key), cql_type(value))
+                }
+                _ => unreachable!("clippy testifies that the match is exhaustive"),
+            };
+            frozen_if(*frozen, collection)
+        }
+        ColumnType::Tuple(elements) => {
+            let elements = elements.iter().map(cql_type).collect::<Vec<_>>();
+            format!("frozen<tuple<{}>>", elements.join(", "))
+        }
+        ColumnType::Vector { typ, dimensions } => {
+            format!("vector<{}, {dimensions}>", cql_type(typ))
+        }
+        ColumnType::UserDefinedType { frozen, definition } => {
+            frozen_if(*frozen, quote_identifier(&definition.name).into_owned())
+        }
+        _ => unreachable!("clippy testifies that the match is exhaustive"),
+    }
+}
+
+/// Collects names of the user-defined types referenced by `typ`.
+fn referenced_types<'a>(typ: &'a ColumnType, out: &mut BTreeSet<&'a str>) {
+    match typ {
+        ColumnType::Collection { typ, .. } => match typ {
+            CollectionType::List(element) | CollectionType::Set(element) => {
+                referenced_types(element, out)
+            }
+            CollectionType::Map(key, value) => {
+                referenced_types(key, out);
+                referenced_types(value, out);
+            }
+            _ => {}
+        },
+        ColumnType::Tuple(elements) => elements.iter().for_each(|e| referenced_types(e, out)),
+        ColumnType::Vector { typ, .. } => referenced_types(typ, out),
+        ColumnType::UserDefinedType { definition, .. } => {
+            out.insert(definition.name.as_ref());
+        }
+        _ => {}
+    }
+}
+
+fn create_keyspace(name: &str, strategy: &Strategy) -> String {
+    #[deny(clippy::wildcard_enum_match_arm)]
+    let replication = match strategy {
+        Strategy::SimpleStrategy {
+            replication_factor, ..
+        } => vec![
+            ("class".to_owned(), "SimpleStrategy".to_owned()),
+            (
+                "replication_factor".to_owned(),
+                replication_factor.to_string(),
+            ),
+        ],
+        Strategy::NetworkTopologyStrategy {
+            datacenter_repfactors,
+        } => {
+            let mut options = vec![("class".to_owned(), "NetworkTopologyStrategy".to_owned())];
+            let mut datacenters = datacenter_repfactors.iter().collect::<Vec<_>>();
+            datacenters.sort();
+            options.extend(
+                datacenters
+                    .into_iter()
+                    .map(|(dc, rf)| (dc.clone(), rf.to_string())),
+            );
+            options
+        }
+        Strategy::Other { name, data } => {
+            let mut options = vec![("class".to_owned(), name.clone())];
+            let mut data = data.iter().collect::<Vec<_>>();
+            data.sort();
+            options.extend(data.into_iter().map(|(k, v)| (k.clone(), v.clone())));
+            options
+        }
+        Strategy::LocalStrategy => vec![("class".to_owned(), "LocalStrategy".to_owned())],
+        _ => unreachable!("clippy testifies that the match is exhaustive"),
+    }
+    .into_iter()
+    .map(|(key, value)| format!("{}: {}", quote_string(&key), quote_string(&value)))
+    .collect::<Vec<_>>();
+
+    format!(
+        "CREATE KEYSPACE {} WITH replication = {{{}}};",
+        quote_identifier(name),
+        replication.join(", ")
+    )
+}
+
+/// Renders the `CREATE TYPE` statement of the user-defined type.
+pub(crate) fn create_type(definition: &UserDefinedType) -> String {
+    let mut out = format!(
+        "CREATE TYPE {}.{} (\n",
+        quote_identifier(&definition.keyspace),
+        quote_identifier(&definition.name)
+    );
+    let fields = definition
+        .field_types
+        .iter()
+        .map(|(name, typ)| format!("    {} {}", quote_identifier(name), cql_type(typ)))
+        .collect::<Vec<_>>();
+    out.push_str(&fields.join(",\n"));
+    out.push_str("\n);");
+    out
+}
+
+/// Renders the `CREATE TABLE` statement of the table.
+///
+/// Partition key columns come first, followed by clustering columns
+/// and then the remaining columns in alphabetical order. With `descending`,
+/// the clustering order of every clustering column is rendered as well.
+pub(crate) fn create_table(
+    keyspace: &str,
+    name: &str,
+    table: &Table,
+    descending: Option<&DescendingColumns>,
+) -> String {
+    let mut regular = table
+        .columns
+        .iter()
+        .filter(|(_, column)| {
+            !matches!(
+                column.kind,
+                ColumnKind::PartitionKey | ColumnKind::Clustering
+            )
+        })
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    regular.sort_unstable();
+
+    let column_names = table
+        .partition_key
+        .iter()
+        .chain(table.clustering_key.iter())
+        .map(String::as_str)
+        .chain(regular);
+
+    let mut out = format!(
+        "CREATE TABLE {}.{} (\n",
+        quote_identifier(keyspace),
+        quote_identifier(name)
+    );
+    for column_name in column_names {
+        let Some(column) = table.columns.get(column_name) else {
+            continue;
+        };
+        let _ = write!(
+            out,
+            "    {} {}",
+            quote_identifier(column_name),
+            cql_type(&column.typ)
+        );
+        if matches!(column.kind, ColumnKind::Static) {
+            out.push_str(" static");
+        }
+        out.push_str(",\n");
+    }
+
+    let quote_all = |names: &[String]| {
+        names
+            .iter()
+            .map(|name| quote_identifier(name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let partition_key = quote_all(&table.partition_key);
+    let _ = write!(out, "    PRIMARY KEY (({partition_key})");
+    if !table.clustering_key.is_empty() {
+        let _ = write!(out, ", {}", quote_all(&table.clustering_key));
+    }
+    out.push_str(")\n)");
+
+    if let Some(descending) = descending.filter(|_| !table.clustering_key.is_empty()) {
+        let descending = descending.get(name);
+        let orders = table
+            .clustering_key
+            .iter()
+            .map(|column| {
+                let order = if descending.is_some_and(|columns| columns.contains(column)) {
+                    "DESC"
+                } else {
+                    "ASC"
+                };
+                format!("{} {order}", quote_identifier(column))
+            })
+            .collect::<Vec<_>>();
+        let _ = write!(out, " WITH CLUSTERING ORDER BY ({})", orders.join(", "));
+    }
+    out.push(';');
+    out
+}
+
+/// Renders the keyspace together with all its user-defined types and tables.
+///
+/// Types are ordered so that every type is created after the types it refers to.
+/// Materialized views are not included. `descending` is passed to `create_table`.
+pub(crate) fn export_keyspace(
+    name: &str,
+    keyspace: &Keyspace,
+    descending: Option<&DescendingColumns>,
+) -> String {
+    let mut statements = vec![create_keyspace(name, &keyspace.strategy)];
+
+    let mut type_names = keyspace.user_defined_types.keys().collect::<Vec<_>>();
+    type_names.sort_unstable();
+    let mut emitted = BTreeSet::new();
+    for type_name in type_names {
+        push_type(keyspace, type_name, &mut emitted, &mut statements);
+    }
+
+    let mut table_names = keyspace.tables.keys().collect::<Vec<_>>();
+    table_names.sort_unstable();
+    statements.extend(table_names.into_iter().map(|table_name| {
+        create_table(name, table_name, &keyspace.tables[table_name], descending)
+    }));
+
+    statements.join("\n\n")
+}
+
+fn push_type<'a>(
+    keyspace: &'a Keyspace,
+    type_name: &'a str,
+    emitted: &mut BTreeSet<&'a str>,
+    statements: &mut Vec<String>,
+) {
+    let Some(definition) = keyspace.user_defined_types.get(type_name) else {
+        return;
+    };
+    if !emitted.insert(type_name) {
+        return;
+    }
+
+    let mut dependencies = BTreeSet::new();
+    for (_, typ) in &definition.field_types {
+        referenced_types(typ, &mut dependencies);
+    }
+    for dependency in dependencies {
+        push_type(keyspace, dependency, emitted, statements);
+    }
+
+    statements.push(create_type(definition));
+}
@@ -8,9 +8,10 @@ use pyo3::{
 };
 use scylla::cluster::metadata::{Column, ColumnKind, Keyspace, MaterializedView, Strategy, Table};
 
-use crate::{cache::Cache, cluster::metadata::column_type::*};
+use crate::{cache::Cache, cluster::metadata::column_type::*, utils::cql_identifier};
 
-mod column_type;
+pub(crate) mod column_type;
+pub(crate) mod ddl;
 
 #[pyclass(name = "StrategyKind", eq, eq_int, frozen, skip_from_py_object)]
 #[derive(Clone, Copy, PartialEq)]
@@ -122,20 +123,30 @@ impl From<&ColumnKind> for PyColumnKind {
 pub(crate) struct PyColumn {
     _inner: Column,
     #[pyo3(get)]
+    name: Py<PyString>,
+    #[pyo3(get)]
     typ: Py<PyCqlColumnType>,
     #[pyo3(get)]
     kind: Py<PyColumnKind>,
+    /// Index of the column within the partition or the clustering key of its table.
+    #[pyo3(get)]
+    position: Option<usize>,
 }
 
-impl TryFrom<&Column> for PyColumn {
-    type Error = PyErr;
-    fn try_from(value: &Column) -> Result<Self, Self::Error> {
-        Python::attach(|py| {
-            Ok(Self {
-                typ: extract_column_type(py, &value.typ)?,
-                kind: Py::new(py, PyColumnKind::from(&value.kind))?,
-                _inner: value.clone(),
-            })
+impl PyColumn {
+    fn new(py: Python<'_>, table: &Table, name: &str, value: &Column) -> PyResult<Self> {
+        let key = match value.kind {
+            ColumnKind::PartitionKey => Some(&table.partition_key),
+            ColumnKind::Clustering => Some(&table.clustering_key),
+            _ => None,
+        };
+
+        Ok(Self {
+            name: PyString::new(py, name).unbind(),
+            typ: extract_column_type(py, &value.typ)?,
+            kind: Py::new(py, PyColumnKind::from(&value.kind))?,
+            position: key.and_then(|key| key.iter().position(|key_name| key_name == name)),
+            _inner: value.clone(),
         })
     }
 }
@@ -146,8 +157,10 @@ impl PyColumn {
         PyString::from_fmt(
             py,
             format_args!(
-                "Column(typ='{:?}', kind={:?})",
-                self._inner.typ, self._inner.kind
+                "Column(name='{}', typ='{:?}', kind={:?})",
+                self.name.bind(py),
+                self._inner.typ,
+                self._inner.kind
             ),
         )
     }
@@ -156,6 +169,8 @@ impl PyColumn {
 #[pyclass(name = "Table", frozen, skip_from_py_object)]
 pub(crate) struct PyTable {
     _inner: Table,
+    keyspace_name: String,
+    name: String,
     partitioner: OnceLock<Option<Py<PyString>>>,
     /// Invariant: Holds all known `PyColumn`s of the table.
     columns: Py<PyDict>,
@@ -169,10 +184,8 @@ pub(crate) struct PyTable {
     clustering_key: Py<PyDict>,
 }
 
-impl TryFrom<Table> for PyTable {
-    type Error = PyErr;
-
-    fn try_from(inner: Table) -> Result<Self, Self::Error> {
+impl PyTable {
+    fn new(keyspace_name: String, name: String, inner: Table) -> PyResult<Self> {
         Python::attach(|py| {
             let py_cols = PyDict::new(py);
             let py_partition_key = PyDict::new(py);
@@ -180,7 +193,7 @@ impl TryFrom<Table> for PyTable {
 
             // Initialize columns dictionary
             for (name, column) in inner.columns.iter() {
-                py_cols.set_item(name, PyColumn::try_from(column)?)?;
+                py_cols.set_item(name, PyColumn::new(py, &inner, name, column)?)?;
             }
 
             // Reuse the same columns for partition and clustering keys
@@ -194,6 +207,8 @@ impl TryFrom<Table> for PyTable {
 
             Ok(Self {
                 _inner: inner,
+                keyspace_name,
+                name,
                 partitioner: OnceLock::new(),
                 columns: py_cols.unbind(),
                 partition_key: py_partition_key.unbind(),
@@ -230,6 +245,29 @@ impl PyTable {
         })
     }
 
+    #[pyo3(signature = (name, *, normalize_case=false))]
+    fn has_column(&self, name: &str, normalize_case: bool) -> bool {
+        self._inner
+            .columns
+            .contains_key(cql_identifier(name, normalize_case).as_ref())
+    }
+
+    #[pyo3(signature = (name, *, normalize_case=false))]
+    fn column<'py>(
+        &self,
+        py: Python<'py>,
+        name: &str,
+        normalize_case: bool,
+    ) -> PyResult<Option<Bound<'py, PyAny>>> {
+        self.columns
+            .bind(py)
+            .get_item(cql_identifier(name, normalize_case).as_ref())
+    }
+
+    fn export_as_string(&self) -> String {
+        ddl::create_table(&self.keyspace_name, &self.name, &self._inner, None)
+    }
+
     fn __repr__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyString>> {
         PyString::from_fmt(
             py,
@@ -271,7 +309,7 @@ impl TryFrom<MaterializedView> for PyMaterializedView {
 
             // Initialize columns dictionary
             for (name, column) in inner.view_metadata.columns.iter() {
-                py_cols.set_item(name, PyColumn::try_from(column)?)?;
+                py_cols.set_item(name, PyColumn::new(py, &inner.view_metadata, name, column)?)?;
             }
 
             // Reuse the same columns for partition and clustering keys
@@ -345,6 +383,7 @@ impl PyMaterializedView {
 #[pyclass(name = "Keyspace", frozen)]
 pub(crate) struct PyKeyspace {
     pub(crate) _inner: Keyspace,
+    pub(crate) name: String,
     pub(crate) strategy: OnceLock<Py<PyStrategy>>,
     /// Tables in this keyspace.
     pub(crate) tables: Cache<String, PyTable>,
@@ -352,10 +391,11 @@ pub(crate) struct PyKeyspace {
     pub(crate) views: Cache<String, PyMaterializedView>,
 }
 
-impl From<Keyspace> for PyKeyspace {
-    fn from(inner: Keyspace) -> Self {
+impl PyKeyspace {
+    pub(crate) fn new(name: String, inner: Keyspace) -> Self {
         Self {
             _inner: inner,
+            name,
             strategy: OnceLock::new(),
             tables: Cache::new(),
             views: Cache::new(),
@@ -384,13 +424,20 @@ impl PyKeyspace {
                 .tables
                 .iter()
                 .map(|(name, table)| {
-                    let py_table = PyTable::try_from(table.clone());
+                    let py_table = PyTable::new(self.name.clone(), name.clone(), table.clone());
                     (name.clone(), py_table.and_then(|t| Py::new(py, t)))
                 })
                 .collect()
         })
     }
 
+    #[pyo3(signature = (name, *, normalize_case=false))]
+    fn has_table(&self, name: &str, normalize_case: bool) -> bool {
+        self._inner
+            .tables
+            .contains_key(cql_identifier(name, normalize_case).as_ref())
+    }
+
     #[getter]
     fn views<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyMappingProxy>> {
         self.views.get_or_init_python_mapping(py, || {
@@ -405,6 +452,10 @@ impl PyKeyspace {
         })
     }
 
+    fn export_as_string(&self) -> String {
+        ddl::export_keyspace(&self.name, &self._inner, None)
+    }
+
     fn __repr__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyString>> {
         PyString::from_fmt(
             py,
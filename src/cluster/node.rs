@@ -8,6 +8,8 @@ use uuid::Uuid;
 
 use scylla::cluster::Node;
 
+use crate::routing::PySharder;
+
 #[pyclass(name = "Node", frozen)]
 pub(crate) struct PyNode {
     pub(crate) _inner: Arc<Node>,
@@ -63,6 +65,15 @@ impl PyNode {
             .map(|rack| rack.clone_ref(py))
     }
 
+    fn __eq__(&self, other: &PyNode) -> bool {
+        self._inner.host_id == other._inner.host_id
+    }
+
+    fn __hash__(&self) -> u64 {
+        let (high, low) = self._inner.host_id.as_u64_pair();
+        high ^ low
+    }
+
     fn __repr__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyString>> {
         PyString::from_fmt(
             py,
@@ -78,6 +89,11 @@ impl PyNode {
         self._inner.sharder().map(|s| s.nr_shards.get() as usize)
     }
 
+    #[getter]
+    fn sharder(&self) -> Option<PySharder> {
+        self._inner.sharder().map(PySharder::from)
+    }
+
     #[getter]
     fn connected(&self) -> bool {
         self._inner.is_connected()
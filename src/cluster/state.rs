@@ -1,10 +1,11 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use pyo3::{
     prelude::*,
     types::{PyDict, PyList, PyMappingProxy, PyString},
 };
 use scylla::cluster::ClusterState;
+use scylla::routing::Token;
 
 use crate::{
     cache::Cache,
@@ -13,6 +14,7 @@ use crate::{
     errors::DriverClusterStateTokenError,
     routing::{PyReplicaLocator, PyToken},
     serialize::value_list::PyValueList,
+    utils::cql_identifier,
 };
 
 #[pyclass(name = "ClusterState", frozen, skip_from_py_object)]
@@ -44,15 +46,18 @@ impl TryFrom<Arc<ClusterState>> for PyClusterState {
 
 #[pymethods]
 impl PyClusterState {
+    #[pyo3(signature = (keyspace, *, normalize_case=false))]
     fn get_keyspace<'py>(
         &self,
         py: Python<'py>,
         keyspace: Py<PyString>,
+        normalize_case: bool,
     ) -> PyResult<Option<Py<PyKeyspace>>> {
-        self.keyspaces.get_or_init(py, keyspace.to_str(py)?, |key| {
+        let keyspace = cql_identifier(keyspace.to_str(py)?, normalize_case);
+        self.keyspaces.get_or_init(py, &*keyspace, |key| {
             self._inner
                 .get_keyspace(key)
-                .map(|ks| Py::new(py, PyKeyspace::from(ks.clone())))
+                .map(|ks| Py::new(py, PyKeyspace::new(key.to_owned(), ks.clone())))
                 .transpose()
         })
     }
@@ -65,18 +70,62 @@ impl PyClusterState {
                 .map(|(name, keyspace)| {
                     (
                         name.to_string(),
-                        Py::new(py, PyKeyspace::from(keyspace.clone())),
+                        Py::new(py, PyKeyspace::new(name.to_string(), keyspace.clone())),
                     )
                 })
                 .collect()
         })
     }
 
+    /// Names of all keyspaces, without converting their metadata to Python objects.
+    fn keyspace_names(&self) -> Vec<String> {
+        self._inner
+            .keyspaces_iter()
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+
+    /// `(keyspace, table)` pairs of all tables, without converting their metadata to Python objects.
+    fn all_tables(&self) -> Vec<(String, String)> {
+        let mut tables: Vec<(String, String)> = self
+            ._inner
+            .keyspaces_iter()
+            .flat_map(|(keyspace_name, keyspace)| {
+                keyspace
+                    .tables
+                    .keys()
+                    .map(move |table_name| (keyspace_name.to_string(), table_name.clone()))
+            })
+            .collect();
+        tables.sort();
+        tables
+    }
+
     #[getter]
     fn get_nodes_info<'py>(&self, py: Python<'py>) -> Bound<'py, PyMappingProxy> {
         PyMappingProxy::new(py, self.known_nodes.bind(py).as_mapping())
     }
 
+    /// Node and datacenter counts, for a quick health overview of the cluster.
+    fn size_summary<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let nodes = self._inner.get_nodes_info();
+        let up_nodes = nodes
+            .iter()
+            .filter(|node| node.is_connected() && node.is_enabled())
+            .count();
+        let datacenters = nodes
+            .iter()
+            .filter_map(|node| node.datacenter.as_deref())
+            .collect::<HashSet<_>>()
+            .len();
+
+        let summary = PyDict::new(py);
+        summary.set_item("total_nodes", nodes.len())?;
+        summary.set_item("up_nodes", up_nodes)?;
+        summary.set_item("datacenters", datacenters)?;
+        Ok(summary)
+    }
+
     fn compute_token(
         &self,
         keyspace: &str,
@@ -112,6 +161,51 @@ impl PyClusterState {
         Ok(list)
     }
 
+    /// Splits the token range `(start, end]` at the tokens of the ring and returns
+    /// `(start, end, replicas)` for each part, in ring order.
+    ///
+    /// The range wraps around the end of the ring if `start >= end`.
+    fn get_token_range_endpoints<'py>(
+        &self,
+        keyspace: &str,
+        table: &str,
+        start: &PyToken,
+        end: &PyToken,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyList>> {
+        let (start, end) = (start._inner, end._inner);
+        let contains = |token: Token| {
+            if start < end {
+                start < token && token < end
+            } else {
+                start < token || token < end
+            }
+        };
+
+        let mut ring_tokens: Vec<Token> = self
+            ._inner
+            .replica_locator()
+            .ring()
+            .iter()
+            .map(|(token, _)| *token)
+            .filter(|token| contains(*token))
+            .collect();
+        ring_tokens.sort();
+        // For a wrapping range, tokens past `start` come before the wrapped ones.
+        let (after_start, wrapped): (Vec<Token>, Vec<Token>) =
+            ring_tokens.into_iter().partition(|token| *token > start);
+
+        let list = PyList::empty(py);
+        let mut range_start = start;
+        for range_end in after_start.into_iter().chain(wrapped).chain([end]) {
+            let range_end = PyToken::from(range_end);
+            let replicas = self.get_token_endpoints(keyspace, table, &range_end, py)?;
+            list.append((PyToken::from(range_start), range_end.clone(), replicas))?;
+            range_start = range_end._inner;
+        }
+        Ok(list)
+    }
+
     fn get_endpoints<'py>(
         &self,
         keyspace: &str,
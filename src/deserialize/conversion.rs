@@ -54,6 +54,8 @@ impl<'py> IntoPyObject<'py> for CqlDurationWrapper {
         let kwargs = PyDict::new(py);
         kwargs.set_item("months", duration.months)?;
         kwargs.set_item("days", duration.days)?;
+        // `relativedelta` has microsecond precision. Division truncates towards
+        // zero, so negative durations stay negative.
         kwargs.set_item("microseconds", duration.nanoseconds / 1000)?;
 
         cls.call((), Some(&kwargs))
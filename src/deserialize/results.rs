@@ -1,12 +1,23 @@
-use crate::deserialize::value::{PyDeserializeValue, PyDeserializedValue};
-use crate::errors::{DriverDeserializationError, DriverExecuteError, DriverRowIterationError};
+use crate::cluster::metadata::column_type::{PyCqlColumnType, extract_column_type};
+use crate::cluster::metadata::ddl::cql_type;
+use crate::deserialize::value::{
+    ColumnDeserializer, DeserializationOptions, PyDeserializedValue, column_deserializer,
+};
+use crate::errors::{
+    DriverDeserializationError, DriverExecuteError, DriverLwtResultError, DriverPagingStateError,
+    DriverRowIterationError, DriverTypedRowError,
+};
 use crate::serialize::value_list::PyValueList;
 use crate::session::{ExecutableStatement, PySession};
-use pyo3::exceptions::{PyRuntimeError, PyStopAsyncIteration, PyStopIteration};
-use pyo3::prelude::{PyDictMethods, PyListMethods, PyModule, PyModuleMethods};
-use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+use pyo3::exceptions::{
+    PyImportError, PyRuntimeError, PyStopAsyncIteration, PyStopIteration, PyTypeError,
+};
+use pyo3::prelude::{PyAnyMethods, PyDictMethods, PyListMethods, PyModule, PyModuleMethods};
+use pyo3::sync::PyOnceLock;
+use pyo3::types::{PyBytes, PyDict, PyList, PyString, PyTuple, PyType};
 use pyo3::{
-    Bound, Py, PyAny, PyErr, PyRef, PyRefMut, PyResult, Python, pyclass, pymethods, pymodule,
+    Bound, IntoPyObjectExt, Py, PyAny, PyClassInitializer, PyErr, PyRef, PyRefMut, PyResult,
+    Python, intern, pyclass, pymethods, pymodule,
 };
 use scylla::deserialize::DeserializationError as ScyllaDeserializationError;
 use scylla::response::query_result::QueryResult;
@@ -14,7 +25,9 @@ use scylla_cql::deserialize::FrameSlice;
 use scylla_cql::deserialize::result::RawRowIterator;
 use scylla_cql::deserialize::row::{ColumnIterator, RawColumn};
 use scylla_cql::frame::request::query::{PagingState, PagingStateResponse};
+use scylla_cql::frame::response::result::{ColumnType, NativeType};
 use stable_deref_trait::StableDeref;
+use std::collections::HashMap;
 use std::iter::Enumerate;
 use std::ops::Deref;
 use std::sync::Arc;
@@ -33,6 +46,13 @@ pub(crate) struct RequestResult {
 }
 
 impl RequestResult {
+    /// Number of rows in the current page, read from the result metadata.
+    fn current_page_rows_count(&self) -> usize {
+        self.query_result
+            .deserialized_metadata_and_rows()
+            .map_or(0, |rows| rows.rows_count())
+    }
+
     pub(crate) fn new(
         query_result: QueryResult,
         query_pager: Pager,
@@ -44,6 +64,65 @@ impl RequestResult {
             row_factory,
         }
     }
+
+    /// Collects the rows of all pages, built by `factory`, into a list.
+    async fn collect_rows(&self, factory: Option<Py<RowFactory>>) -> PyResult<Py<PyList>> {
+        let mut query_pager_clone = self.query_pager.clone();
+
+        let (mut rows_iterator, list) =
+            Python::attach(|py| -> PyResult<(RowsIteratorKind, Py<PyList>)> {
+                Ok((
+                    RowsIteratorKind::new(py, self.query_result.clone(), factory)?,
+                    PyList::empty(py).into(),
+                ))
+            })?;
+
+        // Drain all rows from the current page, then fetch the next page.
+        // This is done to hold the GIL for longer and avoid frequent reacquisition.
+
+        let mut next_page: Option<QueryResult> = None;
+        loop {
+            Python::attach(|py| -> PyResult<()> {
+                if let Some(next_page) = next_page.take() {
+                    rows_iterator.update(py, Arc::new(next_page))?;
+                }
+
+                while let Some(res_row) = rows_iterator.next(py) {
+                    list.bind(py).append(res_row?)?;
+                }
+
+                Ok(())
+            })?;
+
+            if let Some(res) = query_pager_clone.fetch_next_page().await {
+                next_page = Some(res?);
+            } else {
+                break;
+            }
+        }
+
+        Ok(list)
+    }
+}
+
+/// Returns the pandas dtype used by `RequestResult.to_pandas` for a column.
+///
+/// Nullable extension dtypes are used, so that nulls do not turn integers
+/// into floats. Timestamps use millisecond resolution, the precision of CQL
+/// `timestamp`, which also covers its whole range.
+fn pandas_dtype(typ: &ColumnType) -> &'static str {
+    match typ {
+        ColumnType::Native(NativeType::TinyInt) => "Int8",
+        ColumnType::Native(NativeType::SmallInt) => "Int16",
+        ColumnType::Native(NativeType::Int) => "Int32",
+        ColumnType::Native(NativeType::BigInt | NativeType::Counter) => "Int64",
+        ColumnType::Native(NativeType::Float) => "Float32",
+        ColumnType::Native(NativeType::Double) => "Float64",
+        ColumnType::Native(NativeType::Boolean) => "boolean",
+        ColumnType::Native(NativeType::Text | NativeType::Ascii) => "string",
+        ColumnType::Native(NativeType::Timestamp) => "datetime64[ms, UTC]",
+        _ => "object",
+    }
 }
 
 #[pymethods]
@@ -57,6 +136,54 @@ impl RequestResult {
         self.query_pager.has_more_pages()
     }
 
+    /// Returns `true` if the result holds any rows.
+    ///
+    /// Reads the row count from the result metadata, so no rows are consumed.
+    ///
+    /// # Errors
+    ///
+    /// Raises `TypeError` if the current page is empty but more pages remain
+    /// to be fetched. The server may return empty pages before the ones
+    /// holding rows, e.g. with `ALLOW FILTERING`, so the answer is unknown.
+    fn __bool__(&self) -> PyResult<bool> {
+        if self.current_page_rows_count() > 0 {
+            return Ok(true);
+        }
+        if self.query_pager.has_more_pages() {
+            return Err(PyTypeError::new_err(
+                "bool() of an empty page of a RequestResult with more pages to fetch is unknown; \
+                 use first_row() or all() instead",
+            ));
+        }
+
+        Ok(false)
+    }
+
+    /// Returns the number of rows, once the whole result is in memory.
+    ///
+    /// # Errors
+    ///
+    /// Raises `TypeError` if more pages remain to be fetched, as the total
+    /// number of rows is not known until all of them are materialized.
+    fn __len__(&self) -> PyResult<usize> {
+        if self.query_pager.has_more_pages() {
+            return Err(PyTypeError::new_err(
+                "len() of a RequestResult with more pages to fetch is unknown; \
+                 materialize the rows with all() first",
+            ));
+        }
+
+        Ok(self.current_page_rows_count())
+    }
+
+    /// Returns the tracing ID of the request, if it was executed with tracing.
+    ///
+    /// The ID can be passed to `Session.get_tracing_info()`.
+    #[getter]
+    fn tracing_id(&self) -> Option<uuid::Uuid> {
+        self.query_result.tracing_id()
+    }
+
     /// Returns the current paging state.
     ///
     /// Can be `None` if there are no more pages available.
@@ -164,41 +291,147 @@ impl RequestResult {
     ///
     /// Returns an error if fetching or deserialization fails.
     pub async fn all(&self) -> PyResult<Py<PyList>> {
-        let mut query_pager_clone = self.query_pager.clone();
-
-        let (mut rows_iterator, list) =
-            Python::attach(|py| -> PyResult<(RowsIteratorKind, Py<PyList>)> {
-                Ok((
-                    RowsIteratorKind::new(py, self.query_result.clone(), self.row_factory.clone())?,
-                    PyList::empty(py).into(),
-                ))
-            })?;
+        self.collect_rows(self.row_factory.clone()).await
+    }
 
-        // Drain all rows from the current page, then fetch the next page.
-        // This is done to hold the GIL for longer and avoid frequent reacquisition.
+    /// Materializes all rows from all pages into a `pandas.DataFrame`.
+    ///
+    /// Columns get a pandas dtype derived from their CQL type, with nulls as
+    /// pandas missing values. Columns of other types keep the Python objects
+    /// produced by the default row factory, with `None` for nulls.
+    ///
+    /// # Errors
+    ///
+    /// Raises `ImportError` if pandas is not installed, and the errors of
+    /// `all()` if fetching or deserialization fails.
+    pub async fn to_pandas(&self) -> PyResult<Py<PyAny>> {
+        // Checked before fetching any page, so a missing pandas fails fast.
+        let pandas = Python::attach(|py| {
+            py.import("pandas").map(Bound::unbind).map_err(|err| {
+                let import_error = PyImportError::new_err(
+                    "RequestResult.to_pandas() requires pandas to be installed",
+                );
+                import_error.set_cause(py, Some(err));
+                import_error
+            })
+        })?;
 
-        let mut next_page: Option<QueryResult> = None;
-        loop {
-            Python::attach(|py| -> PyResult<()> {
-                if let Some(next_page) = next_page.take() {
-                    rows_iterator.update(py, Arc::new(next_page))?;
+        let rows = self.collect_rows(None).await?;
+
+        let columns: Vec<(String, &'static str)> = self
+            .query_result
+            .deserialized_metadata_and_rows()
+            .map(|rows| {
+                rows.metadata()
+                    .col_specs()
+                    .iter()
+                    .map(|spec| (spec.name().to_owned(), pandas_dtype(spec.typ())))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Python::attach(|py| {
+            let pandas = pandas.bind(py);
+            let rows = rows.bind(py);
+            let data = PyDict::new(py);
+            for (name, dtype) in columns {
+                let values = PyList::empty(py);
+                for row in rows.iter() {
+                    values.append(row.get_item(&name)?)?;
                 }
+                let kwargs = PyDict::new(py);
+                kwargs.set_item(intern!(py, "dtype"), dtype)?;
+                let series = pandas.call_method(intern!(py, "Series"), (values,), Some(&kwargs))?;
+                data.set_item(name, series)?;
+            }
 
-                while let Some(res_row) = rows_iterator.next(py) {
-                    list.bind(py).append(res_row?)?;
-                }
+            Ok(pandas
+                .call_method1(intern!(py, "DataFrame"), (data,))?
+                .unbind())
+        })
+    }
 
-                Ok(())
-            })?;
+    /// Decodes the result of a conditional (LWT) statement.
+    ///
+    /// Reads the first row of the current page and splits it into the
+    /// `[applied]` flag and the remaining columns. When the condition was
+    /// not met, Scylla returns the current values of the row, which are
+    /// exposed as `current_row` so that compare-and-set loops can retry.
+    ///
+    /// # Returns
+    ///
+    /// `LwtResult` with `applied` set and `current_row` holding the remaining
+    /// columns, which is empty if the server returned only `[applied]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result has no rows, the first row has no
+    /// boolean `[applied]` column, or deserialization of the row fails.
+    fn lwt_result(&self, py: Python<'_>) -> Result<LwtResult, DriverLwtResultError> {
+        let rows_iterator = RowsIteratorKind::new(py, self.query_result.clone(), None)
+            .map_err(DriverLwtResultError::python_error)?;
+
+        let row = match rows_iterator.next(py) {
+            Some(row) => row.map_err(DriverLwtResultError::RowIteration)?,
+            None => return Err(DriverLwtResultError::not_an_lwt_result()),
+        };
 
-            if let Some(res) = query_pager_clone.fetch_next_page().await {
-                next_page = Some(res?);
-            } else {
-                break;
-            }
-        }
+        // Default row factory always produces a dict.
+        let row = row
+            .bind(py)
+            .cast::<PyDict>()
+            .map_err(|err| DriverLwtResultError::python_error(err.into()))?
+            .clone();
 
-        Ok(list)
+        let applied = match row
+            .get_item(LWT_APPLIED_COLUMN)
+            .map_err(DriverLwtResultError::python_error)?
+        {
+            Some(applied) => applied
+                .extract::<bool>()
+                .map_err(|_| DriverLwtResultError::not_an_lwt_result())?,
+            None => return Err(DriverLwtResultError::not_an_lwt_result()),
+        };
+
+        row.del_item(LWT_APPLIED_COLUMN)
+            .map_err(DriverLwtResultError::python_error)?;
+
+        Ok(LwtResult {
+            applied,
+            current_row: row.unbind(),
+        })
+    }
+}
+
+/// Name of the boolean column Scylla adds to results of conditional statements.
+const LWT_APPLIED_COLUMN: &str = "[applied]";
+
+/// Decoded result of a conditional (LWT) statement.
+///
+/// `applied` tells whether the condition was met. `current_row` holds the
+/// values of the row returned by the server, without the `[applied]` column.
+/// If the condition was not met, these are the current values of the row.
+/// Otherwise they depend on the server and may be empty or all null.
+#[pyclass(frozen)]
+pub struct LwtResult {
+    #[pyo3(get)]
+    applied: bool,
+    #[pyo3(get)]
+    current_row: Py<PyDict>,
+}
+
+#[pymethods]
+impl LwtResult {
+    fn __bool__(&self) -> bool {
+        self.applied
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!(
+            "LwtResult(applied={}, current_row={})",
+            if self.applied { "True" } else { "False" },
+            self.current_row.bind(py).repr()?
+        ))
     }
 }
 
@@ -241,6 +474,45 @@ impl SinglePageIterator {
     }
 }
 
+/// Prefix of `PagingState.to_bytes()` output.
+const PAGING_STATE_MAGIC: &[u8] = b"SCPS";
+
+/// Version of the `PagingState.to_bytes()` format.
+const PAGING_STATE_FORMAT_VERSION: u8 = 1;
+
+/// Magic, format version and a byte of `PAGING_STATE_*` flags.
+const PAGING_STATE_HEADER_LEN: usize = PAGING_STATE_MAGIC.len() + 2;
+
+/// Flag set if raw paging state follows the header (and the query hash).
+const PAGING_STATE_HAS_RAW: u8 = 0b01;
+
+/// Flag set if the 4-byte query hash follows the header.
+const PAGING_STATE_HAS_QUERY_HASH: u8 = 0b10;
+
+/// CRC-32 (IEEE) checksum, guarding stored paging states against corruption.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Hash of the statement text a paging state was obtained for.
+///
+/// Stable across processes, so that a restored paging state can be checked
+/// against the statement it is resumed with.
+pub(crate) fn query_hash(contents: &str) -> u32 {
+    crc32(contents.as_bytes())
+}
+
 /// Represents paging state for paged queries.
 ///
 /// Used to continue a query from where the previous page ended.
@@ -248,6 +520,9 @@ impl SinglePageIterator {
 #[pyclass(name = "PagingState", frozen)]
 pub struct PyPagingState {
     pub(crate) inner: PagingState,
+    /// `query_hash` of the statement the state was obtained for,
+    /// `None` for the start state created by the user.
+    pub(crate) query_hash: Option<u32>,
 }
 
 #[pymethods]
@@ -257,44 +532,113 @@ impl PyPagingState {
     fn new() -> Self {
         PyPagingState {
             inner: PagingState::start(),
+            query_hash: None,
         }
     }
 
     /// Returns the inner representation of `PagingState` as bytes.
     ///
-    /// Use this to store paging state for a longer time, and later restore it
-    /// using `from_bytes()`. Returns `None` if this represents the start state.
+    /// These are the opaque bytes sent by the server. To store paging state
+    /// outside the process, use `to_bytes()` instead. Returns `None` if this
+    /// represents the start state.
     ///
     /// # Returns
     ///
     /// Raw paging state bytes, or `None` for the start state.
-    pub fn as_bytes<'py>(&self, py: Python<'py>) -> Option<Bound<'py, pyo3::types::PyBytes>> {
+    pub fn as_bytes<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyBytes>> {
         self.inner
             .as_bytes_slice()
-            .map(|arc_slice| pyo3::types::PyBytes::new(py, arc_slice))
+            .map(|arc_slice| PyBytes::new(py, arc_slice))
     }
 
-    /// Creates `PagingState` from raw bytes.
-    ///
-    /// Use this to restore paging state after longer time, having previously
-    /// stored it using `as_bytes()`.
-    ///
-    /// # Parameters
+    /// Serializes the paging state, including the start state, into a
+    /// self-describing, checksummed byte string.
     ///
-    /// raw_bytes : Raw paging state bytes previously obtained from `as_bytes()`.
+    /// The result can be stored outside the process (e.g. in a cookie or URL,
+    /// after encoding it as base64) and restored with `from_bytes()`.
+    /// It includes a hash of the statement the state was obtained for,
+    /// so that resuming it with another statement fails.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let raw = self.inner.as_bytes_slice();
+
+        let mut data = Vec::with_capacity(PAGING_STATE_HEADER_LEN + 8 + raw.map_or(0, |r| r.len()));
+        data.extend_from_slice(PAGING_STATE_MAGIC);
+        data.push(PAGING_STATE_FORMAT_VERSION);
+
+        let mut flags = 0;
+        if raw.is_some() {
+            flags |= PAGING_STATE_HAS_RAW;
+        }
+        if self.query_hash.is_some() {
+            flags |= PAGING_STATE_HAS_QUERY_HASH;
+        }
+        data.push(flags);
+        if let Some(query_hash) = self.query_hash {
+            data.extend_from_slice(&query_hash.to_be_bytes());
+        }
+        if let Some(raw) = raw {
+            data.extend_from_slice(raw);
+        }
+        data.extend_from_slice(&crc32(&data).to_be_bytes());
+
+        PyBytes::new(py, &data)
+    }
+
+    /// Restores `PagingState` from bytes produced by `to_bytes()`.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A new `PagingState` restored from the raw bytes.
+    /// Raises `ValueError` if the bytes were not produced by `to_bytes()`,
+    /// come from an incompatible format version or were corrupted.
     #[staticmethod]
-    pub fn from_bytes(raw_bytes: &[u8]) -> Self {
-        Self {
-            inner: PagingState::new_from_raw_bytes(raw_bytes),
+    pub fn from_bytes(data: &[u8]) -> Result<Self, DriverPagingStateError> {
+        if !data.starts_with(PAGING_STATE_MAGIC) {
+            return Err(DriverPagingStateError::not_a_paging_state());
+        }
+        let Some(&version) = data.get(PAGING_STATE_MAGIC.len()) else {
+            return Err(DriverPagingStateError::corrupted());
+        };
+        if version != PAGING_STATE_FORMAT_VERSION {
+            return Err(DriverPagingStateError::unsupported_version(version));
+        }
+        if data.len() < PAGING_STATE_HEADER_LEN + 4 {
+            return Err(DriverPagingStateError::corrupted());
         }
+
+        let (payload, checksum) = data.split_at(data.len() - 4);
+        if crc32(payload).to_be_bytes() != checksum {
+            return Err(DriverPagingStateError::corrupted());
+        }
+
+        let flags = payload[PAGING_STATE_HEADER_LEN - 1];
+        if flags & !(PAGING_STATE_HAS_RAW | PAGING_STATE_HAS_QUERY_HASH) != 0 {
+            return Err(DriverPagingStateError::corrupted());
+        }
+        let mut rest = &payload[PAGING_STATE_HEADER_LEN..];
+
+        let query_hash = if flags & PAGING_STATE_HAS_QUERY_HASH != 0 {
+            let Some((query_hash, raw)) = rest.split_first_chunk::<4>() else {
+                return Err(DriverPagingStateError::corrupted());
+            };
+            rest = raw;
+            Some(u32::from_be_bytes(*query_hash))
+        } else {
+            None
+        };
+
+        let inner = if flags & PAGING_STATE_HAS_RAW != 0 {
+            PagingState::new_from_raw_bytes(rest)
+        } else if rest.is_empty() {
+            PagingState::start()
+        } else {
+            return Err(DriverPagingStateError::corrupted());
+        };
+
+        Ok(Self { inner, query_hash })
     }
 
     fn __eq__(&self, other: &Self) -> bool {
-        self.inner == other.inner
+        self.inner == other.inner && self.query_hash == other.query_hash
     }
 }
 
@@ -347,6 +691,20 @@ impl AsyncRowsIterator {
     pub fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
         slf
     }
+
+    /// Returns the paging state of the page following the one being iterated,
+    /// or `None` once the last page was fetched, so also after the iteration is
+    /// complete.
+    ///
+    /// Passing it to `execute()` resumes from the next page, skipping the rows
+    /// of the current page not yielded yet.
+    fn paging_state(&self) -> PyResult<Option<PyPagingState>> {
+        let state = self.state.try_lock().map_err(|_| {
+            PyRuntimeError::new_err("Cannot read the paging state while a row is being fetched")
+        })?;
+
+        Ok(state.query_pager.paging_state())
+    }
 }
 
 /// Mutable state for async row iteration.
@@ -404,15 +762,37 @@ pub struct RowColumnCursor {
     // Cached Python strings for column names. Column names are identical
     // across all rows, so we create them once and reuse via clone_ref.
     column_names: Vec<Py<PyString>>,
+
+    // Per-column deserializers picked once from the metadata, so that
+    // cells of fixed-size native columns skip the generic type dispatch.
+    column_deserializers: Vec<ColumnDeserializer>,
+
+    // Representation switches taken from the row factory of the result.
+    options: DeserializationOptions,
+
+    // CQL types of the columns, as exposed on `Column` objects yielded to
+    // Python. Built lazily, as the Rust row factories do not need them.
+    column_types: Option<Vec<Py<PyCqlColumnType>>>,
+
+    // Column layout used by `TypedRowFactory`. Built lazily on the first
+    // typed row of the page, so the default dict path never pays for it.
+    typed_columns: Option<Arc<TypedRowColumns>>,
 }
 
+/// Name and raw CQL bytes (`None` for null) of a single column.
+type RawColumnBytes<'py> = (Py<PyString>, Option<Bound<'py, PyBytes>>);
+
 impl RowColumnCursor {
-    fn new(py: Python<'_>, query_result: Arc<QueryResult>) -> Self {
+    fn new(
+        py: Python<'_>,
+        query_result: Arc<QueryResult>,
+        options: DeserializationOptions,
+    ) -> Self {
         let cart = QueryResultCart(query_result);
 
         // Pre-create Python strings for column names — they are
         // identical for every row and can be reused via clone_ref.
-        let column_names: Vec<Py<PyString>> = {
+        let (column_names, column_deserializers): (Vec<Py<PyString>>, Vec<ColumnDeserializer>) = {
             let raw_rows_with_metadata = cart.deserialized_metadata_and_rows().expect(
                 "deserialized_metadata_and_rows can't be None after is_rows() returned true",
             );
@@ -420,8 +800,13 @@ impl RowColumnCursor {
                 .metadata()
                 .col_specs()
                 .iter()
-                .map(|spec| PyString::new(py, spec.name()).unbind())
-                .collect()
+                .map(|spec| {
+                    (
+                        PyString::new(py, spec.name()).unbind(),
+                        column_deserializer(spec.typ()),
+                    )
+                })
+                .unzip()
         };
 
         let yoked = Yoke::attach_to_cart(cart, |cart| {
@@ -445,18 +830,61 @@ impl RowColumnCursor {
         Self {
             yoked,
             column_names,
+            column_deserializers,
+            options,
+            column_types: None,
+            typed_columns: None,
         }
     }
 
+    fn typed_columns(&mut self, py: Python<'_>) -> Arc<TypedRowColumns> {
+        if let Some(typed_columns) = &self.typed_columns {
+            return Arc::clone(typed_columns);
+        }
+
+        let raw_rows_with_metadata = self
+            .yoked
+            .backing_cart()
+            .deserialized_metadata_and_rows()
+            .expect("deserialized_metadata_and_rows can't be None after is_rows() returned true");
+        let col_specs = raw_rows_with_metadata.metadata().col_specs();
+
+        let typed_columns = Arc::new(TypedRowColumns {
+            names: self
+                .column_names
+                .iter()
+                .map(|name| Py::clone_ref(name, py))
+                .collect(),
+            indices: col_specs
+                .iter()
+                .enumerate()
+                .map(|(index, spec)| (spec.name().into(), index))
+                .collect(),
+            kinds: col_specs
+                .iter()
+                .map(|spec| TypedColumnKind::of(spec.typ()))
+                .collect(),
+            cql_types: col_specs
+                .iter()
+                .map(|spec| cql_type(spec.typ()).into())
+                .collect(),
+        });
+
+        self.typed_columns = Some(Arc::clone(&typed_columns));
+        typed_columns
+    }
+
+    fn advance_column(&mut self) -> Result<(), DriverDeserializationError> {
+        self.yoked
+            .with_mut_return(|view: &mut Cursor<'_>| view.next_column())
+            .map_err(DriverDeserializationError::scylla_decode_failed)
+    }
+
     fn next_column(
         &mut self,
         py: Python<'_>,
     ) -> Option<Result<Column, DriverDeserializationError>> {
-        if let Err(err) = self
-            .yoked
-            .with_mut_return(|view: &mut Cursor<'_>| view.next_column())
-            .map_err(DriverDeserializationError::scylla_decode_failed)
-        {
+        if let Err(err) = self.advance_column() {
             return Some(Err(err));
         }
 
@@ -465,8 +893,8 @@ impl RowColumnCursor {
         // If `current_raw_column` is None, it means all columns of the current row have been exhausted.
         let (column_index, raw_col) = cursor.current_raw_column.as_ref()?;
 
-        let value = match PyDeserializedValue::deserialize_py(raw_col.spec.typ(), raw_col.slice, py)
-        {
+        let deserialize = self.column_deserializers[*column_index];
+        let value = match deserialize(py, raw_col.spec.typ(), raw_col.slice, self.options) {
             Ok(value) => value,
             Err(err) => {
                 return Some(Err(err
@@ -477,17 +905,75 @@ impl RowColumnCursor {
 
         let column_name = Py::clone_ref(&self.column_names[*column_index], py);
 
-        Some(Ok(Column { column_name, value }))
+        Some(Ok(Column {
+            column_name,
+            value,
+            column_type: None,
+        }))
+    }
+
+    /// Like `next_column`, but returns the column's name and CQL wire bytes
+    /// (`None` for null) instead of deserializing them.
+    fn next_raw_column<'py>(
+        &mut self,
+        py: Python<'py>,
+    ) -> Option<Result<RawColumnBytes<'py>, DriverDeserializationError>> {
+        if let Err(err) = self.advance_column() {
+            return Some(Err(err));
+        }
+
+        let (column_index, raw_col) = self.yoked.get().current_raw_column.as_ref()?;
+        let value = raw_col
+            .slice
+            .map(|slice| PyBytes::new(py, slice.as_slice()));
+
+        Some(Ok((
+            Py::clone_ref(&self.column_names[*column_index], py),
+            value,
+        )))
+    }
+
+    fn column_type(&mut self, py: Python<'_>, index: usize) -> PyResult<Py<PyCqlColumnType>> {
+        if self.column_types.is_none() {
+            let raw_rows_with_metadata = self
+                .yoked
+                .backing_cart()
+                .deserialized_metadata_and_rows()
+                .expect(
+                    "deserialized_metadata_and_rows can't be None after is_rows() returned true",
+                );
+            let column_types = raw_rows_with_metadata
+                .metadata()
+                .col_specs()
+                .iter()
+                .map(|spec| extract_column_type(py, spec.typ()))
+                .collect::<PyResult<_>>()?;
+            self.column_types = Some(column_types);
+        }
+
+        let column_types = self.column_types.as_ref().expect("initialized above");
+        Ok(Py::clone_ref(&column_types[index], py))
     }
 }
 
 #[pymethods]
 impl RowColumnCursor {
     pub fn __next__(&mut self, py: Python<'_>) -> PyResult<Column> {
-        match self.next_column(py) {
-            Some(res) => res.map_err(Into::into),
-            None => Err(PyErr::new::<PyStopIteration, _>("")),
-        }
+        let mut column = match self.next_column(py) {
+            Some(res) => res?,
+            None => return Err(PyErr::new::<PyStopIteration, _>("")),
+        };
+
+        let column_index = self
+            .yoked
+            .get()
+            .current_raw_column
+            .as_ref()
+            .map(|(column_index, _)| *column_index)
+            .expect("a column was just returned");
+        column.column_type = Some(self.column_type(py, column_index)?);
+
+        Ok(column)
     }
     pub fn __iter__(slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
         slf
@@ -504,6 +990,10 @@ pub struct Column {
     column_name: Py<PyString>,
     #[pyo3(get)]
     value: PyDeserializedValue,
+    // CQL type of the column, including element types of collections.
+    // Set on every column yielded to Python.
+    #[pyo3(get)]
+    column_type: Option<Py<PyCqlColumnType>>,
 }
 
 /// Factory responsible for constructing Python row objects.
@@ -533,10 +1023,42 @@ impl RowFactory {
         RowFactory {}
     }
 
+    /// Whether CQL `time` values are returned as an `int` of nanoseconds
+    /// since midnight instead of `datetime.time`.
+    ///
+    /// `datetime.time` has microsecond precision, so sub-microsecond digits
+    /// of CQL `time` are lost unless this is set to `True` by a subclass.
+    #[classattr]
+    fn time_as_nanoseconds() -> bool {
+        false
+    }
+
+    /// Whether CQL `date` values are returned as an `int` of days since the
+    /// Unix epoch instead of `datetime.date`.
+    ///
+    /// CQL `date` spans about 5.8 million years around the epoch, while
+    /// `datetime.date` covers only years 1-9999, so dates outside of that
+    /// range can be read only with this set to `True` by a subclass.
+    #[classattr]
+    fn date_as_days() -> bool {
+        false
+    }
+
+    /// Whether null CQL `ascii` and `text` values are returned as `""`
+    /// instead of `None`.
+    ///
+    /// CQL keeps empty and null text distinct; setting this to `True` in a
+    /// subclass makes the two indistinguishable in the returned rows.
+    #[classattr]
+    fn null_text_as_empty() -> bool {
+        false
+    }
+
     /// Build a Python object representing a single row.
     ///
     /// This method consumes all columns from the provided column iterator
     /// and returns a Python `dict` mapping column names to values.
+    /// Keys are inserted in the order of the columns in the result.
     ///
     /// Parameters
     /// ----------
@@ -578,6 +1100,293 @@ impl RowFactory {
         static DEFAULT_FACTORY: RowFactory = RowFactory {};
         &DEFAULT_FACTORY
     }
+
+    /// Reads the deserialization switches of a factory. They can be overridden
+    /// by subclasses either as class attributes or as instance attributes.
+    fn deserialization_options(
+        py: Python<'_>,
+        factory: Option<&Py<RowFactory>>,
+    ) -> PyResult<DeserializationOptions> {
+        let Some(factory) = factory else {
+            return Ok(DeserializationOptions::default());
+        };
+        let factory = factory.bind(py);
+
+        Ok(DeserializationOptions {
+            time_as_nanoseconds: factory
+                .getattr(intern!(py, "time_as_nanoseconds"))?
+                .is_truthy()?,
+            date_as_days: factory.getattr(intern!(py, "date_as_days"))?.is_truthy()?,
+            null_text_as_empty: factory
+                .getattr(intern!(py, "null_text_as_empty"))?
+                .is_truthy()?,
+        })
+    }
+}
+
+/// Row factory producing `TypedRow` objects.
+///
+/// Opt-in alternative to the default dict rows: each row keeps the CQL
+/// type of its columns, so typed getters can detect schema drift.
+#[pyclass(extends = RowFactory, frozen)]
+pub struct TypedRowFactory {}
+
+#[pymethods]
+impl TypedRowFactory {
+    #[new]
+    fn new() -> PyClassInitializer<Self> {
+        PyClassInitializer::from(RowFactory {}).add_subclass(TypedRowFactory {})
+    }
+
+    /// Build a `TypedRow` from all columns of the current row.
+    fn build(
+        &self,
+        py: Python<'_>,
+        column_iterator: &Bound<'_, RowColumnCursor>,
+    ) -> Result<TypedRow, DriverRowIterationError> {
+        let mut columns = column_iterator.borrow_mut();
+
+        let typed_columns = columns.typed_columns(py);
+        let mut values = Vec::with_capacity(typed_columns.names.len());
+        while let Some(next) = columns.next_column(py) {
+            let column = next.map_err(DriverRowIterationError::Deserialization)?;
+            let value = column
+                .value
+                .into_py_any(py)
+                .map_err(DriverRowIterationError::PythonError)?;
+            values.push(value);
+        }
+
+        Ok(TypedRow {
+            columns: typed_columns,
+            values,
+        })
+    }
+}
+
+/// Row factory producing `collections.OrderedDict` rows.
+///
+/// Default dict rows already keep the column order; this is for code relying
+/// on `OrderedDict` semantics, such as order-sensitive equality.
+#[pyclass(extends = RowFactory, frozen)]
+pub struct OrderedDictRowFactory {}
+
+#[pymethods]
+impl OrderedDictRowFactory {
+    #[new]
+    fn new() -> PyClassInitializer<Self> {
+        PyClassInitializer::from(RowFactory {}).add_subclass(OrderedDictRowFactory {})
+    }
+
+    /// Build an `OrderedDict` from all columns of the current row, in column order.
+    fn build<'py>(
+        &self,
+        py: Python<'py>,
+        column_iterator: &Bound<'py, RowColumnCursor>,
+    ) -> Result<Bound<'py, PyAny>, DriverRowIterationError> {
+        static ORDERED_DICT_CLS: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
+        let row = ORDERED_DICT_CLS
+            .import(py, "collections", "OrderedDict")
+            .and_then(|cls| cls.call0())
+            .map_err(DriverRowIterationError::PythonError)?;
+
+        let mut columns = column_iterator.borrow_mut();
+        while let Some(next) = columns.next_column(py) {
+            let column = next.map_err(DriverRowIterationError::Deserialization)?;
+            row.set_item(column.column_name, column.value)
+                .map_err(DriverRowIterationError::PythonError)?;
+        }
+
+        Ok(row)
+    }
+}
+
+/// Row factory producing `dict` rows of raw column bytes.
+///
+/// Values are left in their CQL wire representation, which makes this
+/// factory suitable for passthrough and for inspecting data that fails
+/// regular deserialization.
+#[pyclass(extends = RowFactory, frozen)]
+pub struct RawBytesRowFactory {}
+
+#[pymethods]
+impl RawBytesRowFactory {
+    #[new]
+    fn new() -> PyClassInitializer<Self> {
+        PyClassInitializer::from(RowFactory {}).add_subclass(RawBytesRowFactory {})
+    }
+
+    /// Build a `dict` mapping column names to raw `bytes`, or `None` for nulls.
+    fn build<'py>(
+        &self,
+        py: Python<'py>,
+        column_iterator: &Bound<'py, RowColumnCursor>,
+    ) -> Result<Py<PyDict>, DriverRowIterationError> {
+        let mut columns = column_iterator.borrow_mut();
+
+        let dict = PyDict::new(py);
+        while let Some(next) = columns.next_raw_column(py) {
+            let (column_name, value) = next.map_err(DriverRowIterationError::Deserialization)?;
+            dict.set_item(column_name, value)
+                .map_err(DriverRowIterationError::PythonError)?;
+        }
+
+        Ok(dict.into())
+    }
+}
+
+/// Category of a CQL column type, as checked by the typed getters of `TypedRow`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TypedColumnKind {
+    Int,
+    Float,
+    Str,
+    Bool,
+    Bytes,
+    Uuid,
+    Other,
+}
+
+impl TypedColumnKind {
+    fn of(typ: &ColumnType<'_>) -> Self {
+        match typ {
+            ColumnType::Native(native) => match native {
+                NativeType::TinyInt
+                | NativeType::SmallInt
+                | NativeType::Int
+                | NativeType::BigInt
+                | NativeType::Varint
+                | NativeType::Counter => Self::Int,
+                NativeType::Float | NativeType::Double => Self::Float,
+                NativeType::Ascii | NativeType::Text => Self::Str,
+                NativeType::Boolean => Self::Bool,
+                NativeType::Blob => Self::Bytes,
+                NativeType::Uuid | NativeType::Timeuuid => Self::Uuid,
+                _ => Self::Other,
+            },
+            _ => Self::Other,
+        }
+    }
+
+    fn python_name(self) -> &'static str {
+        match self {
+            Self::Int => "int",
+            Self::Float => "float",
+            Self::Str => "str",
+            Self::Bool => "bool",
+            Self::Bytes => "bytes",
+            Self::Uuid => "UUID",
+            Self::Other => "object",
+        }
+    }
+}
+
+/// Column layout of a result page, shared by all `TypedRow`s built from it.
+struct TypedRowColumns {
+    names: Vec<Py<PyString>>,
+    indices: HashMap<Box<str>, usize>,
+    kinds: Vec<TypedColumnKind>,
+    cql_types: Vec<Box<str>>,
+}
+
+/// A row with typed accessors, produced by `TypedRowFactory`.
+///
+/// Each `get_*` method checks that the CQL type of the column matches
+/// the requested Python type and raises `ColumnTypeMismatchError` otherwise.
+/// Null values are returned as `None` regardless of the getter.
+#[pyclass(frozen)]
+pub struct TypedRow {
+    columns: Arc<TypedRowColumns>,
+    values: Vec<Py<PyAny>>,
+}
+
+impl TypedRow {
+    fn index_of(&self, name: &str) -> Result<usize, DriverTypedRowError> {
+        self.columns
+            .indices
+            .get(name)
+            .copied()
+            .ok_or_else(|| DriverTypedRowError::unknown_column(name))
+    }
+
+    fn get_typed(
+        &self,
+        py: Python<'_>,
+        name: &str,
+        kind: TypedColumnKind,
+    ) -> Result<Py<PyAny>, DriverTypedRowError> {
+        let index = self.index_of(name)?;
+        if self.columns.kinds[index] != kind {
+            return Err(DriverTypedRowError::type_mismatch(
+                name,
+                kind.python_name(),
+                &*self.columns.cql_types[index],
+            ));
+        }
+
+        Ok(Py::clone_ref(&self.values[index], py))
+    }
+}
+
+#[pymethods]
+impl TypedRow {
+    /// Value of a column of any CQL integer type.
+    fn get_int(&self, py: Python<'_>, name: &str) -> Result<Py<PyAny>, DriverTypedRowError> {
+        self.get_typed(py, name, TypedColumnKind::Int)
+    }
+
+    /// Value of a `float` or `double` column.
+    fn get_float(&self, py: Python<'_>, name: &str) -> Result<Py<PyAny>, DriverTypedRowError> {
+        self.get_typed(py, name, TypedColumnKind::Float)
+    }
+
+    /// Value of a `text` or `ascii` column.
+    fn get_str(&self, py: Python<'_>, name: &str) -> Result<Py<PyAny>, DriverTypedRowError> {
+        self.get_typed(py, name, TypedColumnKind::Str)
+    }
+
+    /// Value of a `boolean` column.
+    fn get_bool(&self, py: Python<'_>, name: &str) -> Result<Py<PyAny>, DriverTypedRowError> {
+        self.get_typed(py, name, TypedColumnKind::Bool)
+    }
+
+    /// Value of a `blob` column.
+    fn get_bytes(&self, py: Python<'_>, name: &str) -> Result<Py<PyAny>, DriverTypedRowError> {
+        self.get_typed(py, name, TypedColumnKind::Bytes)
+    }
+
+    /// Value of a `uuid` or `timeuuid` column.
+    fn get_uuid(&self, py: Python<'_>, name: &str) -> Result<Py<PyAny>, DriverTypedRowError> {
+        self.get_typed(py, name, TypedColumnKind::Uuid)
+    }
+
+    /// Value of a column without any type check.
+    fn __getitem__(&self, py: Python<'_>, name: &str) -> Result<Py<PyAny>, DriverTypedRowError> {
+        let index = self.index_of(name)?;
+        Ok(Py::clone_ref(&self.values[index], py))
+    }
+
+    fn __len__(&self) -> usize {
+        self.values.len()
+    }
+
+    fn __contains__(&self, name: &str) -> bool {
+        self.columns.indices.contains_key(name)
+    }
+
+    /// Convert the row into a `dict` mapping column names to values.
+    fn as_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for (name, value) in self.columns.names.iter().zip(&self.values) {
+            dict.set_item(name, value)?;
+        }
+        Ok(dict)
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!("TypedRow({})", self.as_dict(py)?.repr()?))
+    }
 }
 
 /// Determines how to iterate over query results based on result type.
@@ -602,7 +1411,8 @@ impl RowsIteratorKind {
             return Ok(RowsIteratorKind::NonRows);
         }
 
-        let row_col_cursor = Py::new(py, RowColumnCursor::new(py, query_result))?;
+        let options = RowFactory::deserialization_options(py, factory.as_ref())?;
+        let row_col_cursor = Py::new(py, RowColumnCursor::new(py, query_result, options))?;
 
         Ok(RowsIteratorKind::Rows {
             row_col_cursor,
@@ -612,7 +1422,8 @@ impl RowsIteratorKind {
 
     fn update(&mut self, py: Python, query_result: Arc<QueryResult>) -> PyResult<()> {
         if let RowsIteratorKind::Rows { row_col_cursor, .. } = self {
-            *row_col_cursor = Py::new(py, RowColumnCursor::new(py, query_result))?;
+            let options = row_col_cursor.borrow(py).options;
+            *row_col_cursor = Py::new(py, RowColumnCursor::new(py, query_result, options))?;
         }
         Ok(())
     }
@@ -665,6 +1476,8 @@ pub(crate) enum Pager {
         session: PySession,
         query_request: ExecutableStatement,
         value_list: PyValueList,
+        /// Retries of each page, see the `retries` option of `Session.execute`.
+        retries: u32,
     },
 }
 
@@ -678,12 +1491,14 @@ impl Pager {
         session: PySession,
         query_request: ExecutableStatement,
         value_list: PyValueList,
+        retries: u32,
     ) -> Self {
         Pager::Paged {
             paging_response,
             session,
             query_request,
             value_list,
+            retries,
         }
     }
 
@@ -701,9 +1516,11 @@ impl Pager {
         match self {
             Pager::Paged {
                 paging_response: PagingStateResponse::HasMorePages { state },
+                query_request,
                 ..
             } => Some(PyPagingState {
                 inner: state.clone(),
+                query_hash: Some(query_request.query_hash()),
             }),
             Pager::Paged {
                 paging_response: PagingStateResponse::NoMorePages,
@@ -719,6 +1536,7 @@ impl Pager {
             session,
             query_request,
             value_list,
+            retries,
         } = self
         else {
             return None;
@@ -730,7 +1548,7 @@ impl Pager {
         };
 
         let result = session
-            .execute_single_page(state, query_request.clone(), value_list.clone())
+            .execute_single_page(state, query_request.clone(), value_list.clone(), *retries)
             .await;
 
         let (query_result, new_paging_response) = match result {
@@ -810,6 +1628,11 @@ pub(crate) fn results(_py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult
     module.add_class::<PyPagingState>()?;
     module.add_class::<RequestResult>()?;
     module.add_class::<AsyncRowsIterator>()?;
+    module.add_class::<LwtResult>()?;
+    module.add_class::<TypedRowFactory>()?;
+    module.add_class::<OrderedDictRowFactory>()?;
+    module.add_class::<RawBytesRowFactory>()?;
+    module.add_class::<TypedRow>()?;
 
     Ok(())
 }
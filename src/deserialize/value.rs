@@ -1,13 +1,21 @@
 use crate::deserialize::conversion::{CqlDurationWrapper, CqlVarintWrapper};
 use crate::errors::DriverDeserializationError;
+use crate::serialize::integer::{CqlBigInt, CqlCounter, CqlInt, CqlSmallInt, CqlTinyInt};
+use crate::serialize::value::{DATE_EPOCH_OFFSET, NANOSECONDS_PER_DAY, serialize_value};
+use crate::timeuuid::{datetime_from_timeuuid, max_timeuuid, min_timeuuid, timeuuid_from_time};
 use bigdecimal::BigDecimal;
+use bigdecimal::num_bigint::BigInt;
 use chrono::{DateTime, NaiveTime, Utc};
+use pyo3::exceptions::{PyOverflowError, PyValueError};
 use pyo3::prelude::{PyDictMethods, PyListMethods, PyModule, PyModuleMethods, PySetMethods};
 use pyo3::sync::PyOnceLock;
 use pyo3::types::{
     PyBool, PyBytes, PyDict, PyFloat, PyInt, PyList, PyNone, PySet, PyString, PyTuple,
 };
-use pyo3::{Bound, IntoPyObject, Py, PyAny, PyResult, Python, pyclass, pymethods, pymodule};
+use pyo3::{
+    Bound, IntoPyObject, IntoPyObjectExt, Py, PyAny, PyResult, Python, pyclass, pymethods,
+    pymodule, wrap_pyfunction,
+};
 use scylla::deserialize::value::FrameSliceWithMetadata;
 use scylla::deserialize::value::VectorIterator;
 use scylla::deserialize::value::{DeserializeValue, ListlikeIterator, MapIterator, UdtIterator};
@@ -16,12 +24,13 @@ use scylla_cql::frame::response::result::ColumnType;
 use scylla_cql::frame::response::result::ColumnType::Native;
 use scylla_cql::frame::response::result::{CollectionType, NativeType};
 use scylla_cql::value::{
-    Counter, CqlDate, CqlDecimalBorrowed, CqlDuration, CqlTime, CqlTimestamp, CqlTimeuuid,
-    CqlVarintBorrowed,
+    Counter, CqlDate, CqlDecimal, CqlDecimalBorrowed, CqlDuration, CqlTime, CqlTimestamp,
+    CqlTimeuuid, CqlValue, CqlVarint, CqlVarintBorrowed,
 };
 use std::convert::Infallible;
 use std::marker::PhantomData;
 use std::net::IpAddr;
+use uuid::Uuid;
 // NOTE: I intentionally do NOT use Scylla's `DeserializeValue` trait here.
 // The trait does not provide a `Python` argument, meaning that Python objects which
 // would have to be constructed inside `deserialize()` or deeper in recursion
@@ -41,9 +50,39 @@ pub(crate) trait PyDeserializeValue<'frame, 'metadata, 'py>: Sized {
         typ: &'metadata ColumnType<'metadata>,
         v: Option<FrameSlice<'frame>>,
         py: Python<'py>,
+        options: DeserializationOptions,
     ) -> Result<PyDeserializedValue, DriverDeserializationError>;
 }
 
+/// Switches changing the Python representation of some CQL types.
+///
+/// Read once per result from the row factory and applied to all values,
+/// including the ones nested in collections, tuples and UDTs, except for
+/// `null_text_as_empty`.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct DeserializationOptions {
+    /// Return CQL `time` as an `int` of nanoseconds since midnight instead of
+    /// `datetime.time`, which has only microsecond precision.
+    pub(crate) time_as_nanoseconds: bool,
+    /// Return CQL `date` as an `int` of days since the Unix epoch instead of
+    /// `datetime.date`, which cannot represent dates outside of years 1-9999.
+    pub(crate) date_as_days: bool,
+    /// Return null `ascii` and `text` column values as `""` instead of `None`.
+    /// Like `SessionBuilder.empty_string_as_null` on the writing side, applies
+    /// only to whole column values, not to fields of tuples and UDTs.
+    pub(crate) null_text_as_empty: bool,
+}
+
+impl DeserializationOptions {
+    /// Options for the fields of a tuple or UDT.
+    fn for_fields(self) -> Self {
+        Self {
+            null_text_as_empty: false,
+            ..self
+        }
+    }
+}
+
 impl PyDeserializedValue {
     fn new(value: Bound<'_, PyAny>) -> Self {
         Self {
@@ -90,8 +129,9 @@ impl<'frame, 'metadata, 'py> PyDeserializeValue<'frame, 'metadata, 'py> for PyDe
         typ: &'metadata ColumnType<'metadata>,
         v: Option<FrameSlice<'frame>>,
         py: Python<'py>,
+        options: DeserializationOptions,
     ) -> Result<Self, DriverDeserializationError> {
-        deser_cql_py_value(py, typ, v)
+        deser_cql_py_value(py, typ, v, options)
     }
 }
 
@@ -131,6 +171,151 @@ impl CqlEmpty {
     }
 }
 
+/// Exact CQL value, serialized as is without inspecting the Python type.
+///
+/// Each constructor produces a value of one CQL type. The value is type-checked
+/// against the column when bound, so e.g. a `smallint` is rejected for an `int` column.
+#[pyclass(name = "CqlValue", frozen, eq)]
+#[derive(PartialEq)]
+pub struct PyCqlValue {
+    pub(crate) inner: CqlValue,
+}
+
+impl PyCqlValue {
+    fn new(inner: CqlValue) -> Self {
+        Self { inner }
+    }
+}
+
+#[pymethods]
+impl PyCqlValue {
+    #[staticmethod]
+    fn tinyint(value: i8) -> Self {
+        Self::new(CqlValue::TinyInt(value))
+    }
+
+    #[staticmethod]
+    fn smallint(value: i16) -> Self {
+        Self::new(CqlValue::SmallInt(value))
+    }
+
+    #[staticmethod]
+    fn int(value: i32) -> Self {
+        Self::new(CqlValue::Int(value))
+    }
+
+    #[staticmethod]
+    fn bigint(value: i64) -> Self {
+        Self::new(CqlValue::BigInt(value))
+    }
+
+    #[staticmethod]
+    fn counter(value: i64) -> Self {
+        Self::new(CqlValue::Counter(Counter(value)))
+    }
+
+    #[staticmethod]
+    fn varint(value: BigInt) -> Self {
+        Self::new(CqlValue::Varint(CqlVarint::from(value)))
+    }
+
+    #[staticmethod]
+    fn float(value: f32) -> Self {
+        Self::new(CqlValue::Float(value))
+    }
+
+    #[staticmethod]
+    fn double(value: f64) -> Self {
+        Self::new(CqlValue::Double(value))
+    }
+
+    #[staticmethod]
+    fn decimal(value: BigDecimal) -> PyResult<Self> {
+        let decimal = CqlDecimal::try_from(value)
+            .map_err(|_| PyOverflowError::new_err("decimal scale does not fit in 32 bits"))?;
+        Ok(Self::new(CqlValue::Decimal(decimal)))
+    }
+
+    #[staticmethod]
+    fn boolean(value: bool) -> Self {
+        Self::new(CqlValue::Boolean(value))
+    }
+
+    #[staticmethod]
+    fn text(value: String) -> Self {
+        Self::new(CqlValue::Text(value))
+    }
+
+    #[staticmethod]
+    fn ascii(value: String) -> PyResult<Self> {
+        if let Some(index) = value.chars().position(|c| !c.is_ascii()) {
+            return Err(PyValueError::new_err(format!(
+                "ascii value contains a non-ASCII character at index {index}"
+            )));
+        }
+        Ok(Self::new(CqlValue::Ascii(value)))
+    }
+
+    #[staticmethod]
+    fn blob(value: Vec<u8>) -> Self {
+        Self::new(CqlValue::Blob(value))
+    }
+
+    #[staticmethod]
+    fn uuid(value: Uuid) -> Self {
+        Self::new(CqlValue::Uuid(value))
+    }
+
+    #[staticmethod]
+    fn timeuuid(value: Uuid) -> Self {
+        Self::new(CqlValue::Timeuuid(CqlTimeuuid::from(value)))
+    }
+
+    #[staticmethod]
+    fn inet(value: IpAddr) -> Self {
+        Self::new(CqlValue::Inet(value))
+    }
+
+    /// Days since the Unix epoch.
+    #[staticmethod]
+    fn date(days: i32) -> Self {
+        Self::new(CqlValue::Date(CqlDate(
+            (i64::from(days) + DATE_EPOCH_OFFSET) as u32,
+        )))
+    }
+
+    /// Nanoseconds since midnight.
+    #[staticmethod]
+    fn time(nanoseconds: i64) -> PyResult<Self> {
+        if !(0..NANOSECONDS_PER_DAY).contains(&nanoseconds) {
+            return Err(PyOverflowError::new_err(format!(
+                "time value must be between 0 and {}, got {nanoseconds}",
+                NANOSECONDS_PER_DAY - 1
+            )));
+        }
+        Ok(Self::new(CqlValue::Time(CqlTime(nanoseconds))))
+    }
+
+    /// Milliseconds since the Unix epoch.
+    #[staticmethod]
+    fn timestamp(milliseconds: i64) -> Self {
+        Self::new(CqlValue::Timestamp(CqlTimestamp(milliseconds)))
+    }
+
+    #[staticmethod]
+    fn duration(months: i32, days: i32, nanoseconds: i64) -> Self {
+        Self::new(CqlValue::Duration(CqlDuration {
+            months,
+            days,
+            nanoseconds,
+        }))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CqlValue({:?})", self.inner)
+    }
+}
+
 struct List<T> {
     phantom_data: PhantomData<T>,
 }
@@ -139,6 +324,7 @@ fn deserialize_sequence<'frame, 'metadata, 'py, T, FBuild>(
     typ: &'metadata ColumnType<'metadata>,
     v: FrameSlice<'frame>,
     py: Python<'py>,
+    options: DeserializationOptions,
     mut builder: FBuild,
 ) -> Result<(), DriverDeserializationError>
 where
@@ -158,6 +344,7 @@ where
             raw_elem_with_metadata.column_type,
             raw_elem_with_metadata.frame_slice,
             py,
+            options,
         )
         .map_err(|e| e.in_sequence_index(i))?;
 
@@ -177,6 +364,7 @@ where
         typ: &'metadata ColumnType<'metadata>,
         v: Option<FrameSlice<'frame>>,
         py: Python<'py>,
+        options: DeserializationOptions,
     ) -> Result<PyDeserializedValue, DriverDeserializationError> {
         let Some(v) = v else {
             return Ok(PyDeserializedValue::new(PyList::empty(py).into_any()));
@@ -184,7 +372,7 @@ where
 
         let list = PyList::empty(py);
 
-        deserialize_sequence::<T, _>(typ, v, py, |item| list.append(item))?;
+        deserialize_sequence::<T, _>(typ, v, py, options, |item| list.append(item))?;
 
         Ok(PyDeserializedValue::new(list.into_any()))
     }
@@ -204,6 +392,7 @@ where
         typ: &'metadata ColumnType<'metadata>,
         v: Option<FrameSlice<'frame>>,
         py: Python<'py>,
+        options: DeserializationOptions,
     ) -> Result<PyDeserializedValue, DriverDeserializationError> {
         let Some(v) = v else {
             return Ok(PyDeserializedValue::new(PyDict::new(py).into_any()));
@@ -226,6 +415,7 @@ where
                 raw_key_with_metadata.column_type,
                 raw_key_with_metadata.frame_slice,
                 py,
+                options,
             )
             .map_err(|e| e.in_map_index(i))?;
 
@@ -233,6 +423,7 @@ where
                 raw_value_with_metadata.column_type,
                 raw_value_with_metadata.frame_slice,
                 py,
+                options,
             )
             .map_err(|e| e.in_map_index(i))?;
 
@@ -257,6 +448,7 @@ where
         typ: &'metadata ColumnType<'metadata>,
         v: Option<FrameSlice<'frame>>,
         py: Python<'py>,
+        options: DeserializationOptions,
     ) -> Result<PyDeserializedValue, DriverDeserializationError> {
         let Some(v) = v else {
             return Ok(PyDeserializedValue::new(
@@ -268,7 +460,7 @@ where
 
         let set = PySet::empty(py).map_err(DriverDeserializationError::python_conversion_failed)?;
 
-        deserialize_sequence::<T, _>(typ, v, py, |item| set.add(item))?;
+        deserialize_sequence::<T, _>(typ, v, py, options, |item| set.add(item))?;
 
         Ok(PyDeserializedValue::new(set.into_any()))
     }
@@ -286,6 +478,7 @@ where
         typ: &'metadata ColumnType<'metadata>,
         v: Option<FrameSlice<'frame>>,
         py: Python<'py>,
+        options: DeserializationOptions,
     ) -> Result<PyDeserializedValue, DriverDeserializationError> {
         let Some(val) = v else {
             return Ok(PyDeserializedValue::none(py));
@@ -308,6 +501,7 @@ where
                 raw_value_with_metadata.column_type,
                 raw_value_with_metadata.frame_slice,
                 py,
+                options,
             )
             .map_err(|e| e.in_vector_index(i))?;
 
@@ -320,10 +514,64 @@ where
     }
 }
 
+/// Deserializer of a single column value, chosen once per result from its metadata.
+pub(crate) type ColumnDeserializer =
+    for<'py, 'metadata, 'frame> fn(
+        Python<'py>,
+        &'metadata ColumnType<'metadata>,
+        Option<FrameSlice<'frame>>,
+        DeserializationOptions,
+    ) -> Result<PyDeserializedValue, DriverDeserializationError>;
+
+/// Picks the deserializer for a column of the given type.
+///
+/// Fixed-size native types whose Python counterpart can be built straight from
+/// the Rust value get a specialized deserializer, which skips the per-cell
+/// `ColumnType` dispatch of `deser_cql_py_value`. All other types use the generic path.
+pub(crate) fn column_deserializer(typ: &ColumnType<'_>) -> ColumnDeserializer {
+    match typ {
+        Native(NativeType::TinyInt) => deser_native_fast::<i8>,
+        Native(NativeType::SmallInt) => deser_native_fast::<i16>,
+        Native(NativeType::Int) => deser_native_fast::<i32>,
+        Native(NativeType::BigInt) => deser_native_fast::<i64>,
+        Native(NativeType::Float) => deser_native_fast::<f32>,
+        Native(NativeType::Double) => deser_native_fast::<f64>,
+        Native(NativeType::Boolean) => deser_native_fast::<bool>,
+        _ => deser_cql_py_value,
+    }
+}
+
+fn deser_native_fast<'py, 'metadata, 'frame, T>(
+    py: Python<'py>,
+    typ: &'metadata ColumnType<'metadata>,
+    val: Option<FrameSlice<'frame>>,
+    _options: DeserializationOptions,
+) -> Result<PyDeserializedValue, DriverDeserializationError>
+where
+    T: for<'f, 'm> DeserializeValue<'f, 'm> + for<'p> IntoPyObject<'p>,
+{
+    let Some(v) = val else {
+        return Ok(PyDeserializedValue::none(py));
+    };
+
+    // Same as in `deser_cql_py_value`: empty value of a non-string type.
+    if v.as_slice().is_empty() {
+        return PyDeserializedValue::empty_value(py)
+            .map_err(DriverDeserializationError::python_conversion_failed);
+    }
+
+    T::deserialize(typ, Some(v))
+        .map_err(DriverDeserializationError::scylla_decode_failed)?
+        .into_bound_py_any(py)
+        .map(PyDeserializedValue::new)
+        .map_err(DriverDeserializationError::python_conversion_failed)
+}
+
 fn deser_cql_py_value<'py, 'metadata, 'frame>(
     py: Python<'py>,
     typ: &'metadata ColumnType<'metadata>,
     val: Option<FrameSlice<'frame>>,
+    options: DeserializationOptions,
 ) -> Result<PyDeserializedValue, DriverDeserializationError> {
     if let Some(v) = val
         && v.as_slice().is_empty()
@@ -342,6 +590,11 @@ fn deser_cql_py_value<'py, 'metadata, 'frame>(
     Ok(match typ {
         Native(native_type) => {
             let Some(v) = val else {
+                if options.null_text_as_empty
+                    && matches!(native_type, NativeType::Ascii | NativeType::Text)
+                {
+                    return Ok(PyDeserializedValue::new(PyString::new(py, "").into_any()));
+                }
                 return Ok(PyDeserializedValue::none(py));
             };
 
@@ -424,7 +677,12 @@ fn deser_cql_py_value<'py, 'metadata, 'frame>(
                             .map_err(DriverDeserializationError::scylla_decode_failed)?;
                         PyBool::new(py, v).to_owned().into_any()
                     }
-                    // CQL Date → Python datetime.date
+                    // CQL Date → Python datetime.date, or int days since the Unix epoch
+                    NativeType::Date if options.date_as_days => {
+                        let date = CqlDate::deserialize(typ, Some(v))
+                            .map_err(DriverDeserializationError::scylla_decode_failed)?;
+                        PyInt::new(py, i64::from(date.0) - DATE_EPOCH_OFFSET).into_any()
+                    }
                     NativeType::Date => {
                         let date: chrono::NaiveDate = CqlDate::deserialize(typ, Some(v))
                             .map_err(DriverDeserializationError::scylla_decode_failed)?
@@ -448,7 +706,12 @@ fn deser_cql_py_value<'py, 'metadata, 'frame>(
                             .map_err(DriverDeserializationError::python_conversion_failed)?
                             .into_any()
                     }
-                    // CQL Time → Python datetime.time
+                    // CQL Time → Python datetime.time, or int nanoseconds since midnight
+                    NativeType::Time if options.time_as_nanoseconds => {
+                        let time = CqlTime::deserialize(typ, Some(v))
+                            .map_err(DriverDeserializationError::scylla_decode_failed)?;
+                        PyInt::new(py, time.0).into_any()
+                    }
                     NativeType::Time => {
                         let time: NaiveTime = CqlTime::deserialize(typ, Some(v))
                             .map_err(DriverDeserializationError::scylla_decode_failed)?
@@ -509,15 +772,17 @@ fn deser_cql_py_value<'py, 'metadata, 'frame>(
         } => match col_typ {
             // CQL List → Python list
             CollectionType::List(_type_name) => {
-                List::<PyDeserializedValue>::deserialize_py(typ, val, py)?
+                List::<PyDeserializedValue>::deserialize_py(typ, val, py, options)?
             }
             // CQL Map → Python dict
             CollectionType::Map(_key_type, _value_type) => {
-                Map::<PyDeserializedValue, PyDeserializedValue>::deserialize_py(typ, val, py)?
+                Map::<PyDeserializedValue, PyDeserializedValue>::deserialize_py(
+                    typ, val, py, options,
+                )?
             }
             // CQL Set → Python set
             CollectionType::Set(_type_name) => {
-                Set::<PyDeserializedValue>::deserialize_py(typ, val, py)?
+                Set::<PyDeserializedValue>::deserialize_py(typ, val, py, options)?
             }
             _ => {
                 return Err(DriverDeserializationError::unsupported_type(format!(
@@ -543,8 +808,13 @@ fn deser_cql_py_value<'py, 'metadata, 'frame>(
                     .map_err(DriverDeserializationError::scylla_decode_failed)
                     .map_err(|e| e.in_udt_field(col_name.clone()))?;
 
-                let val = PyDeserializedValue::deserialize_py(col_type, v.flatten(), py)
-                    .map_err(|e| e.in_udt_field(col_name.clone()))?;
+                let val = PyDeserializedValue::deserialize_py(
+                    col_type,
+                    v.flatten(),
+                    py,
+                    options.for_fields(),
+                )
+                .map_err(|e| e.in_udt_field(col_name.clone()))?;
 
                 dict.set_item(col_name.clone(), val)
                     .map_err(DriverDeserializationError::python_conversion_failed)
@@ -554,7 +824,9 @@ fn deser_cql_py_value<'py, 'metadata, 'frame>(
             PyDeserializedValue::new(dict.into_any())
         }
         // CQL Vector → Python list
-        ColumnType::Vector { .. } => Vector::<PyDeserializedValue>::deserialize_py(typ, val, py)?,
+        ColumnType::Vector { .. } => {
+            Vector::<PyDeserializedValue>::deserialize_py(typ, val, py, options)?
+        }
         // CQL Tuple → Python tuple
         ColumnType::Tuple(type_names) => {
             let Some(mut v) = val else {
@@ -572,7 +844,9 @@ fn deser_cql_py_value<'py, 'metadata, 'frame>(
                         // DeserializationError → DriverDeserializationError
                         .map_err(DriverDeserializationError::scylla_decode_failed)
                         // Option<&[u8]> → PyDeserializedValue
-                        .and_then(|raw| PyDeserializedValue::deserialize_py(typ, raw, py))
+                        .and_then(|raw| {
+                            PyDeserializedValue::deserialize_py(typ, raw, py, options.for_fields())
+                        })
                         // Add context about which tuple index failed
                         .map_err(|e| e.in_tuple_index(i));
                     PyValueOrError::new(result)
@@ -593,6 +867,17 @@ fn deser_cql_py_value<'py, 'metadata, 'frame>(
 #[pymodule]
 pub(crate) fn value(_py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<CqlEmpty>()?;
+    module.add_class::<CqlCounter>()?;
+    module.add_class::<CqlTinyInt>()?;
+    module.add_class::<CqlSmallInt>()?;
+    module.add_class::<CqlInt>()?;
+    module.add_class::<CqlBigInt>()?;
+    module.add_class::<PyCqlValue>()?;
+    module.add_function(wrap_pyfunction!(timeuuid_from_time, module)?)?;
+    module.add_function(wrap_pyfunction!(min_timeuuid, module)?)?;
+    module.add_function(wrap_pyfunction!(max_timeuuid, module)?)?;
+    module.add_function(wrap_pyfunction!(datetime_from_timeuuid, module)?)?;
+    module.add_function(wrap_pyfunction!(serialize_value, module)?)?;
 
     Ok(())
 }
@@ -1,14 +1,14 @@
-use crate::errors::DriverSessionConfigError;
+use crate::errors::{DriverConsistencyError, DriverSessionConfigError};
 use crate::session_builder::PyDuration;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyString;
+use pyo3::types::{PyString, PyType};
 use scylla::client::{PoolSize, SelfIdentity, WriteCoalescingDelay};
 use scylla::statement::{Consistency, SerialConsistency};
 use scylla_cql::frame::Compression;
 use std::num::{NonZeroU64, NonZeroUsize};
 
-#[pyclass(name = "Consistency", eq, eq_int, frozen, from_py_object)]
+#[pyclass(name = "Consistency", eq, eq_int, frozen)]
 #[derive(Clone, Copy, PartialEq)]
 pub(crate) enum PyConsistency {
     Any,
@@ -60,6 +60,67 @@ impl From<Consistency> for PyConsistency {
     }
 }
 
+impl PyConsistency {
+    const NAMES: [(&'static str, PyConsistency); 11] = [
+        ("ANY", PyConsistency::Any),
+        ("ONE", PyConsistency::One),
+        ("TWO", PyConsistency::Two),
+        ("THREE", PyConsistency::Three),
+        ("QUORUM", PyConsistency::Quorum),
+        ("ALL", PyConsistency::All),
+        ("LOCAL_QUORUM", PyConsistency::LocalQuorum),
+        ("EACH_QUORUM", PyConsistency::EachQuorum),
+        ("LOCAL_ONE", PyConsistency::LocalOne),
+        ("SERIAL", PyConsistency::Serial),
+        ("LOCAL_SERIAL", PyConsistency::LocalSerial),
+    ];
+
+    /// Looks up a consistency by name, ignoring case and underscores,
+    /// so `"LOCAL_QUORUM"`, `"local_quorum"` and `"LocalQuorum"` are all accepted.
+    fn from_name(name: &str) -> Result<Self, DriverConsistencyError> {
+        let normalized = |s: &str| {
+            s.chars()
+                .filter(|c| *c != '_')
+                .map(|c| c.to_ascii_uppercase())
+                .collect::<String>()
+        };
+        let wanted = normalized(name);
+
+        Self::NAMES
+            .iter()
+            .find(|(candidate, _)| normalized(candidate) == wanted)
+            .map(|(_, consistency)| *consistency)
+            .ok_or_else(|| DriverConsistencyError::unknown_name(name))
+    }
+}
+
+#[pymethods]
+impl PyConsistency {
+    #[classmethod]
+    fn from_string(_cls: &Bound<'_, PyType>, name: &str) -> Result<Self, DriverConsistencyError> {
+        Self::from_name(name)
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyConsistency {
+    type Error = DriverConsistencyError;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        if let Ok(consistency) = obj.cast::<PyConsistency>() {
+            return Ok(*consistency.get());
+        }
+
+        if let Ok(name) = obj.cast::<PyString>() {
+            let name = name
+                .to_str()
+                .map_err(|_| DriverConsistencyError::invalid_type(&obj))?;
+            return Self::from_name(name);
+        }
+
+        Err(DriverConsistencyError::invalid_type(&obj))
+    }
+}
+
 #[pyclass(name = "SerialConsistency", eq, eq_int, frozen, from_py_object)]
 #[derive(Clone, Copy, PartialEq)]
 pub(crate) enum PySerialConsistency {
@@ -110,6 +171,15 @@ impl From<Compression> for PyCompression {
     }
 }
 
+/// What a session does when an executed statement contains `ALLOW FILTERING`.
+#[pyclass(eq, eq_int, frozen, from_py_object, name = "AllowFilteringPolicy")]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum PyAllowFilteringPolicy {
+    Off,
+    Warn,
+    Error,
+}
+
 #[pyclass(name = "PoolSize", from_py_object, frozen)]
 #[derive(Clone, Copy, Debug)]
 pub struct PyPoolSize {
@@ -328,6 +398,7 @@ pub(crate) fn enums(_py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<(
     module.add_class::<PyConsistency>()?;
     module.add_class::<PySerialConsistency>()?;
     module.add_class::<PyCompression>()?;
+    module.add_class::<PyAllowFilteringPolicy>()?;
     module.add_class::<PyPoolSize>()?;
     module.add_class::<PyWriteCoalescingDelay>()?;
     module.add_class::<PySelfIdentity>()?;
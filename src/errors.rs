@@ -1,14 +1,18 @@
 // src/errors.rs
 use std::error::Error;
 use std::fmt;
+use std::time::Duration;
 
 use pyo3::PyErr;
 use pyo3::create_exception;
-use pyo3::exceptions::PyException;
+use pyo3::exceptions::{PyException, PyKeyError, PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::{PyModule, PyNone};
 use scylla::errors::ClusterStateTokenError as RustClusterStateTokenError;
 use scylla::errors::UseKeyspaceError as RustUseKeyspaceError;
+use scylla::errors::{DbError, ExecutionError, RequestAttemptError};
+
+use crate::enums::PyConsistency;
 
 /* Python exception classes */
 
@@ -16,6 +20,10 @@ create_exception!(errors, ScyllaError, PyException);
 
 create_exception!(errors, RowIterationError, ScyllaError);
 
+create_exception!(errors, LwtResultError, ScyllaError);
+
+create_exception!(errors, ColumnTypeMismatchError, ScyllaError);
+
 create_exception!(errors, DeserializationError, ScyllaError);
 create_exception!(
     errors,
@@ -32,10 +40,14 @@ create_exception!(errors, SessionConfigError, ScyllaError);
 create_exception!(errors, StatementConversionError, ScyllaError);
 
 create_exception!(errors, ExecuteError, ScyllaError);
+create_exception!(errors, WriteTimeoutError, ExecuteError);
+create_exception!(errors, InvalidQueryError, ExecuteError);
 
 create_exception!(errors, PrepareError, ScyllaError);
+create_exception!(errors, InvalidStatementError, PrepareError);
 
 create_exception!(errors, SchemaAgreementError, ScyllaError);
+create_exception!(errors, TracingError, ScyllaError);
 create_exception!(errors, StatementConfigError, ScyllaError);
 
 create_exception!(errors, BatchError, ScyllaError);
@@ -74,6 +86,25 @@ create_exception!(errors, RuntimeTaskJoinFailedError, UseKeyspaceError);
 // For errors originating from our own Rust code, we create a custom Python exception with a descriptive message,
 // and we can include any relevant information in the message or as attributes.
 
+/// Message of errors raised when a blocking API is called while an event loop is running.
+const BLOCKING_CALL_INSIDE_EVENT_LOOP: &str = "Blocking API called from a thread with a running asyncio event loop; use the async variant instead";
+
+/// Message of errors raised when a coroutine is awaited without a running asyncio event loop.
+const NO_RUNNING_EVENT_LOOP: &str = "Coroutine awaited without a running asyncio event loop; the driver supports asyncio-compatible event loops only, use the *_blocking variants from synchronous code";
+
+/// Message of errors raised when a request is made on a session after `shutdown`.
+const SESSION_SHUT_DOWN: &str = "Session has been shut down";
+
+/// Returned when a request is made on a session after `shutdown`.
+/// Converted into the error type of the request via the `From` trait.
+#[derive(Debug)]
+pub(crate) struct SessionShutDown;
+
+/// Returned when a coroutine is driven without a running asyncio event loop.
+/// Converted into the error type of the request via the `From` trait.
+#[derive(Debug)]
+pub(crate) struct NoRunningEventLoop;
+
 /* Row iteration errors */
 
 #[derive(Debug)]
@@ -119,6 +150,233 @@ impl From<DriverRowIterationError> for PyErr {
     }
 }
 
+/* LWT result errors */
+
+/// Errors that can occur while decoding the result of a conditional (LWT) statement.
+#[derive(Debug)]
+#[must_use]
+pub enum DriverLwtResultError {
+    /// The result has no rows or its first row lacks a boolean `[applied]` column.
+    NotAnLwtResult,
+    /// Building the first row of the result failed.
+    RowIteration(DriverRowIterationError),
+}
+
+impl DriverLwtResultError {
+    /* Constructors */
+
+    pub fn not_an_lwt_result() -> Self {
+        Self::NotAnLwtResult
+    }
+
+    pub fn python_error(err: PyErr) -> Self {
+        Self::RowIteration(DriverRowIterationError::PythonError(err))
+    }
+}
+
+impl From<DriverLwtResultError> for PyErr {
+    fn from(e: DriverLwtResultError) -> PyErr {
+        match e {
+            DriverLwtResultError::NotAnLwtResult => LwtResultError::new_err(
+                "Result is not a result of a conditional statement: no boolean [applied] column",
+            ),
+            DriverLwtResultError::RowIteration(e) => e.into(),
+        }
+    }
+}
+
+/* Typed row errors */
+
+/// Errors raised by typed getters of `TypedRow`.
+#[derive(Debug)]
+#[must_use]
+pub enum DriverTypedRowError {
+    /// The row has no column with the requested name.
+    UnknownColumn { name: Box<str> },
+    /// The CQL type of the column does not match the requested getter.
+    TypeMismatch {
+        name: Box<str>,
+        expected: &'static str,
+        cql_type: Box<str>,
+    },
+}
+
+impl DriverTypedRowError {
+    /* Constructors */
+
+    pub fn unknown_column(name: impl Into<Box<str>>) -> Self {
+        Self::UnknownColumn { name: name.into() }
+    }
+
+    pub fn type_mismatch(
+        name: impl Into<Box<str>>,
+        expected: &'static str,
+        cql_type: impl Into<Box<str>>,
+    ) -> Self {
+        Self::TypeMismatch {
+            name: name.into(),
+            expected,
+            cql_type: cql_type.into(),
+        }
+    }
+}
+
+impl From<DriverTypedRowError> for PyErr {
+    fn from(e: DriverTypedRowError) -> PyErr {
+        match e {
+            DriverTypedRowError::UnknownColumn { name } => PyKeyError::new_err(name.into_string()),
+            DriverTypedRowError::TypeMismatch {
+                name,
+                expected,
+                cql_type,
+            } => ColumnTypeMismatchError::new_err(format!(
+                "Column '{name}' has CQL type {cql_type}, which cannot be read as {expected}"
+            )),
+        }
+    }
+}
+
+/* Timeuuid errors */
+
+/// Errors raised by the `timeuuid` helper functions.
+#[derive(Debug)]
+#[must_use]
+pub enum DriverTimeuuidError {
+    /// The timestamp is neither a `datetime` nor a number of seconds.
+    InvalidTimestamp { type_name: String },
+    /// The timestamp cannot be represented in a version 1 UUID.
+    TimestampOutOfRange,
+    /// The UUID is not a version 1 (time-based) UUID.
+    NotATimeuuid { version: usize },
+}
+
+impl DriverTimeuuidError {
+    /* Constructors */
+
+    pub fn invalid_timestamp(value: &Bound<'_, PyAny>) -> Self {
+        let type_name = value
+            .get_type()
+            .name()
+            .map(|name| name.to_string())
+            .unwrap_or_else(|_| "<unknown>".to_string());
+        Self::InvalidTimestamp { type_name }
+    }
+
+    pub fn timestamp_out_of_range() -> Self {
+        Self::TimestampOutOfRange
+    }
+
+    pub fn not_a_timeuuid(version: usize) -> Self {
+        Self::NotATimeuuid { version }
+    }
+}
+
+impl From<DriverTimeuuidError> for PyErr {
+    fn from(e: DriverTimeuuidError) -> PyErr {
+        match e {
+            DriverTimeuuidError::InvalidTimestamp { type_name } => PyTypeError::new_err(format!(
+                "Expected a datetime or a number of seconds since the Unix epoch, got {type_name}"
+            )),
+            DriverTimeuuidError::TimestampOutOfRange => {
+                PyValueError::new_err("Timestamp is out of the range of a timeuuid")
+            }
+            DriverTimeuuidError::NotATimeuuid { version } => PyValueError::new_err(format!(
+                "Expected a version 1 UUID (timeuuid), got version {version}"
+            )),
+        }
+    }
+}
+
+/* Consistency errors */
+
+/// Errors raised when converting a Python object into a `Consistency`.
+#[derive(Debug)]
+#[must_use]
+pub enum DriverConsistencyError {
+    /// The string does not name any consistency level.
+    UnknownName { name: String },
+    /// The value is neither a `Consistency` nor a `str`.
+    InvalidType { type_name: String },
+}
+
+impl DriverConsistencyError {
+    /* Constructors */
+
+    pub fn unknown_name(name: &str) -> Self {
+        Self::UnknownName {
+            name: name.to_string(),
+        }
+    }
+
+    pub fn invalid_type(value: &Bound<'_, PyAny>) -> Self {
+        let type_name = value
+            .get_type()
+            .name()
+            .map(|name| name.to_string())
+            .unwrap_or_else(|_| "<unknown>".to_string());
+        Self::InvalidType { type_name }
+    }
+}
+
+impl From<DriverConsistencyError> for PyErr {
+    fn from(e: DriverConsistencyError) -> PyErr {
+        match e {
+            DriverConsistencyError::UnknownName { name } => {
+                PyValueError::new_err(format!("Unknown consistency level: {name:?}"))
+            }
+            DriverConsistencyError::InvalidType { type_name } => PyTypeError::new_err(format!(
+                "Expected a Consistency or a consistency name, got {type_name}"
+            )),
+        }
+    }
+}
+
+/* Paging state errors */
+
+/// Errors raised when restoring a `PagingState` from `PagingState.to_bytes()` output.
+#[derive(Debug)]
+#[must_use]
+pub enum DriverPagingStateError {
+    /// The bytes were not produced by `PagingState.to_bytes()`.
+    NotAPagingState,
+    /// The bytes were produced by an incompatible version of the driver.
+    UnsupportedVersion { version: u8 },
+    /// The bytes were truncated or modified after being produced.
+    Corrupted,
+}
+
+impl DriverPagingStateError {
+    /* Constructors */
+
+    pub fn not_a_paging_state() -> Self {
+        Self::NotAPagingState
+    }
+
+    pub fn unsupported_version(version: u8) -> Self {
+        Self::UnsupportedVersion { version }
+    }
+
+    pub fn corrupted() -> Self {
+        Self::Corrupted
+    }
+}
+
+impl From<DriverPagingStateError> for PyErr {
+    fn from(e: DriverPagingStateError) -> PyErr {
+        match e {
+            DriverPagingStateError::NotAPagingState => {
+                PyValueError::new_err("Bytes were not produced by PagingState.to_bytes()")
+            }
+            DriverPagingStateError::UnsupportedVersion { version } => {
+                PyValueError::new_err(format!("Unsupported paging state format version {version}"))
+            }
+            DriverPagingStateError::Corrupted => PyValueError::new_err(
+                "Paging state bytes are corrupted (checksum mismatch or truncated data)",
+            ),
+        }
+    }
+}
+
 /* Deserialization errors */
 
 /// Errors that can occur during deserialization of CQL values into Python objects.
@@ -429,12 +687,22 @@ pub enum DriverSessionConnectionError {
     },
     /// The Rust driver failed to establish a new session.
     NewSessionError {
+        contact_points: Vec<String>,
         source: Box<scylla::errors::NewSessionError>,
     },
+    /// The session was not established within the configured connect deadline.
+    ConnectDeadlineExceeded {
+        contact_points: Vec<String>,
+        deadline: Duration,
+    },
 
     PythonConversionError {
         source: PyErr,
     },
+    /// A blocking connect was requested while an asyncio event loop is running.
+    BlockingCallInsideEventLoop,
+    /// A coroutine was awaited outside of a running asyncio event loop.
+    NoRunningEventLoop,
 }
 
 impl DriverSessionConnectionError {
@@ -444,15 +712,30 @@ impl DriverSessionConnectionError {
         Self::RuntimeTaskJoinFailed { message }
     }
 
-    pub fn new_session_error(source: scylla::errors::NewSessionError) -> Self {
+    pub fn new_session_error(
+        source: scylla::errors::NewSessionError,
+        contact_points: Vec<String>,
+    ) -> Self {
         Self::NewSessionError {
+            contact_points,
             source: Box::new(source),
         }
     }
 
+    pub fn connect_deadline_exceeded(contact_points: Vec<String>, deadline: Duration) -> Self {
+        Self::ConnectDeadlineExceeded {
+            contact_points,
+            deadline,
+        }
+    }
+
     pub(crate) fn python_conversion_error(source: PyErr) -> Self {
         Self::PythonConversionError { source }
     }
+
+    pub fn blocking_call_inside_event_loop() -> Self {
+        Self::BlockingCallInsideEventLoop
+    }
 }
 
 impl From<DriverSessionConnectionError> for PyErr {
@@ -464,15 +747,42 @@ impl From<DriverSessionConnectionError> for PyErr {
                 ))
             }
 
-            DriverSessionConnectionError::NewSessionError { source } => {
-                SessionConnectionError::new_err(format!("failed to establish session: {source}"))
-            }
+            DriverSessionConnectionError::NewSessionError {
+                contact_points,
+                source,
+            } => SessionConnectionError::new_err(format!(
+                "failed to establish session with contact points [{}]: {source}",
+                contact_points.join(", ")
+            )),
+
+            DriverSessionConnectionError::ConnectDeadlineExceeded {
+                contact_points,
+                deadline,
+            } => SessionConnectionError::new_err(format!(
+                "failed to establish session with contact points [{}] within {deadline:?}: \
+                 resolving them, connecting or fetching cluster metadata did not complete in time",
+                contact_points.join(", ")
+            )),
 
             DriverSessionConnectionError::PythonConversionError { source } => source,
+
+            DriverSessionConnectionError::BlockingCallInsideEventLoop => {
+                SessionConnectionError::new_err(BLOCKING_CALL_INSIDE_EVENT_LOOP)
+            }
+
+            DriverSessionConnectionError::NoRunningEventLoop => {
+                SessionConnectionError::new_err(NO_RUNNING_EVENT_LOOP)
+            }
         }
     }
 }
 
+impl From<NoRunningEventLoop> for DriverSessionConnectionError {
+    fn from(_: NoRunningEventLoop) -> Self {
+        Self::NoRunningEventLoop
+    }
+}
+
 // Allow converting a tokio::task::JoinError into SessionConnectionError
 // so that callers that spawn tasks can map JoinError -> SessionConnectionError via the `From` trait.
 impl From<tokio::task::JoinError> for DriverSessionConnectionError {
@@ -503,6 +813,11 @@ pub enum DriverSessionConfigError {
 
     InvalidPortRange,
 
+    /// The local IP address is neither an `ipaddress` object nor a parsable string.
+    InvalidLocalIpAddress {
+        source: Box<PyErr>,
+    },
+
     InvalidDuration {
         type_name: String,
     },
@@ -535,6 +850,12 @@ impl DriverSessionConfigError {
         }
     }
 
+    pub fn invalid_local_ip_address(source: PyErr) -> Self {
+        Self::InvalidLocalIpAddress {
+            source: Box::new(source),
+        }
+    }
+
     pub fn invalid_duration(obj: Borrowed<PyAny>) -> Self {
         let type_name = obj
             .get_type()
@@ -594,6 +915,11 @@ impl From<DriverSessionConfigError> for PyErr {
                 build_session_config_pyerr(py, message, None, None)
             }
 
+            DriverSessionConfigError::InvalidLocalIpAddress { source } => {
+                let message = "Invalid local IP address: expected an IPv4Address, an IPv6Address or a string representation of one";
+                build_session_config_pyerr(py, message, Some(*source), None)
+            }
+
             DriverSessionConfigError::InvalidDuration { type_name } => {
                 let message = format!(
                     "Expected a datetime.timedelta or a non-negative finite float (seconds), got: {type_name}"
@@ -661,16 +987,43 @@ impl From<DriverStatementConversionError> for PyErr {
 pub enum DriverExecuteError {
     /// paging_state parameter in session.execute must be None.
     PagingStateMustBeNoneForUnpagedExecution,
+    /// The paging state was obtained for a different statement than the one executed.
+    PagingStateOfOtherStatement,
+    /// max_rows parameter in session.execute must be None for paged execution.
+    MaxRowsMustBeNoneForPagedExecution,
+    /// An unpaged query returned more rows than the configured max_rows limit.
+    RowLimitExceeded { limit: usize, rows: usize },
     /// The Rust driver failed while executing a query.
     RustDriverExecutionError {
         source: Box<scylla::errors::ExecutionError>,
     },
+    /// `retries` was requested for a statement not marked as idempotent.
+    RetriesRequireIdempotentStatement,
+    /// The statement contains `ALLOW FILTERING` and the session's policy rejects it.
+    AllowFilteringRejected { query: String },
+    /// `execute_by_id` got an id of a statement this session did not prepare, and no query.
+    UnknownPreparedStatementId { id: Vec<u8> },
+    /// Values were given as a mapping for a statement mixing positional and named markers.
+    MappingForMixedBindMarkers,
+    /// The statement kept failing with a timeout or `Unavailable` error until retries ran out.
+    RetriesExhausted {
+        attempts: u32,
+        source: Box<scylla::errors::ExecutionError>,
+    },
+    /// A query to the system tables returned a result of an unexpected shape.
+    UnexpectedSystemTableResult { message: String },
     /// Serialization of values failed before execution.
     SerializationFailed {
         source: scylla::serialize::SerializationError,
     },
     /// The Tokio runtime task responsible for executing the query failed to join.
     RuntimeTaskJoinFailed { message: Box<str> },
+    /// A blocking execution was requested while an asyncio event loop is running.
+    BlockingCallInsideEventLoop,
+    /// A coroutine was awaited outside of a running asyncio event loop.
+    NoRunningEventLoop,
+    /// The session was shut down before the request was made.
+    SessionShutDown,
 }
 
 impl DriverExecuteError {
@@ -680,12 +1033,49 @@ impl DriverExecuteError {
         Self::PagingStateMustBeNoneForUnpagedExecution
     }
 
+    pub fn paging_state_of_other_statement() -> Self {
+        Self::PagingStateOfOtherStatement
+    }
+
+    pub fn max_rows_must_be_none_for_paged_execution() -> Self {
+        Self::MaxRowsMustBeNoneForPagedExecution
+    }
+
+    pub fn row_limit_exceeded(limit: usize, rows: usize) -> Self {
+        Self::RowLimitExceeded { limit, rows }
+    }
+
     pub fn rust_driver_execution_error(source: scylla::errors::ExecutionError) -> Self {
         Self::RustDriverExecutionError {
             source: Box::new(source),
         }
     }
 
+    pub fn retries_require_idempotent_statement() -> Self {
+        Self::RetriesRequireIdempotentStatement
+    }
+
+    pub fn allow_filtering_rejected(query: impl Into<String>) -> Self {
+        Self::AllowFilteringRejected {
+            query: query.into(),
+        }
+    }
+
+    pub fn mapping_for_mixed_bind_markers() -> Self {
+        Self::MappingForMixedBindMarkers
+    }
+
+    pub fn unknown_prepared_statement_id(id: impl Into<Vec<u8>>) -> Self {
+        Self::UnknownPreparedStatementId { id: id.into() }
+    }
+
+    pub fn retries_exhausted(attempts: u32, source: scylla::errors::ExecutionError) -> Self {
+        Self::RetriesExhausted {
+            attempts,
+            source: Box::new(source),
+        }
+    }
+
     pub fn runtime_task_join_failed(err: tokio::task::JoinError) -> Self {
         Self::RuntimeTaskJoinFailed {
             message: err.to_string().into_boxed_str(),
@@ -695,6 +1085,109 @@ impl DriverExecuteError {
     pub fn serialization_failed(source: scylla::serialize::SerializationError) -> Self {
         Self::SerializationFailed { source }
     }
+
+    pub fn unexpected_system_table_result(err: impl std::fmt::Display) -> Self {
+        Self::UnexpectedSystemTableResult {
+            message: err.to_string(),
+        }
+    }
+
+    pub fn blocking_call_inside_event_loop() -> Self {
+        Self::BlockingCallInsideEventLoop
+    }
+}
+
+/// Builds an `ExecuteError`, or one of its subclasses carrying the details
+/// reported by the server if the last attempt failed with a write timeout
+/// or an invalid request. An invalid request may also be reported by the
+/// implicit prepare of an unprepared statement executed with values.
+fn build_execution_pyerr(message: String, source: &ExecutionError) -> PyErr {
+    let (db_error, reason) = match source {
+        ExecutionError::LastAttemptError(RequestAttemptError::DbError(db_error, reason)) => {
+            (db_error, reason)
+        }
+        ExecutionError::PrepareError(prepare_error) => {
+            return match invalid_prepare_message(prepare_error) {
+                Some(server_message) => invalid_query_pyerr(message, server_message),
+                None => ExecuteError::new_err(message),
+            };
+        }
+        _ => return ExecuteError::new_err(message),
+    };
+
+    match db_error {
+        DbError::WriteTimeout {
+            consistency,
+            received,
+            required,
+            write_type,
+        } => with_attributes(WriteTimeoutError::new_err(message), |inst| {
+            inst.setattr("write_type", write_type.as_str())?;
+            inst.setattr("consistency", PyConsistency::from(*consistency))?;
+            inst.setattr("received", *received)?;
+            inst.setattr("required", *required)
+        }),
+
+        DbError::Invalid => invalid_query_pyerr(message, reason),
+
+        _ => ExecuteError::new_err(message),
+    }
+}
+
+/// Builds an `InvalidQueryError` whose reason is classified from the server's message.
+fn invalid_query_pyerr(message: String, server_message: &str) -> PyErr {
+    with_attributes(InvalidQueryError::new_err(message), |inst| {
+        inst.setattr("reason", invalid_query_reason(server_message))
+    })
+}
+
+/// Builds a `PrepareError`, or `InvalidStatementError` carrying the reason if
+/// the server rejected the statement as invalid.
+fn build_prepare_pyerr(message: String, source: &scylla::errors::PrepareError) -> PyErr {
+    match invalid_prepare_message(source) {
+        Some(server_message) => with_attributes(InvalidStatementError::new_err(message), |inst| {
+            inst.setattr("reason", invalid_query_reason(server_message))
+        }),
+        None => PrepareError::new_err(message),
+    }
+}
+
+/// Returns the server's message if preparing failed with an `Invalid` error.
+fn invalid_prepare_message(source: &scylla::errors::PrepareError) -> Option<&str> {
+    match source {
+        scylla::errors::PrepareError::AllAttemptsFailed {
+            first_attempt: RequestAttemptError::DbError(DbError::Invalid, reason),
+        } => Some(reason.as_str()),
+        _ => None,
+    }
+}
+
+/// Sets attributes on the exception of `err`. If that fails, the failure is
+/// returned instead, rather than an exception missing its attributes.
+fn with_attributes(
+    err: PyErr,
+    set: impl FnOnce(&Bound<'_, pyo3::exceptions::PyBaseException>) -> PyResult<()>,
+) -> PyErr {
+    Python::attach(|py| match set(err.value(py)) {
+        Ok(()) => err,
+        Err(setattr_err) => setattr_err,
+    })
+}
+
+/// Classifies the message of an `Invalid` error. The server reports missing
+/// schema objects only in the message, so this matches the wording used by
+/// ScyllaDB and Cassandra.
+fn invalid_query_reason(message: &str) -> Option<&'static str> {
+    let message = message.to_ascii_lowercase();
+    if message.starts_with("keyspace") && message.contains("does not exist") {
+        Some("keyspace_not_found")
+    } else if message.contains("unconfigured table")
+        || (message.starts_with("table") && message.contains("does not exist"))
+    {
+        Some("table_not_found")
+    } else {
+        None
+    }
 }
 
 impl From<DriverExecuteError> for PyErr {
@@ -704,20 +1197,71 @@ impl From<DriverExecuteError> for PyErr {
                 ExecuteError::new_err("Paging state must be None for unpaged execution")
             }
 
+            DriverExecuteError::PagingStateOfOtherStatement => ExecuteError::new_err(
+                "Paging state was obtained for a different statement than the one being executed",
+            ),
+
+            DriverExecuteError::MaxRowsMustBeNoneForPagedExecution => {
+                ExecuteError::new_err("max_rows can only be used with unpaged execution")
+            }
+
+            DriverExecuteError::RowLimitExceeded { limit, rows } => ExecuteError::new_err(format!(
+                "Unpaged query returned {rows} rows, which exceeds max_rows={limit}"
+            )),
+
             DriverExecuteError::RustDriverExecutionError { source } => {
                 let message = format!("Failed to execute statement: {source}");
 
-                ExecuteError::new_err(message)
+                build_execution_pyerr(message, &source)
+            }
+
+            DriverExecuteError::RetriesRequireIdempotentStatement => ExecuteError::new_err(
+                "retries can only be used with statements marked as idempotent",
+            ),
+
+            DriverExecuteError::AllowFilteringRejected { query } => ExecuteError::new_err(format!(
+                "Statement contains ALLOW FILTERING, which the session's AllowFilteringPolicy rejects: {query}"
+            )),
+
+            DriverExecuteError::MappingForMixedBindMarkers => ExecuteError::new_err(
+                "Statement mixes positional (?) and named (:name) bind markers, so values cannot be \
+matched by name; pass them as a list or tuple, in the order the markers appear",
+            ),
+
+            DriverExecuteError::UnknownPreparedStatementId { id } => {
+                let id: String = id.iter().map(|byte| format!("{byte:02x}")).collect();
+                ExecuteError::new_err(format!(
+                    "No statement with id {id} was prepared by this session; pass `query` to prepare it"
+                ))
+            }
+
+            DriverExecuteError::RetriesExhausted { attempts, source } => {
+                let message =
+                    format!("Failed to execute statement after {attempts} attempts: {source}");
+
+                build_execution_pyerr(message, &source)
             }
 
             DriverExecuteError::RuntimeTaskJoinFailed { message } => ExecuteError::new_err(
                 format!("Internal driver error: runtime error while executing query: {message}"),
             ),
 
+            DriverExecuteError::UnexpectedSystemTableResult { message } => ExecuteError::new_err(
+                format!("Unexpected result of a system table query: {message}"),
+            ),
+
             DriverExecuteError::SerializationFailed { source } => {
                 let message = format!("Failed to serialize values: {source}");
                 ExecuteError::new_err(message)
             }
+
+            DriverExecuteError::BlockingCallInsideEventLoop => {
+                ExecuteError::new_err(BLOCKING_CALL_INSIDE_EVENT_LOOP)
+            }
+
+            DriverExecuteError::NoRunningEventLoop => ExecuteError::new_err(NO_RUNNING_EVENT_LOOP),
+
+            DriverExecuteError::SessionShutDown => ExecuteError::new_err(SESSION_SHUT_DOWN),
         }
     }
 }
@@ -731,6 +1275,18 @@ impl From<tokio::task::JoinError> for DriverExecuteError {
     }
 }
 
+impl From<SessionShutDown> for DriverExecuteError {
+    fn from(_: SessionShutDown) -> Self {
+        Self::SessionShutDown
+    }
+}
+
+impl From<NoRunningEventLoop> for DriverExecuteError {
+    fn from(_: NoRunningEventLoop) -> Self {
+        Self::NoRunningEventLoop
+    }
+}
+
 /// Errors that can occur during preparation of a statement.
 #[derive(Debug)]
 #[must_use]
@@ -742,6 +1298,14 @@ pub enum DriverPrepareError {
     },
     /// Attempted to prepare an already prepared statement.
     CannotPreparePreparedStatement,
+    /// The Tokio runtime task preparing the statement failed to join.
+    RuntimeTaskJoinFailed { message: Box<str> },
+    /// A blocking prepare was requested while an asyncio event loop is running.
+    BlockingCallInsideEventLoop,
+    /// A coroutine was awaited outside of a running asyncio event loop.
+    NoRunningEventLoop,
+    /// The session was shut down before the statement was prepared.
+    SessionShutDown,
 }
 
 impl DriverPrepareError {
@@ -756,6 +1320,16 @@ impl DriverPrepareError {
     pub fn cannot_prepare_prepared_statement() -> Self {
         Self::CannotPreparePreparedStatement
     }
+
+    pub fn runtime_task_join_failed(err: tokio::task::JoinError) -> Self {
+        Self::RuntimeTaskJoinFailed {
+            message: err.to_string().into_boxed_str(),
+        }
+    }
+
+    pub fn blocking_call_inside_event_loop() -> Self {
+        Self::BlockingCallInsideEventLoop
+    }
 }
 
 impl From<DriverPrepareError> for PyErr {
@@ -764,16 +1338,48 @@ impl From<DriverPrepareError> for PyErr {
             DriverPrepareError::RustDriverPrepareError { source } => {
                 let message = format!("Failed to prepare statement: {source}");
 
-                PrepareError::new_err(message)
+                build_prepare_pyerr(message, &source)
             }
 
             DriverPrepareError::CannotPreparePreparedStatement => PrepareError::new_err(
                 "Cannot prepare a PreparedStatement; expected a str or Statement",
             ),
+
+            DriverPrepareError::RuntimeTaskJoinFailed { message } => {
+                PrepareError::new_err(format!(
+                    "Internal driver error: runtime error while preparing statement: {message}"
+                ))
+            }
+
+            DriverPrepareError::BlockingCallInsideEventLoop => {
+                PrepareError::new_err(BLOCKING_CALL_INSIDE_EVENT_LOOP)
+            }
+
+            DriverPrepareError::NoRunningEventLoop => PrepareError::new_err(NO_RUNNING_EVENT_LOOP),
+
+            DriverPrepareError::SessionShutDown => PrepareError::new_err(SESSION_SHUT_DOWN),
         }
     }
 }
 
+impl From<tokio::task::JoinError> for DriverPrepareError {
+    fn from(err: tokio::task::JoinError) -> Self {
+        DriverPrepareError::runtime_task_join_failed(err)
+    }
+}
+
+impl From<SessionShutDown> for DriverPrepareError {
+    fn from(_: SessionShutDown) -> Self {
+        Self::SessionShutDown
+    }
+}
+
+impl From<NoRunningEventLoop> for DriverPrepareError {
+    fn from(_: NoRunningEventLoop) -> Self {
+        Self::NoRunningEventLoop
+    }
+}
+
 /// Errors that can occur during schema agreement checks.
 #[derive(Debug)]
 #[must_use]
@@ -784,6 +1390,10 @@ pub enum DriverSchemaAgreementError {
     },
     /// The Tokio runtime task responsible for checking schema agreement failed to join.
     RuntimeTaskJoinFailed { message: Box<str> },
+    /// A coroutine was awaited outside of a running asyncio event loop.
+    NoRunningEventLoop,
+    /// The session was shut down before the check.
+    SessionShutDown,
 }
 
 impl DriverSchemaAgreementError {
@@ -818,6 +1428,14 @@ impl From<DriverSchemaAgreementError> for PyErr {
                     "Internal driver error: runtime error while checking schema agreement: {message}"
                 ))
             }
+
+            DriverSchemaAgreementError::NoRunningEventLoop => {
+                SchemaAgreementError::new_err(NO_RUNNING_EVENT_LOOP)
+            }
+
+            DriverSchemaAgreementError::SessionShutDown => {
+                SchemaAgreementError::new_err(SESSION_SHUT_DOWN)
+            }
         }
     }
 }
@@ -830,12 +1448,176 @@ impl From<tokio::task::JoinError> for DriverSchemaAgreementError {
     }
 }
 
+impl From<SessionShutDown> for DriverSchemaAgreementError {
+    fn from(_: SessionShutDown) -> Self {
+        Self::SessionShutDown
+    }
+}
+
+impl From<NoRunningEventLoop> for DriverSchemaAgreementError {
+    fn from(_: NoRunningEventLoop) -> Self {
+        Self::NoRunningEventLoop
+    }
+}
+
+/// Errors that can occur while waiting for nodes to become connected.
+#[derive(Debug)]
+#[must_use]
+pub enum DriverWaitForNodesError {
+    /// Fewer nodes than requested were connected when the timeout elapsed.
+    Timeout {
+        expected: usize,
+        connected: usize,
+        timeout: Duration,
+    },
+    /// The Tokio runtime task waiting for the nodes failed to join.
+    RuntimeTaskJoinFailed { message: Box<str> },
+    /// A coroutine was awaited outside of a running asyncio event loop.
+    NoRunningEventLoop,
+    /// The session was shut down before waiting.
+    SessionShutDown,
+}
+
+impl DriverWaitForNodesError {
+    /* Constructors */
+
+    pub fn timeout(expected: usize, connected: usize, timeout: Duration) -> Self {
+        Self::Timeout {
+            expected,
+            connected,
+            timeout,
+        }
+    }
+
+    pub fn runtime_task_join_failed(err: tokio::task::JoinError) -> Self {
+        Self::RuntimeTaskJoinFailed {
+            message: err.to_string().into_boxed_str(),
+        }
+    }
+}
+
+impl From<DriverWaitForNodesError> for PyErr {
+    fn from(e: DriverWaitForNodesError) -> PyErr {
+        match e {
+            DriverWaitForNodesError::Timeout {
+                expected,
+                connected,
+                timeout,
+            } => PyRuntimeError::new_err(format!(
+                "Timed out after {timeout:?} waiting for {expected} connected nodes, {connected} connected"
+            )),
+
+            DriverWaitForNodesError::RuntimeTaskJoinFailed { message } => PyRuntimeError::new_err(
+                format!("Internal driver error: runtime error while waiting for nodes: {message}"),
+            ),
+
+            DriverWaitForNodesError::NoRunningEventLoop => {
+                PyRuntimeError::new_err(NO_RUNNING_EVENT_LOOP)
+            }
+
+            DriverWaitForNodesError::SessionShutDown => PyRuntimeError::new_err(SESSION_SHUT_DOWN),
+        }
+    }
+}
+
+impl From<tokio::task::JoinError> for DriverWaitForNodesError {
+    fn from(err: tokio::task::JoinError) -> Self {
+        DriverWaitForNodesError::runtime_task_join_failed(err)
+    }
+}
+
+impl From<SessionShutDown> for DriverWaitForNodesError {
+    fn from(_: SessionShutDown) -> Self {
+        Self::SessionShutDown
+    }
+}
+
+impl From<NoRunningEventLoop> for DriverWaitForNodesError {
+    fn from(_: NoRunningEventLoop) -> Self {
+        Self::NoRunningEventLoop
+    }
+}
+
+/// Errors that can occur while reading the tracing info of a request.
+#[derive(Debug)]
+#[must_use]
+pub enum DriverTracingError {
+    /// The Rust driver failed to read the tracing info.
+    RustDriverTracingError {
+        source: Box<scylla::errors::TracingError>,
+    },
+    /// The Tokio runtime task reading the tracing info failed to join.
+    RuntimeTaskJoinFailed { message: Box<str> },
+    /// A coroutine was awaited outside of a running asyncio event loop.
+    NoRunningEventLoop,
+    /// The session was shut down before the tracing info was read.
+    SessionShutDown,
+}
+
+impl DriverTracingError {
+    /* Constructors */
+
+    pub fn rust_driver_tracing_error(source: scylla::errors::TracingError) -> Self {
+        Self::RustDriverTracingError {
+            source: Box::new(source),
+        }
+    }
+
+    pub fn runtime_task_join_failed(err: tokio::task::JoinError) -> Self {
+        Self::RuntimeTaskJoinFailed {
+            message: err.to_string().into_boxed_str(),
+        }
+    }
+}
+
+impl From<DriverTracingError> for PyErr {
+    fn from(e: DriverTracingError) -> PyErr {
+        match e {
+            DriverTracingError::RustDriverTracingError { source } => {
+                TracingError::new_err(format!("Failed to read tracing info: {source}"))
+            }
+
+            DriverTracingError::RuntimeTaskJoinFailed { message } => {
+                TracingError::new_err(format!(
+                    "Internal driver error: runtime error while reading tracing info: {message}"
+                ))
+            }
+
+            DriverTracingError::NoRunningEventLoop => TracingError::new_err(NO_RUNNING_EVENT_LOOP),
+
+            DriverTracingError::SessionShutDown => TracingError::new_err(SESSION_SHUT_DOWN),
+        }
+    }
+}
+
+impl From<tokio::task::JoinError> for DriverTracingError {
+    fn from(err: tokio::task::JoinError) -> Self {
+        DriverTracingError::runtime_task_join_failed(err)
+    }
+}
+
+impl From<SessionShutDown> for DriverTracingError {
+    fn from(_: SessionShutDown) -> Self {
+        Self::SessionShutDown
+    }
+}
+
+impl From<NoRunningEventLoop> for DriverTracingError {
+    fn from(_: NoRunningEventLoop) -> Self {
+        Self::NoRunningEventLoop
+    }
+}
+
 /// Errors related to invalid statement configuration.
 #[derive(Debug)]
 #[must_use]
 pub enum DriverStatementConfigError {
     /// The provided request timeout is not a non-negative finite number of seconds.
     InvalidRequestTimeout { value: f64 },
+    /// The number of positional bind markers doesn't match the number of values.
+    BindMarkerCountMismatch { markers: usize, values: usize },
+    /// Serial consistency was set with `check_lwt=True` on a statement without an `IF` condition.
+    SerialConsistencyWithoutLwt,
     /// An error occurred in Python code while handling a statement value.
     PythonConversionFailed { source: Box<PyErr> },
 }
@@ -847,6 +1629,14 @@ impl DriverStatementConfigError {
         Self::InvalidRequestTimeout { value }
     }
 
+    pub fn bind_marker_count_mismatch(markers: usize, values: usize) -> Self {
+        Self::BindMarkerCountMismatch { markers, values }
+    }
+
+    pub fn serial_consistency_without_lwt() -> Self {
+        Self::SerialConsistencyWithoutLwt
+    }
+
     pub fn python_conversion_failed(source: PyErr) -> Self {
         Self::PythonConversionFailed {
             source: Box::new(source),
@@ -862,6 +1652,17 @@ impl From<DriverStatementConfigError> for PyErr {
                     "timeout must be a non-negative, finite number (in seconds), got {value}"
                 ))
             }
+            DriverStatementConfigError::BindMarkerCountMismatch { markers, values } => {
+                StatementConfigError::new_err(format!(
+                    "Statement has {markers} positional bind markers, but {values} values were provided"
+                ))
+            }
+            DriverStatementConfigError::SerialConsistencyWithoutLwt => {
+                StatementConfigError::new_err(
+                    "Serial consistency only applies to lightweight transactions, \
+                 but the statement has no IF condition",
+                )
+            }
             DriverStatementConfigError::PythonConversionFailed { source } => Python::attach(|py| {
                 let err = StatementConfigError::new_err(
                     "Python conversion failed while handling batch value",
@@ -931,8 +1732,29 @@ pub enum SerializationErrorKind {
     UnsupportedType { cql: Box<str> },
     /// The Python value has the wrong top-level shape for the target CQL type.
     TypeMismatch { expected: TypeExpected },
+    /// A `str` was given for a CQL blob, which needs `bytes` with an explicit encoding.
+    StrForBlob,
+    /// An `ipaddress` network was given for a CQL inet, which holds a single address.
+    IpNetworkForInet,
+    /// A `Decimal` NaN or infinity was given for a CQL decimal, which has no such values.
+    NonFiniteDecimal { value: Box<str> },
+    /// A `str` for a CQL ascii column contains a non-ASCII character at `index`.
+    NonAsciiString { index: usize },
+    /// A mapping of values has no key `name`, but has `key`, which differs from it only in case.
+    NameCaseMismatch { name: Box<str>, key: Box<str> },
     /// The Python value could not fit into the requested CQL representation.
     ValueOverflow,
+    /// A Python `int` is outside the range of the fixed-width CQL integer type `cql`.
+    IntegerOutOfRange {
+        cql: &'static str,
+        value: Box<str>,
+        min: i64,
+        max: i64,
+    },
+    /// A collection has more elements than fit in the `i32` element count of the CQL format.
+    TooManyElements { count: usize },
+    /// The serialized value is larger than the `i32` length prefix of a CQL value allows.
+    ValueTooLarge,
     /// An error occurred while interacting with Python objects during serialization.
     PythonInteropFailed { source: Box<PyErr> },
     /// An error occurred in the Rust driver's serialization layer.
@@ -1003,6 +1825,35 @@ impl fmt::Display for DriverSerializationError {
                     write!(f, "Value overflow during serialization{location}")
                 }
             }
+            SerializationErrorKind::IntegerOutOfRange {
+                cql,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "{}{location}",
+                integer_out_of_range_message(cql, value, *min, *max)
+            ),
+            SerializationErrorKind::StrForBlob => write!(f, "{STR_FOR_BLOB}{location}"),
+            SerializationErrorKind::IpNetworkForInet => {
+                write!(f, "{IP_NETWORK_FOR_INET}{location}")
+            }
+            SerializationErrorKind::NonFiniteDecimal { value } => {
+                write!(f, "{}{location}", non_finite_decimal_message(value))
+            }
+            SerializationErrorKind::NonAsciiString { index } => {
+                write!(f, "{}{location}", non_ascii_string_message(*index))
+            }
+            SerializationErrorKind::NameCaseMismatch { name, key } => {
+                write!(f, "{}{location}", name_case_mismatch_message(name, key))
+            }
+            SerializationErrorKind::TooManyElements { count } => {
+                write!(f, "{}{location}", too_many_elements_message(*count))
+            }
+            SerializationErrorKind::ValueTooLarge => {
+                write!(f, "{VALUE_TOO_LARGE}{location}")
+            }
             SerializationErrorKind::PythonInteropFailed { source } => {
                 if location.is_empty() {
                     write!(f, "Python serialization failed: {source}")
@@ -1055,6 +1906,77 @@ impl DriverSerializationError {
         }
     }
 
+    pub fn integer_out_of_range(
+        cql: &'static str,
+        value: impl Into<Box<str>>,
+        min: i64,
+        max: i64,
+    ) -> Self {
+        Self {
+            kind: SerializationErrorKind::IntegerOutOfRange {
+                cql,
+                value: value.into(),
+                min,
+                max,
+            },
+            location: None,
+        }
+    }
+
+    pub fn str_for_blob() -> Self {
+        Self {
+            kind: SerializationErrorKind::StrForBlob,
+            location: None,
+        }
+    }
+
+    pub fn ip_network_for_inet() -> Self {
+        Self {
+            kind: SerializationErrorKind::IpNetworkForInet,
+            location: None,
+        }
+    }
+
+    pub fn non_finite_decimal(value: impl Into<Box<str>>) -> Self {
+        Self {
+            kind: SerializationErrorKind::NonFiniteDecimal {
+                value: value.into(),
+            },
+            location: None,
+        }
+    }
+
+    pub fn non_ascii_string(index: usize) -> Self {
+        Self {
+            kind: SerializationErrorKind::NonAsciiString { index },
+            location: None,
+        }
+    }
+
+    pub fn name_case_mismatch(name: impl Into<Box<str>>, key: impl Into<Box<str>>) -> Self {
+        Self {
+            kind: SerializationErrorKind::NameCaseMismatch {
+                name: name.into(),
+                key: key.into(),
+            },
+            location: None,
+        }
+    }
+
+    pub fn too_many_elements(count: usize) -> Self {
+        Self {
+            kind: SerializationErrorKind::TooManyElements { count },
+            location: None,
+        }
+    }
+
+    pub fn value_too_large() -> Self {
+        Self {
+            kind: SerializationErrorKind::ValueTooLarge,
+            location: None,
+        }
+    }
+
     pub fn scylla_serialize_failed(source: scylla::serialize::SerializationError) -> Self {
         Self {
             kind: SerializationErrorKind::ScyllaSerializeFailed { source },
@@ -1084,6 +2006,49 @@ impl DriverSerializationError {
     }
 }
 
+const STR_FOR_BLOB: &str = "Cannot serialize str as CQL blob, blob values must be bytes: \
+encode the text first, e.g. value.encode(\"utf-8\")";
+
+const IP_NETWORK_FOR_INET: &str = "Cannot serialize an IP network as CQL inet, inet values \
+are single addresses: pass an ipaddress.ip_address, e.g. network.network_address";
+
+const VALUE_TOO_LARGE: &str =
+    "Serialized value exceeds the maximum CQL value size of 2147483647 bytes (2 GiB)";
+
+fn non_ascii_string_message(index: usize) -> String {
+    format!(
+        "Cannot serialize str as CQL ascii: non-ASCII character at index {index}, \
+ascii values may contain only characters U+0000 to U+007F; use a text column for other characters"
+    )
+}
+
+fn integer_out_of_range_message(cql: &str, value: &str, min: i64, max: i64) -> String {
+    format!(
+        "Value overflow during serialization: {value} is out of range for CQL {cql}, \
+which holds integers from {min} to {max}; use a varint column for larger values"
+    )
+}
+
+fn name_case_mismatch_message(name: &str, key: &str) -> String {
+    format!(
+        "Missing value for bind marker '{name}': the mapping has key '{key}', which differs only in case; \
+unquoted CQL identifiers are lowercased, so use the key '{name}' or quote the identifier in the statement"
+    )
+}
+
+fn non_finite_decimal_message(value: &str) -> String {
+    format!(
+        "Cannot serialize Decimal('{value}') as CQL decimal: \
+CQL decimal has no representation of NaN or infinity"
+    )
+}
+
+fn too_many_elements_message(count: usize) -> String {
+    format!(
+        "Collection has {count} elements, but a CQL collection can hold at most 2147483647 (i32::MAX) elements"
+    )
+}
+
 /// Helper function to format serialization location information into a readable string.
 fn format_serialization_location(loc: &Option<ParameterReference>) -> String {
     let mut parts: Vec<String> = Vec::new();
@@ -1186,6 +2151,90 @@ impl From<DriverSerializationError> for PyErr {
                     )
                 }
 
+                SerializationErrorKind::IntegerOutOfRange {
+                    cql,
+                    value,
+                    min,
+                    max,
+                } => build_serialization_pyerr(
+                    py,
+                    ValueOverflowSerializationError::new_err(format!(
+                        "{}{location_as_string}",
+                        integer_out_of_range_message(cql, &value, min, max)
+                    )),
+                    &e.location,
+                    None,
+                ),
+
+                SerializationErrorKind::StrForBlob => build_serialization_pyerr(
+                    py,
+                    TypeMismatchSerializationError::new_err(format!(
+                        "{STR_FOR_BLOB}{location_as_string}"
+                    )),
+                    &e.location,
+                    None,
+                ),
+
+                SerializationErrorKind::IpNetworkForInet => build_serialization_pyerr(
+                    py,
+                    TypeMismatchSerializationError::new_err(format!(
+                        "{IP_NETWORK_FOR_INET}{location_as_string}"
+                    )),
+                    &e.location,
+                    None,
+                ),
+
+                SerializationErrorKind::NonFiniteDecimal { value } => build_serialization_pyerr(
+                    py,
+                    ValueOverflowSerializationError::new_err(format!(
+                        "{}{location_as_string}",
+                        non_finite_decimal_message(&value)
+                    )),
+                    &e.location,
+                    None,
+                ),
+
+                SerializationErrorKind::NonAsciiString { index } => build_serialization_pyerr(
+                    py,
+                    TypeMismatchSerializationError::new_err(format!(
+                        "{}{location_as_string}",
+                        non_ascii_string_message(index)
+                    )),
+                    &e.location,
+                    None,
+                ),
+
+                SerializationErrorKind::NameCaseMismatch { name, key } => {
+                    build_serialization_pyerr(
+                        py,
+                        SerializeFailedError::new_err(format!(
+                            "{}{location_as_string}",
+                            name_case_mismatch_message(&name, &key)
+                        )),
+                        &e.location,
+                        None,
+                    )
+                }
+
+                SerializationErrorKind::TooManyElements { count } => build_serialization_pyerr(
+                    py,
+                    ValueOverflowSerializationError::new_err(format!(
+                        "{}{location_as_string}",
+                        too_many_elements_message(count)
+                    )),
+                    &e.location,
+                    None,
+                ),
+
+                SerializationErrorKind::ValueTooLarge => build_serialization_pyerr(
+                    py,
+                    ValueOverflowSerializationError::new_err(format!(
+                        "{VALUE_TOO_LARGE}{location_as_string}"
+                    )),
+                    &e.location,
+                    None,
+                ),
+
                 SerializationErrorKind::PythonInteropFailed { source } => {
                     let message = if location_as_string.is_empty() {
                         "Python interop failed".to_string()
@@ -1235,6 +2284,8 @@ pub(crate) enum DriverUseKeyspaceError {
     KeyspaceNameMismatch { message: String },
     RequestTimeout { message: String },
     RuntimeTaskJoinFailed { message: String },
+    NoRunningEventLoop,
+    SessionShutDown,
 }
 
 impl From<RustUseKeyspaceError> for DriverUseKeyspaceError {
@@ -1269,6 +2320,18 @@ impl From<tokio::task::JoinError> for DriverUseKeyspaceError {
     }
 }
 
+impl From<SessionShutDown> for DriverUseKeyspaceError {
+    fn from(_: SessionShutDown) -> Self {
+        Self::SessionShutDown
+    }
+}
+
+impl From<NoRunningEventLoop> for DriverUseKeyspaceError {
+    fn from(_: NoRunningEventLoop) -> Self {
+        Self::NoRunningEventLoop
+    }
+}
+
 impl From<DriverUseKeyspaceError> for PyErr {
     fn from(e: DriverUseKeyspaceError) -> Self {
         match e {
@@ -1285,6 +2348,10 @@ impl From<DriverUseKeyspaceError> for PyErr {
             DriverUseKeyspaceError::RuntimeTaskJoinFailed { message } => {
                 RuntimeTaskJoinFailedError::new_err(message)
             }
+            DriverUseKeyspaceError::NoRunningEventLoop => {
+                UseKeyspaceError::new_err(NO_RUNNING_EVENT_LOOP)
+            }
+            DriverUseKeyspaceError::SessionShutDown => UseKeyspaceError::new_err(SESSION_SHUT_DOWN),
         }
     }
 }
@@ -1347,6 +2414,11 @@ impl From<DriverClusterStateTokenError> for PyErr {
 pub(crate) fn errors(py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add("ScyllaError", py.get_type::<ScyllaError>())?;
     module.add("RowIterationError", py.get_type::<RowIterationError>())?;
+    module.add("LwtResultError", py.get_type::<LwtResultError>())?;
+    module.add(
+        "ColumnTypeMismatchError",
+        py.get_type::<ColumnTypeMismatchError>(),
+    )?;
     module.add(
         "DeserializationError",
         py.get_type::<DeserializationError>(),
@@ -1370,11 +2442,18 @@ pub(crate) fn errors(py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<(
         py.get_type::<StatementConversionError>(),
     )?;
     module.add("PrepareError", py.get_type::<PrepareError>())?;
+    module.add(
+        "InvalidStatementError",
+        py.get_type::<InvalidStatementError>(),
+    )?;
     module.add(
         "SchemaAgreementError",
         py.get_type::<SchemaAgreementError>(),
     )?;
+    module.add("TracingError", py.get_type::<TracingError>())?;
     module.add("ExecuteError", py.get_type::<ExecuteError>())?;
+    module.add("WriteTimeoutError", py.get_type::<WriteTimeoutError>())?;
+    module.add("InvalidQueryError", py.get_type::<InvalidQueryError>())?;
     module.add(
         "StatementConfigError",
         py.get_type::<StatementConfigError>(),
@@ -1,14 +1,28 @@
 use pyo3::prelude::*;
 use scylla::client;
+use scylla::client::execution_profile::ExecutionProfileHandle;
 use std::time::Duration;
 
 use crate::enums::{PyConsistency, PySerialConsistency};
 use crate::errors::DriverStatementConfigError;
+use crate::policies::{PyLoadBalancingPolicy, PyRetryPolicy};
 
 #[pyclass(frozen, from_py_object)]
 #[derive(Clone)]
 pub(crate) struct ExecutionProfile {
     pub(crate) _inner: client::execution_profile::ExecutionProfile,
+    /// Handle created once with the profile and shared by every statement,
+    /// batch and session the profile is applied to.
+    pub(crate) handle: ExecutionProfileHandle,
+}
+
+impl From<ExecutionProfileHandle> for ExecutionProfile {
+    fn from(handle: ExecutionProfileHandle) -> Self {
+        Self {
+            _inner: handle.to_profile(),
+            handle,
+        }
+    }
 }
 
 #[pymethods]
@@ -18,11 +32,15 @@ impl ExecutionProfile {
         timeout=30.0,
         consistency=PyConsistency::LocalQuorum,
         serial_consistency=PySerialConsistency::LocalSerial,
+        load_balancing_policy=None,
+        retry_policy=None,
     ))]
     pub(crate) fn new(
         timeout: Option<f64>,
         consistency: PyConsistency,
         serial_consistency: Option<PySerialConsistency>,
+        load_balancing_policy: Option<PyLoadBalancingPolicy>,
+        retry_policy: Option<PyRetryPolicy>,
     ) -> Result<Self, DriverStatementConfigError> {
         let mut profile_builder = client::execution_profile::ExecutionProfile::builder();
 
@@ -38,9 +56,17 @@ impl ExecutionProfile {
         profile_builder =
             profile_builder.serial_consistency(serial_consistency.map(|sc| sc.into()));
 
-        Ok(ExecutionProfile {
-            _inner: profile_builder.build(),
-        })
+        if let Some(policy) = load_balancing_policy {
+            profile_builder = profile_builder.load_balancing_policy(policy._inner);
+        }
+
+        if let Some(policy) = retry_policy {
+            profile_builder = profile_builder.retry_policy(policy._inner);
+        }
+
+        Ok(ExecutionProfile::from(
+            profile_builder.build().into_handle(),
+        ))
     }
 
     #[getter]
@@ -59,6 +85,15 @@ impl ExecutionProfile {
             .get_serial_consistency()
             .map(PySerialConsistency::from)
     }
+
+    /// Returns the profile's load balancing policy, which is the driver's
+    /// default policy if none was given.
+    #[getter]
+    pub(crate) fn get_load_balancing_policy(&self) -> PyLoadBalancingPolicy {
+        PyLoadBalancingPolicy {
+            _inner: self._inner.get_load_balancing_policy().clone(),
+        }
+    }
 }
 
 #[pymodule]
@@ -19,16 +19,20 @@ mod errors;
 mod execution_profile;
 mod policies;
 mod routing;
+mod runtime;
 mod serialize;
 mod session;
 mod session_builder;
 mod statement;
+mod timeuuid;
+mod tracing_info;
 mod types;
 mod utils;
 
 use crate::utils::add_submodule;
 
-pub static RUNTIME: LazyLock<Runtime> = LazyLock::new(|| Runtime::new().unwrap());
+/// Global Tokio runtime, built on first use. See `runtime::configure_runtime`.
+pub static RUNTIME: LazyLock<Runtime> = LazyLock::new(runtime::build_runtime);
 
 static INIT_LOG: Once = Once::new();
 
@@ -69,5 +73,7 @@ fn scylla(py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     add_submodule(py, module, "policies", policies::policies)?;
     add_submodule(py, module, "cluster", cluster::cluster)?;
     add_submodule(py, module, "routing", routing::routing)?;
+    add_submodule(py, module, "runtime", runtime::runtime)?;
+    add_submodule(py, module, "tracing", tracing_info::tracing)?;
     Ok(())
 }
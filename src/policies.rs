@@ -8,8 +8,11 @@ use scylla::cluster::metadata::Peer;
 use scylla::errors::{CustomTranslationError, TranslationError};
 use scylla::policies::address_translator::{AddressTranslator, UntranslatedPeer};
 use scylla::policies::host_filter::HostFilter;
+use scylla::policies::load_balancing::{DefaultPolicy, LoadBalancingPolicy};
+use scylla::policies::retry::{DefaultRetryPolicy, DowngradingConsistencyRetryPolicy, RetryPolicy};
 use scylla::policies::timestamp_generator::TimestampGenerator;
 use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 #[derive(Clone)]
@@ -326,6 +329,107 @@ impl From<&Peer> for PyPeer {
     }
 }
 
+/// Load balancing policy deciding which nodes (and shards) serve a request.
+///
+/// Built on the driver's default policy. Can be set on an `ExecutionProfile`,
+/// or on a single statement to override the profile's policy.
+#[pyclass(frozen, from_py_object, name = "LoadBalancingPolicy")]
+#[derive(Clone)]
+pub(crate) struct PyLoadBalancingPolicy {
+    pub(crate) _inner: Arc<dyn LoadBalancingPolicy>,
+}
+
+#[pymethods]
+impl PyLoadBalancingPolicy {
+    #[new]
+    #[pyo3(signature = (*, preferred_datacenter=None, token_aware=true, permit_dc_failover=false))]
+    fn new(
+        preferred_datacenter: Option<String>,
+        token_aware: bool,
+        permit_dc_failover: bool,
+    ) -> Self {
+        let mut builder = DefaultPolicy::builder()
+            .token_aware(token_aware)
+            .permit_dc_failover(permit_dc_failover);
+
+        if let Some(datacenter) = preferred_datacenter {
+            builder = builder.prefer_datacenter(datacenter);
+        }
+
+        PyLoadBalancingPolicy {
+            _inner: builder.build(),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("LoadBalancingPolicy({})", self._inner.name())
+    }
+
+    /// Policies are equal if they are the same policy object in the driver,
+    /// e.g. the one passed to an `ExecutionProfile` and the one it returns.
+    fn __eq__(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self._inner, &other._inner)
+    }
+
+    fn __hash__(&self) -> u64 {
+        Arc::as_ptr(&self._inner).cast::<()>() as usize as u64
+    }
+}
+
+/// Retry policy deciding whether and how a failed request attempt is retried.
+///
+/// Base class of the built-in retry policies; can be set on an `ExecutionProfile`.
+#[pyclass(subclass, frozen, from_py_object, name = "RetryPolicy")]
+#[derive(Clone)]
+pub(crate) struct PyRetryPolicy {
+    pub(crate) _inner: Arc<dyn RetryPolicy>,
+    name: &'static str,
+}
+
+#[pymethods]
+impl PyRetryPolicy {
+    fn __repr__(&self) -> String {
+        format!("{}()", self.name)
+    }
+}
+
+/// The driver's default retry policy, used when no other policy is set.
+#[pyclass(extends = PyRetryPolicy, frozen, name = "DefaultRetryPolicy")]
+pub(crate) struct PyDefaultRetryPolicy;
+
+#[pymethods]
+impl PyDefaultRetryPolicy {
+    #[new]
+    pub(crate) fn new() -> (Self, PyRetryPolicy) {
+        (
+            Self,
+            PyRetryPolicy {
+                _inner: Arc::new(DefaultRetryPolicy::new()),
+                name: "DefaultRetryPolicy",
+            },
+        )
+    }
+}
+
+/// Retries requests that failed for lack of replicas at a lower consistency
+/// level, one that the replicas which responded or are alive can satisfy.
+#[pyclass(extends = PyRetryPolicy, frozen, name = "DowngradingConsistencyRetryPolicy")]
+pub(crate) struct PyDowngradingConsistencyRetryPolicy;
+
+#[pymethods]
+impl PyDowngradingConsistencyRetryPolicy {
+    #[new]
+    pub(crate) fn new() -> (Self, PyRetryPolicy) {
+        (
+            Self,
+            PyRetryPolicy {
+                _inner: Arc::new(DowngradingConsistencyRetryPolicy::new()),
+                name: "DowngradingConsistencyRetryPolicy",
+            },
+        )
+    }
+}
+
 #[pymodule]
 pub(crate) fn policies(_py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<PyAuthenticatorProvider>()?;
@@ -335,5 +439,9 @@ pub(crate) fn policies(_py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResul
     module.add_class::<PyTimestampGenerator>()?;
     module.add_class::<PyHostFilter>()?;
     module.add_class::<PyPeer>()?;
+    module.add_class::<PyLoadBalancingPolicy>()?;
+    module.add_class::<PyRetryPolicy>()?;
+    module.add_class::<PyDefaultRetryPolicy>()?;
+    module.add_class::<PyDowngradingConsistencyRetryPolicy>()?;
     Ok(())
 }
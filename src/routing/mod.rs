@@ -1,10 +1,13 @@
+use std::num::NonZeroU16;
+
 use pyo3::{
+    exceptions::PyValueError,
     prelude::*,
     types::{PyList, PyString},
 };
 use scylla::{
     frame::response::result::TableSpec,
-    routing::{Shard, Token},
+    routing::{Shard, Sharder, Token},
 };
 
 use crate::cluster::{metadata::PyStrategy, node::PyNode, state::PyClusterState};
@@ -43,11 +46,95 @@ impl PyToken {
         self._inner == other._inner
     }
 
+    fn __lt__(&self, other: &PyToken) -> bool {
+        self._inner < other._inner
+    }
+
+    fn __le__(&self, other: &PyToken) -> bool {
+        self._inner <= other._inner
+    }
+
+    fn __gt__(&self, other: &PyToken) -> bool {
+        self._inner > other._inner
+    }
+
+    fn __ge__(&self, other: &PyToken) -> bool {
+        self._inner >= other._inner
+    }
+
     fn __hash__(&self) -> i64 {
         self._inner.value()
     }
 }
 
+/// Maps tokens and source ports to shards of a Scylla node.
+#[pyclass(name = "Sharder", frozen, skip_from_py_object)]
+pub(crate) struct PySharder {
+    pub(crate) _inner: Sharder,
+}
+
+impl From<Sharder> for PySharder {
+    fn from(sharder: Sharder) -> Self {
+        Self { _inner: sharder }
+    }
+}
+
+#[pymethods]
+impl PySharder {
+    #[new]
+    fn new(nr_shards: NonZeroU16, msb_ignore: u8) -> PyResult<Self> {
+        // The token is shifted left by `msb_ignore`, which overflows from 64 bits on.
+        if msb_ignore >= 64 {
+            return Err(PyValueError::new_err(format!(
+                "msb_ignore must be less than 64, got {msb_ignore}"
+            )));
+        }
+        Ok(Self {
+            _inner: Sharder::new(nr_shards, msb_ignore),
+        })
+    }
+
+    #[getter]
+    fn nr_shards(&self) -> u16 {
+        self._inner.nr_shards.get()
+    }
+
+    #[getter]
+    fn msb_ignore(&self) -> u8 {
+        self._inner.msb_ignore
+    }
+
+    fn shard_of(&self, token: &PyToken) -> Shard {
+        self._inner.shard_of(token._inner)
+    }
+
+    fn shard_of_source_port(&self, port: u16) -> Shard {
+        self._inner.shard_of_source_port(port)
+    }
+
+    /// Maps every port to its shard in a single call, keeping the order of `ports`.
+    fn shard_of_source_ports(&self, ports: Vec<u16>) -> Vec<Shard> {
+        ports
+            .into_iter()
+            .map(|port| self._inner.shard_of_source_port(port))
+            .collect()
+    }
+
+    fn __eq__(&self, other: &PySharder) -> bool {
+        self._inner == other._inner
+    }
+
+    fn __repr__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyString>> {
+        PyString::from_fmt(
+            py,
+            format_args!(
+                "Sharder(nr_shards={}, msb_ignore={})",
+                self._inner.nr_shards, self._inner.msb_ignore
+            ),
+        )
+    }
+}
+
 #[pyclass(name = "ReplicaLocator", frozen, skip_from_py_object)]
 pub(crate) struct PyReplicaLocator {
     _inner: Py<PyClusterState>,
@@ -214,5 +301,6 @@ impl PyReplicaLocator {
 pub(crate) fn routing(_py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<PyToken>()?;
     module.add_class::<PyReplicaLocator>()?;
+    module.add_class::<PySharder>()?;
     Ok(())
 }
@@ -0,0 +1,70 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::sync::Mutex;
+use tokio::runtime::{Builder, Runtime};
+
+/// Configuration of the global Tokio runtime.
+///
+/// `RUNTIME` is a `LazyLock` built on first use (first `connect`, request, ...)
+/// from the configuration stored here. Once built, the runtime cannot be
+/// reconfigured, so `configure_runtime` only has an effect before that point.
+struct RuntimeConfig {
+    worker_threads: Option<usize>,
+    initialized: bool,
+}
+
+static RUNTIME_CONFIG: Mutex<RuntimeConfig> = Mutex::new(RuntimeConfig {
+    worker_threads: None,
+    initialized: false,
+});
+
+/// Builds the global runtime. Called once, by the `RUNTIME` initializer.
+pub(crate) fn build_runtime() -> Runtime {
+    let mut config = RUNTIME_CONFIG.lock().unwrap();
+    config.initialized = true;
+
+    let mut builder = Builder::new_multi_thread();
+    builder.enable_all();
+    if let Some(worker_threads) = config.worker_threads {
+        builder.worker_threads(worker_threads);
+    }
+    builder.build().unwrap()
+}
+
+/// Configures the global Tokio runtime used to run all requests.
+///
+/// Must be called before the runtime is first used, i.e. before connecting
+/// the first session. Afterwards the call is ignored and a warning is logged.
+#[pyfunction]
+#[pyo3(signature = (*, worker_threads=None))]
+fn configure_runtime(worker_threads: Option<usize>) -> PyResult<()> {
+    if worker_threads == Some(0) {
+        return Err(PyValueError::new_err(
+            "worker_threads must be a positive integer",
+        ));
+    }
+
+    let mut config = RUNTIME_CONFIG.lock().unwrap();
+    if config.initialized {
+        log::warn!(
+            "The driver runtime is already running, configure_runtime() has no effect. Call it before connecting the first session."
+        );
+        return Ok(());
+    }
+    config.worker_threads = worker_threads;
+
+    Ok(())
+}
+
+/// Returns `true` if the global runtime has already been built.
+#[pyfunction]
+fn runtime_initialized() -> bool {
+    RUNTIME_CONFIG.lock().unwrap().initialized
+}
+
+#[pymodule]
+pub(crate) fn runtime(_py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(configure_runtime, module)?)?;
+    module.add_function(wrap_pyfunction!(runtime_initialized, module)?)?;
+    Ok(())
+}
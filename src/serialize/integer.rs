@@ -0,0 +1,103 @@
+use pyo3::types::PyInt;
+use pyo3::{Bound, PyAny, PyErr, PyResult, pyclass, pymethods};
+
+use crate::errors::DriverSerializationError;
+
+/// Rust integer types backing the fixed-width CQL integer types.
+pub(crate) trait CqlInteger {
+    const CQL_NAME: &'static str;
+    const MIN: i64;
+    const MAX: i64;
+}
+
+macro_rules! impl_cql_integer {
+    ($($rust:ty => $cql:literal),* $(,)?) => {
+        $(
+            impl CqlInteger for $rust {
+                const CQL_NAME: &'static str = $cql;
+                const MIN: i64 = <$rust>::MIN as i64;
+                const MAX: i64 = <$rust>::MAX as i64;
+            }
+        )*
+    };
+}
+
+impl_cql_integer!(i8 => "tinyint", i16 => "smallint", i32 => "int", i64 => "bigint");
+
+/// Error for a Python `int` that does not fit in the range of `T`, stored as CQL `cql`.
+/// Shared by serialization and the constructors of the classes below.
+pub(crate) fn integer_out_of_range<T: CqlInteger>(
+    cql: &'static str,
+    value: &Bound<'_, PyAny>,
+) -> DriverSerializationError {
+    DriverSerializationError::integer_out_of_range(cql, value.to_string(), T::MIN, T::MAX)
+}
+
+/// Explicit counter value to bind to `counter` columns.
+///
+/// Plain `int`s are accepted for counter columns as well, but a `CqlCounter`
+/// is rejected for any other column type, which catches counter/non-counter mixups.
+#[pyclass(frozen, eq, hash)]
+#[derive(PartialEq, Eq, Hash)]
+pub struct CqlCounter {
+    #[pyo3(get)]
+    pub(crate) value: i64,
+}
+
+#[pymethods]
+impl CqlCounter {
+    #[new]
+    fn new(value: i64) -> Self {
+        Self { value }
+    }
+
+    fn __int__(&self) -> i64 {
+        self.value
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CqlCounter({})", self.value)
+    }
+}
+
+/// Defines a pyclass wrapping a Python `int` which serializes only as the
+/// given CQL integer type. The range of the value is checked on construction,
+/// the same way as when a plain `int` is serialized.
+macro_rules! integer_width_class {
+    ($name:ident, $int:ty, $cql_name:literal) => {
+        #[doc = concat!("Explicit value to bind to `", $cql_name, "` columns.")]
+        #[pyclass(frozen, eq, hash)]
+        #[derive(PartialEq, Eq, Hash)]
+        pub struct $name {
+            #[pyo3(get)]
+            pub(crate) value: $int,
+        }
+
+        #[pymethods]
+        impl $name {
+            #[new]
+            fn new(value: &Bound<'_, PyInt>) -> PyResult<Self> {
+                let value = value.extract::<$int>().map_err(|_| {
+                    PyErr::from(integer_out_of_range::<$int>(
+                        <$int as CqlInteger>::CQL_NAME,
+                        value.as_any(),
+                    ))
+                })?;
+                Ok(Self { value })
+            }
+
+            fn __int__(&self) -> $int {
+                self.value
+            }
+
+            fn __repr__(&self) -> String {
+                format!(concat!(stringify!($name), "({})"), self.value)
+            }
+        }
+    };
+}
+
+integer_width_class!(CqlTinyInt, i8, "tinyint");
+integer_width_class!(CqlSmallInt, i16, "smallint");
+integer_width_class!(CqlInt, i32, "int");
+integer_width_class!(CqlBigInt, i64, "bigint");
@@ -1,3 +1,5 @@
+pub mod integer;
+
 pub mod value_list;
 
 pub mod value;
@@ -1,4 +1,9 @@
+use crate::cluster::metadata::column_type::{PyCqlColumnType, column_type_from_py};
+use crate::deserialize::value::PyCqlValue;
 use crate::errors::{DriverSerializationError, TypeExpected};
+use crate::serialize::integer::{
+    CqlBigInt, CqlCounter, CqlInt, CqlInteger, CqlSmallInt, CqlTinyInt, integer_out_of_range,
+};
 
 use std::any::Any;
 use std::net::IpAddr;
@@ -7,12 +12,15 @@ use std::sync::Arc;
 
 use bigdecimal::BigDecimal;
 use bigdecimal::num_bigint::BigInt;
-use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeDelta, Utc};
 use uuid::Uuid;
 
 use pyo3::Bound;
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyBytes, PyDict, PyInt, PyList, PyMapping, PySet, PyString, PyTuple};
+use pyo3::types::{
+    PyAny, PyBool, PyBytes, PyDate, PyDateTime, PyDict, PyInt, PyIterator, PyList, PyMapping,
+    PySet, PyString, PyTuple,
+};
 
 use scylla::cluster::metadata::{CollectionType, ColumnType, NativeType, UserDefinedType};
 use scylla::serialize::SerializationError;
@@ -23,7 +31,7 @@ use scylla::serialize::value::{
 };
 use scylla::serialize::writers::{CellWriter, WrittenCellProof};
 use scylla::value::{
-    Counter, CqlDuration, CqlTime, CqlTimestamp, CqlTimeuuid, CqlValue, ValueOverflow,
+    Counter, CqlDate, CqlDuration, CqlTime, CqlTimestamp, CqlTimeuuid, CqlValue, ValueOverflow,
 };
 
 use scylla_cql::serialize::value::{
@@ -31,6 +39,41 @@ use scylla_cql::serialize::value::{
     serialize_next_variable_length_elem_unstable,
 };
 
+/// Number of nanoseconds in a day; CQL `time` values lie in `0..NANOSECONDS_PER_DAY`.
+pub(crate) const NANOSECONDS_PER_DAY: i64 = 86_400_000_000_000;
+
+/// Number of microseconds in a day, the precision of `datetime.timedelta`.
+const MICROSECONDS_PER_DAY: i64 = 86_400_000_000;
+
+/// CQL `date` is encoded as days since the Unix epoch shifted by 2^31,
+/// so that the epoch is in the middle of the `u32` range.
+pub(crate) const DATE_EPOCH_OFFSET: i64 = 1 << 31;
+
+/// Extracts a UTC timestamp from a timezone-aware `datetime.datetime`
+/// or any of its subclasses. Returns `None` for any other value.
+pub(crate) fn extract_utc_datetime(value: &Bound<'_, PyAny>) -> Option<DateTime<Utc>> {
+    PyAnyWrapper::new(value).extract_timestamp()
+}
+
+/// Serializes `value` as a CQL value of `column_type`, exactly as it would be
+/// serialized when bound to a statement. Returns `None` for a null value.
+#[pyfunction]
+pub(crate) fn serialize_value<'py>(
+    value: &Bound<'py, PyAny>,
+    column_type: &Bound<'py, PyCqlColumnType>,
+) -> PyResult<Option<Bound<'py, PyBytes>>> {
+    let typ = column_type_from_py(column_type)?;
+    let mut cell = Vec::new();
+    PyAnyWrapper::new(value)
+        .serialize(&typ, CellWriter::new(&mut cell))
+        .map_err(DriverSerializationError::scylla_serialize_failed)?;
+
+    // The cell starts with the length of its contents, negative for null.
+    let (len, contents) = cell.split_at(4);
+    let is_null = i32::from_be_bytes([len[0], len[1], len[2], len[3]]) < 0;
+    Ok((!is_null).then(|| PyBytes::new(value.py(), contents)))
+}
+
 /// Wrapper around a Python value (`PyAny`) used for Python → CQL serialization.
 ///
 /// This type performs runtime type inspection and dispatches the value to the
@@ -68,6 +111,10 @@ impl<'a, 'py> PyAnyWrapper<'a, 'py> {
                 ..
             } => match collection_typ {
                 CollectionType::List(_) => {
+                    if let Ok(drained) = self.cast::<DrainedIterator>() {
+                        return drained.get().serialize(typ, cell_writer);
+                    }
+
                     let list = PyListWrapper::new(self).map_err(SerializationError::from)?;
 
                     list.serialize(typ, cell_writer)
@@ -80,6 +127,10 @@ impl<'a, 'py> PyAnyWrapper<'a, 'py> {
                 }
 
                 CollectionType::Set(_) => {
+                    if let Ok(drained) = self.cast::<DrainedIterator>() {
+                        return drained.get().serialize(typ, cell_writer);
+                    }
+
                     let set = PySetWrapper::new(self).map_err(SerializationError::from)?;
 
                     set.serialize(typ, cell_writer)
@@ -136,16 +187,31 @@ impl<'a, 'py> PyAnyWrapper<'a, 'py> {
     ) -> Result<WrittenCellProof<'b>, SerializationError> {
         match native_type {
             // Integer types.
-            NativeType::TinyInt => self.serialize_int::<i8>(typ, cell_writer),
-            NativeType::SmallInt => self.serialize_int::<i16>(typ, cell_writer),
-            NativeType::Int => self.serialize_int::<i32>(typ, cell_writer),
-            NativeType::BigInt => self.serialize_int::<i64>(typ, cell_writer),
+            NativeType::TinyInt => match self.cast::<CqlTinyInt>() {
+                Ok(v) => v.get().value.serialize(typ, cell_writer),
+                Err(_) => self.serialize_int::<i8>(typ, cell_writer),
+            },
+            NativeType::SmallInt => match self.cast::<CqlSmallInt>() {
+                Ok(v) => v.get().value.serialize(typ, cell_writer),
+                Err(_) => self.serialize_int::<i16>(typ, cell_writer),
+            },
+            NativeType::Int => match self.cast::<CqlInt>() {
+                Ok(v) => v.get().value.serialize(typ, cell_writer),
+                Err(_) => self.serialize_int::<i32>(typ, cell_writer),
+            },
+            NativeType::BigInt => match self.cast::<CqlBigInt>() {
+                Ok(v) => v.get().value.serialize(typ, cell_writer),
+                Err(_) => self.serialize_int::<i64>(typ, cell_writer),
+            },
             NativeType::Counter => {
-                let value = self
-                    .cast::<PyInt>()
-                    .map_err(|_| self.mismatched_type_error::<Counter>(typ))?
-                    .extract::<i64>()
-                    .map_err(|_| DriverSerializationError::value_overflow())?;
+                let value = match self.cast::<CqlCounter>() {
+                    Ok(counter) => counter.get().value,
+                    Err(_) => self
+                        .cast::<PyInt>()
+                        .map_err(|_| self.mismatched_type_error::<Counter>(typ))?
+                        .extract::<i64>()
+                        .map_err(|_| integer_out_of_range::<i64>("counter", self))?,
+                };
 
                 let counter = Counter(value);
                 counter.serialize(typ, cell_writer)
@@ -155,7 +221,17 @@ impl<'a, 'py> PyAnyWrapper<'a, 'py> {
             // Float types.
             NativeType::Float => self.serialize_native::<f32>(typ, cell_writer),
             NativeType::Double => self.serialize_native::<f64>(typ, cell_writer),
-            NativeType::Decimal => self.serialize_native::<BigDecimal>(typ, cell_writer),
+            NativeType::Decimal => {
+                // `Decimal` NaN and infinities have no CQL representation.
+                if let Ok(is_finite) = self.call_method0("is_finite")
+                    && !is_finite.is_truthy().unwrap_or(true)
+                {
+                    let value = self.str().map(|s| s.to_string()).unwrap_or_default();
+                    return Err(DriverSerializationError::non_finite_decimal(value).into());
+                }
+
+                self.serialize_native::<BigDecimal>(typ, cell_writer)
+            }
 
             // Boolean type.
             NativeType::Boolean => self.serialize_native::<bool>(typ, cell_writer),
@@ -163,10 +239,25 @@ impl<'a, 'py> PyAnyWrapper<'a, 'py> {
             // Text types.
             // TODO: Python allows strings that are not valid in Rust, conversion to `&str` is fallible.
             // This case is currently ignored and should be handled in the future. See: #41
-            NativeType::Ascii | NativeType::Text => self.serialize_native::<&str>(typ, cell_writer),
+            NativeType::Ascii => {
+                let value = self
+                    .extract::<&str>()
+                    .map_err(|_| self.mismatched_type_error::<&str>(typ))?;
+
+                if let Some(index) = value.chars().position(|c| !c.is_ascii()) {
+                    return Err(DriverSerializationError::non_ascii_string(index).into());
+                }
+
+                value.serialize(typ, cell_writer)
+            }
+            NativeType::Text => self.serialize_native::<&str>(typ, cell_writer),
 
             // Binary data type.
             NativeType::Blob => {
+                if self.is_instance_of::<PyString>() {
+                    return Err(DriverSerializationError::str_for_blob().into());
+                }
+
                 let value = self
                     .cast::<PyBytes>()
                     .map_err(|_| self.mismatched_type_error::<PyBytes>(typ))?;
@@ -177,26 +268,77 @@ impl<'a, 'py> PyAnyWrapper<'a, 'py> {
             }
 
             // Datatime types.
-            NativeType::Date => self.serialize_native::<NaiveDate>(typ, cell_writer),
-            NativeType::Duration => {
-                let months = self
-                    .getattr("months")
-                    .and_then(|m| m.extract::<i32>())
-                    .map_err(|_| self.mismatched_type_error::<CqlDuration>(typ))?;
+            NativeType::Date => {
+                // CQL `date` spans far beyond the years 1-9999 of `datetime.date`,
+                // so an `int` of days since the Unix epoch is accepted as well.
+                // `bool` is a subclass of `int`, but `True` is not a date.
+                if let Some(days) = self
+                    .cast::<PyInt>()
+                    .ok()
+                    .filter(|_| !self.is_instance_of::<PyBool>())
+                {
+                    let days = days
+                        .extract::<i32>()
+                        .map_err(|_| DriverSerializationError::value_overflow())?;
+                    let date = CqlDate((i64::from(days) + DATE_EPOCH_OFFSET) as u32);
+                    return date.serialize(typ, cell_writer);
+                }
+
+                let value = self
+                    .extract_date()
+                    .ok_or_else(|| self.mismatched_type_error::<NaiveDate>(typ))?;
 
-                let days = self
-                    .getattr("days")
-                    .and_then(|m| m.extract::<i32>())
-                    .map_err(|_| self.mismatched_type_error::<CqlDuration>(typ))?;
+                value.serialize(typ, cell_writer)
+            }
+            NativeType::Duration => {
+                // `timedelta` has no months. It normalizes negative values to a negative
+                // `days` and positive `seconds`, while CQL requires all components of
+                // a duration to have the same sign, so the total is split again.
+                if let Ok(delta) = self.extract::<TimeDelta>() {
+                    let microseconds = delta
+                        .num_microseconds()
+                        .ok_or_else(|| SerializationError::new(ValueOverflow))?;
+                    let duration = CqlDuration {
+                        months: 0,
+                        days: (microseconds / MICROSECONDS_PER_DAY) as i32,
+                        nanoseconds: (microseconds % MICROSECONDS_PER_DAY) * 1000,
+                    };
+                    return duration.serialize(typ, cell_writer);
+                }
 
-                let microseconds = self
-                    .getattr("microseconds")
-                    .and_then(|m| m.extract::<i64>())
-                    .map_err(|_| self.mismatched_type_error::<CqlDuration>(typ))?;
+                // `relativedelta` normalizes its components, e.g. `months=14` is
+                // stored as `years=1, months=2` and `microseconds=1_500_000` as
+                // `seconds=1, microseconds=500000`, so they have to be combined back.
+                let component = |name: &str| {
+                    self.getattr(name)
+                        .and_then(|v| v.extract::<i64>())
+                        .map_err(|_| self.mismatched_type_error::<CqlDuration>(typ))
+                };
+                let overflow = || SerializationError::new(ValueOverflow);
+
+                let (years, months) = (component("years")?, component("months")?);
+                let months = years
+                    .checked_mul(12)
+                    .and_then(|m| m.checked_add(months))
+                    .and_then(|m| i32::try_from(m).ok())
+                    .ok_or_else(overflow)?;
+
+                let days = i32::try_from(component("days")?).map_err(|_| overflow())?;
+
+                let microseconds = [
+                    ("hours", 3_600_000_000),
+                    ("minutes", 60_000_000),
+                    ("seconds", 1_000_000),
+                ]
+                .into_iter()
+                .try_fold(component("microseconds")?, |acc, (name, scale)| {
+                    component(name)?
+                        .checked_mul(scale)
+                        .and_then(|v| v.checked_add(acc))
+                        .ok_or_else(overflow)
+                })?;
 
-                let nanoseconds = microseconds
-                    .checked_mul(1000)
-                    .ok_or_else(|| SerializationError::new(ValueOverflow))?;
+                let nanoseconds = microseconds.checked_mul(1000).ok_or_else(overflow)?;
 
                 let duration = CqlDuration {
                     months,
@@ -206,6 +348,22 @@ impl<'a, 'py> PyAnyWrapper<'a, 'py> {
                 duration.serialize(typ, cell_writer)
             }
             NativeType::Time => {
+                // `datetime.time` has only microsecond precision, so an `int` of
+                // nanoseconds since midnight is accepted for full CQL `time` precision.
+                // `bool` is a subclass of `int`, but `True` is not a time of day.
+                if let Some(nanoseconds) = self
+                    .cast::<PyInt>()
+                    .ok()
+                    .filter(|_| !self.is_instance_of::<PyBool>())
+                {
+                    let nanoseconds = nanoseconds
+                        .extract::<i64>()
+                        .ok()
+                        .filter(|n| (0..NANOSECONDS_PER_DAY).contains(n))
+                        .ok_or_else(DriverSerializationError::value_overflow)?;
+                    return CqlTime(nanoseconds).serialize(typ, cell_writer);
+                }
+
                 let value = self
                     .extract::<NaiveTime>()
                     .map_err(|_| self.mismatched_type_error::<CqlTime>(typ))?;
@@ -218,8 +376,8 @@ impl<'a, 'py> PyAnyWrapper<'a, 'py> {
             }
             NativeType::Timestamp => {
                 let value = self
-                    .extract::<DateTime<Utc>>()
-                    .map_err(|_| self.mismatched_type_error::<CqlTimestamp>(typ))?;
+                    .extract_timestamp()
+                    .ok_or_else(|| self.mismatched_type_error::<CqlTimestamp>(typ))?;
 
                 let timestamp: CqlTimestamp = value.into();
 
@@ -228,6 +386,14 @@ impl<'a, 'py> PyAnyWrapper<'a, 'py> {
 
             // IP address type.
             NativeType::Inet => {
+                // `ip_network` objects have no `packed` attribute, and would
+                // otherwise fail with a generic type mismatch.
+                if self.hasattr("netmask").unwrap_or(false)
+                    && self.hasattr("network_address").unwrap_or(false)
+                {
+                    return Err(DriverSerializationError::ip_network_for_inet().into());
+                }
+
                 let value = self
                     .getattr("packed")
                     .map_err(|_| self.mismatched_type_error::<IpAddr>(typ))?;
@@ -293,6 +459,69 @@ impl<'a, 'py> PyAnyWrapper<'a, 'py> {
         }
     }
 
+    /// Extracts a date from a `datetime.date` or any of its subclasses.
+    ///
+    /// Exact extraction is tried first; subclasses it rejects are read
+    /// component by component.
+    fn extract_date(&self) -> Option<NaiveDate> {
+        if let Ok(value) = self.extract::<NaiveDate>() {
+            return Some(value);
+        }
+        if !self.is_instance_of::<PyDate>() {
+            return None;
+        }
+
+        self.date_components()
+    }
+
+    /// Extracts a UTC timestamp from a timezone-aware `datetime.datetime`
+    /// or any of its subclasses, such as `pandas.Timestamp`.
+    ///
+    /// Values that exact extraction rejects (subclasses, or a `tzinfo` other
+    /// than `datetime.timezone.utc`) are read component by component and
+    /// shifted by their `utcoffset()`. Naive datetimes are rejected.
+    fn extract_timestamp(&self) -> Option<DateTime<Utc>> {
+        if let Ok(value) = self.extract::<DateTime<Utc>>() {
+            return Some(value);
+        }
+        if !self.is_instance_of::<PyDateTime>() {
+            return None;
+        }
+
+        let offset = self.call_method0("utcoffset").ok()?;
+        if offset.is_none() {
+            return None;
+        }
+        let offset = offset.extract::<TimeDelta>().ok()?;
+
+        let time = NaiveTime::from_hms_micro_opt(
+            self.int_attr("hour")?,
+            self.int_attr("minute")?,
+            self.int_attr("second")?,
+            self.int_attr("microsecond")?,
+        )?;
+
+        self.date_components()?
+            .and_time(time)
+            .checked_sub_signed(offset)
+            .map(|local| local.and_utc())
+    }
+
+    fn date_components(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(
+            self.int_attr("year")?,
+            self.int_attr("month")?,
+            self.int_attr("day")?,
+        )
+    }
+
+    fn int_attr<T>(&self, name: &str) -> Option<T>
+    where
+        T: for<'x> pyo3::FromPyObject<'x, 'py>,
+    {
+        self.getattr(name).ok()?.extract::<T>().ok()
+    }
+
     fn serialize_native<'b, T>(
         &self,
         typ: &ColumnType,
@@ -312,14 +541,14 @@ impl<'a, 'py> PyAnyWrapper<'a, 'py> {
         cell_writer: CellWriter<'b>,
     ) -> Result<WrittenCellProof<'b>, SerializationError>
     where
-        T: pyo3::FromPyObject<'a, 'py> + SerializeValue,
+        T: pyo3::FromPyObject<'a, 'py> + SerializeValue + CqlInteger,
     {
         // The cast to `PyInt` is performed to distinguish between two different error cases:
         // `MismatchedType` and `ValueOverflow`.
         self.cast::<PyInt>()
             .map_err(|_| self.mismatched_type_error::<T>(typ))?
             .extract::<T>()
-            .map_err(|_| DriverSerializationError::value_overflow())?
+            .map_err(|_| integer_out_of_range::<T>(T::CQL_NAME, self))?
             .serialize(typ, cell_writer)
     }
 
@@ -342,17 +571,25 @@ impl<'a, 'py> PyAnyWrapper<'a, 'py> {
 
         let columns = match name {
             "int" => INT_COLUMNS,
+            "CqlCounter" => COUNTER_COLUMNS,
+            "CqlTinyInt" => TINYINT_COLUMNS,
+            "CqlSmallInt" => SMALLINT_COLUMNS,
+            "CqlInt" => CQL_INT_COLUMNS,
+            "CqlBigInt" => BIGINT_COLUMNS,
             "float" => FLOAT_COLUMNS,
             "bool" => BOOL_COLUMNS,
             "str" => STRING_COLUMNS,
             "bytes" => BYTES_COLUMNS,
             "Decimal" => DECIMAL_COLUMNS,
             "relativedelta" => RELATIVEDELTA_COLUMNS,
-            "datetime" => DATETIME_COLUMNS,
+            "date" | "datetime" => DATETIME_COLUMNS,
             "IPv4Address" | "IPv6Address" => IP_COLUMNS,
             "time" => TIME_COLUMNS,
             "UUID" => UUID_COLUMNS,
 
+            // Subclasses such as `pandas.Timestamp` serialize like their base type.
+            _ if self.is_instance_of::<PyDate>() => DATETIME_COLUMNS,
+
             _ => {
                 return Err(DriverSerializationError::unsupported_type(name.to_string()).into());
             }
@@ -380,6 +617,11 @@ impl<'a, 'py> SerializeValue for PyAnyWrapper<'a, 'py> {
             return Ok(cell_writer.set_null());
         }
 
+        // Exact values carry their CQL type and are checked by the Rust driver.
+        if let Ok(value) = self.cast::<PyCqlValue>() {
+            return value.get().inner.serialize(typ, cell_writer);
+        }
+
         self.serialize_arbitrary_value(typ, cell_writer)
     }
 }
@@ -400,9 +642,7 @@ fn serialize_sequence<'t, 'b, 'py, T: Any>(
 
     let mut builder = writer.into_value_builder();
 
-    let element_count: i32 = len
-        .try_into()
-        .map_err(|_| mk_ser_err::<T>(typ, SetOrListSerializationErrorKind::TooManyElements))?;
+    let element_count = collection_element_count(len)?;
     builder.append_bytes(&element_count.to_be_bytes());
 
     for el in iter {
@@ -418,7 +658,7 @@ fn serialize_sequence<'t, 'b, 'py, T: Any>(
 
     builder
         .finish()
-        .map_err(|_| mk_ser_err::<T>(typ, BuiltinSerializationErrorKind::SizeOverflow))
+        .map_err(|_| DriverSerializationError::value_too_large().into())
 }
 
 pub fn serialize_vector<'t, 'b, 'py, T: Any>(
@@ -463,7 +703,7 @@ pub fn serialize_vector<'t, 'b, 'py, T: Any>(
 
     builder
         .finish()
-        .map_err(|_| mk_ser_err::<T>(typ, BuiltinSerializationErrorKind::SizeOverflow))
+        .map_err(|_| DriverSerializationError::value_too_large().into())
 }
 
 #[derive(Debug)]
@@ -531,6 +771,45 @@ impl<'a, 'py> SerializeValue for PySetWrapper<'a, 'py> {
     }
 }
 
+/// Elements of a Python iterator (e.g. a generator) bound to a `list` or `set` column.
+///
+/// Bound values may be serialized more than once, e.g. for each page of a paged
+/// request or for a retry, so iterators are drained into this once, when the bound
+/// values are extracted, rather than each time they are serialized.
+#[pyclass(frozen)]
+pub(crate) struct DrainedIterator(Py<PyList>);
+
+impl DrainedIterator {
+    /// Drains `value` if it is an iterator, returning `None` otherwise.
+    pub(crate) fn drain<'py>(value: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let Ok(iterator) = value.cast::<PyIterator>() else {
+            return Ok(None);
+        };
+        let elements = iterator
+            .clone()
+            .collect::<PyResult<Vec<_>>>()
+            .map_err(|err| PyErr::from(DriverSerializationError::python_interop_failed(err)))?;
+        let elements = PyList::new(value.py(), elements)?;
+        Ok(Some(
+            Bound::new(value.py(), DrainedIterator(elements.unbind()))?.into_any(),
+        ))
+    }
+}
+
+impl SerializeValue for DrainedIterator {
+    fn serialize<'b>(
+        &self,
+        typ: &ColumnType,
+        cell_writer: CellWriter<'b>,
+    ) -> Result<WrittenCellProof<'b>, SerializationError> {
+        Python::attach(|py| {
+            let items = self.0.bind(py).iter();
+
+            serialize_sequence::<PyIterator>(items.len(), items, typ, cell_writer)
+        })
+    }
+}
+
 struct PyMapWrapper<'a, 'py>(&'a Bound<'py, PyMapping>);
 
 impl<'a, 'py> Deref for PyMapWrapper<'a, 'py> {
@@ -570,9 +849,7 @@ impl<'a, 'py> SerializeValue for PyMapWrapper<'a, 'py> {
             .items()
             .map_err(DriverSerializationError::python_interop_failed)?;
 
-        let element_count: i32 = items.len().try_into().map_err(|_| {
-            mk_ser_err::<PyMapping>(typ, MapSerializationErrorKind::TooManyElements)
-        })?;
+        let element_count = collection_element_count(items.len())?;
         builder.append_bytes(&element_count.to_be_bytes());
 
         for pair in items {
@@ -597,7 +874,7 @@ impl<'a, 'py> SerializeValue for PyMapWrapper<'a, 'py> {
 
         builder
             .finish()
-            .map_err(|_| mk_ser_err::<PyMapping>(typ, BuiltinSerializationErrorKind::SizeOverflow))
+            .map_err(|_| DriverSerializationError::value_too_large().into())
     }
 }
 
@@ -759,7 +1036,7 @@ impl<'py> SerializeValue for PyUdtWrapper<'py, '_> {
 
         builder
             .finish()
-            .map_err(|_| mk_ser_err::<CqlValue>(typ, BuiltinSerializationErrorKind::SizeOverflow))
+            .map_err(|_| DriverSerializationError::value_too_large().into())
     }
 }
 
@@ -795,7 +1072,7 @@ impl<'py, 'a> PyTupleWrapper<'py, 'a> {
 impl<'py, 'a> SerializeValue for PyTupleWrapper<'py, 'a> {
     fn serialize<'b>(
         &self,
-        typ: &ColumnType,
+        _typ: &ColumnType,
         cell_writer: CellWriter<'b>,
     ) -> Result<WrittenCellProof<'b>, SerializationError> {
         let mut builder = cell_writer.into_value_builder();
@@ -807,7 +1084,7 @@ impl<'py, 'a> SerializeValue for PyTupleWrapper<'py, 'a> {
 
         builder
             .finish()
-            .map_err(|_| mk_ser_err::<CqlValue>(typ, BuiltinSerializationErrorKind::SizeOverflow))
+            .map_err(|_| DriverSerializationError::value_too_large().into())
     }
 }
 
@@ -820,8 +1097,21 @@ static INT_COLUMNS: &[ColumnType<'static>] = &[
     ColumnType::Native(NativeType::BigInt),
     ColumnType::Native(NativeType::Counter),
     ColumnType::Native(NativeType::Varint),
+    ColumnType::Native(NativeType::Date),
+    ColumnType::Native(NativeType::Time),
 ];
 
+// List of CQL column types used to provide clear error messages
+// indicating which CQL types are compatible with `CqlCounter` type.
+static COUNTER_COLUMNS: &[ColumnType<'static>] = &[ColumnType::Native(NativeType::Counter)];
+
+// Lists of CQL column types used to provide clear error messages
+// indicating which CQL types are compatible with the explicit integer width types.
+static TINYINT_COLUMNS: &[ColumnType<'static>] = &[ColumnType::Native(NativeType::TinyInt)];
+static SMALLINT_COLUMNS: &[ColumnType<'static>] = &[ColumnType::Native(NativeType::SmallInt)];
+static CQL_INT_COLUMNS: &[ColumnType<'static>] = &[ColumnType::Native(NativeType::Int)];
+static BIGINT_COLUMNS: &[ColumnType<'static>] = &[ColumnType::Native(NativeType::BigInt)];
+
 // List of CQL column types used to provide clear error messages
 // indicating which CQL types are compatible with Python `float` type.
 static FLOAT_COLUMNS: &[ColumnType<'static>] = &[
@@ -887,6 +1177,12 @@ fn mk_typck_err<T: ?Sized>(
     })
 }
 
+/// Converts a collection length into the `i32` element count used by the CQL format.
+pub(crate) fn collection_element_count(len: usize) -> Result<i32, DriverSerializationError> {
+    len.try_into()
+        .map_err(|_| DriverSerializationError::too_many_elements(len))
+}
+
 fn mk_ser_err<T: ?Sized>(
     got: &ColumnType,
     kind: impl Into<BuiltinSerializationErrorKind>,
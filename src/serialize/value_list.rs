@@ -4,10 +4,10 @@ use std::any::Any;
 
 use pyo3::exceptions::{PyKeyError, PyTypeError};
 use pyo3::prelude::*;
-use pyo3::types::{PyList, PyMapping, PySequence, PyTuple};
+use pyo3::types::{PyDict, PyIterator, PyList, PyMapping, PySequence, PyString, PyTuple};
 use pyo3::{Bound, BoundObject, Py, PyAny};
 
-use scylla::frame::response::result::ColumnSpec;
+use scylla::frame::response::result::{ColumnSpec, ColumnType, NativeType};
 use scylla::serialize::SerializationError;
 use scylla::serialize::row::{
     BuiltinTypeCheckError, BuiltinTypeCheckErrorKind, RowSerializationContext, SerializeRow,
@@ -15,16 +15,57 @@ use scylla::serialize::row::{
 use scylla::serialize::value::SerializeValue;
 use scylla::serialize::writers::{RowWriter, WrittenCellProof};
 
-use crate::serialize::value::PyAnyWrapper;
+use crate::serialize::value::{DrainedIterator, PyAnyWrapper};
+use crate::types::UnsetType;
+
+/// Adjustments applied to the bound values of a row when serializing it.
+///
+/// Values nested in collections, tuples and UDTs are not affected.
+#[derive(Default, Clone, Copy)]
+pub(crate) struct ValueListOptions {
+    /// Empty strings bound to `ascii` and `text` columns are written as nulls.
+    empty_string_as_null: bool,
+    /// `None` is written as unset, leaving the column untouched, instead of as null.
+    none_as_unset: bool,
+}
 
 #[derive(Default, Clone)]
 pub(crate) enum PyValueList {
-    Sequence(Py<PySequence>),
-    Mapping(Py<PyMapping>),
+    Sequence {
+        values: Py<PySequence>,
+        options: ValueListOptions,
+    },
+    Mapping {
+        values: Py<PyMapping>,
+        options: ValueListOptions,
+    },
     #[default]
     Empty,
 }
 
+impl PyValueList {
+    /// Makes empty strings bound to `ascii` and `text` columns serialize as nulls
+    /// when `enabled`. Values nested in collections, tuples and UDTs are not affected.
+    pub(crate) fn with_empty_string_as_null(self, enabled: bool) -> Self {
+        self.map_options(|options| options.empty_string_as_null = enabled)
+    }
+
+    /// Makes `None` values serialize as unset instead of null when `enabled`,
+    /// so that they do not create tombstones. Values nested in collections,
+    /// tuples and UDTs are not affected.
+    pub(crate) fn with_none_as_unset(self, enabled: bool) -> Self {
+        self.map_options(|options| options.none_as_unset = enabled)
+    }
+
+    fn map_options(mut self, f: impl FnOnce(&mut ValueListOptions)) -> Self {
+        match &mut self {
+            Self::Sequence { options, .. } | Self::Mapping { options, .. } => f(options),
+            Self::Empty => {}
+        }
+        self
+    }
+}
+
 impl SerializeRow for PyValueList {
     fn serialize(
         &self,
@@ -32,8 +73,12 @@ impl SerializeRow for PyValueList {
         row_writer: &mut RowWriter,
     ) -> Result<(), SerializationError> {
         Python::attach(|py| match self {
-            Self::Sequence(sequence) => serialize_sequence(sequence.bind(py), ctx, row_writer),
-            Self::Mapping(mapping) => serialize_mapping(mapping.bind(py), ctx, row_writer),
+            Self::Sequence { values, options } => {
+                serialize_sequence(values.bind(py), *options, ctx, row_writer)
+            }
+            Self::Mapping { values, options } => {
+                serialize_mapping(values.bind(py), *options, ctx, row_writer)
+            }
             Self::Empty => {
                 if ctx.columns().is_empty() {
                     Ok(())
@@ -70,14 +115,20 @@ impl<'a, 'py> FromPyObject<'a, 'py> for PyValueList {
             if sequence.len() == 0 {
                 return Ok(Self::Empty);
             }
-            return Ok(Self::Sequence(sequence.as_sequence().to_owned().unbind()));
+            return Ok(Self::Sequence {
+                values: drain_sequence_iterators(sequence.as_sequence())?.unbind(),
+                options: ValueListOptions::default(),
+            });
         }
 
         if let Ok(sequence) = val.cast::<PyTuple>() {
             if sequence.len() == 0 {
                 return Ok(Self::Empty);
             }
-            return Ok(Self::Sequence(sequence.as_sequence().to_owned().unbind()));
+            return Ok(Self::Sequence {
+                values: drain_sequence_iterators(sequence.as_sequence())?.unbind(),
+                options: ValueListOptions::default(),
+            });
         }
 
         if let Ok(mapping) = val.cast::<PyMapping>() {
@@ -85,7 +136,10 @@ impl<'a, 'py> FromPyObject<'a, 'py> for PyValueList {
             if mapping.len().map(|len| len == 0).unwrap_or(false) {
                 return Ok(Self::Empty);
             }
-            return Ok(Self::Mapping(mapping.unbind()));
+            return Ok(Self::Mapping {
+                values: drain_mapping_iterators(&mapping)?.unbind(),
+                options: ValueListOptions::default(),
+            });
         }
 
         let python_type_name = val.get_type().name()?;
@@ -98,6 +152,58 @@ impl<'a, 'py> FromPyObject<'a, 'py> for PyValueList {
     }
 }
 
+/// Returns `values` with its iterators drained, see [`DrainedIterator`].
+/// `values` itself is returned when it holds no iterators, without copying it.
+fn drain_sequence_iterators<'py>(
+    values: &Bound<'py, PySequence>,
+) -> PyResult<Bound<'py, PySequence>> {
+    let mut drained: Option<Vec<Bound<'py, PyAny>>> = None;
+    for (index, item) in values.try_iter()?.enumerate() {
+        let item = item?;
+        let drained_item = DrainedIterator::drain(&item)?;
+        if drained.is_none() && drained_item.is_some() {
+            // The items before the first iterator are kept as they are.
+            let mut items = Vec::with_capacity(values.len()?);
+            for i in 0..index {
+                items.push(values.get_item(i)?);
+            }
+            drained = Some(items);
+        }
+        if let Some(items) = &mut drained {
+            items.push(drained_item.unwrap_or(item));
+        }
+    }
+
+    match drained {
+        Some(items) => Ok(PyList::new(values.py(), items)?.into_sequence()),
+        None => Ok(values.clone()),
+    }
+}
+
+/// Returns `values` with its iterators drained, see [`DrainedIterator`].
+/// `values` itself is returned when it holds no iterators, without copying it.
+fn drain_mapping_iterators<'py>(values: &Bound<'py, PyMapping>) -> PyResult<Bound<'py, PyMapping>> {
+    let is_iterator = |value: &Bound<'py, PyAny>| value.cast::<PyIterator>().is_ok();
+    // A dict is checked in place, `items()` would copy it into a list.
+    let has_iterators = match values.as_any().cast::<PyDict>() {
+        Ok(dict) => dict.iter().any(|(_, value)| is_iterator(&value)),
+        Err(_) => values
+            .items()?
+            .iter()
+            .any(|item| matches!(item.get_item(1), Ok(value) if is_iterator(&value))),
+    };
+    if !has_iterators {
+        return Ok(values.clone());
+    }
+
+    let drained = PyDict::new(values.py());
+    for item in values.items()?.iter() {
+        let (key, value) = item.extract::<(Bound<PyAny>, Bound<PyAny>)>()?;
+        drained.set_item(key, DrainedIterator::drain(&value)?.unwrap_or(value))?;
+    }
+    Ok(drained.into_mapping())
+}
+
 fn length_equality_check<T: Any>(
     val_list_len: usize,
     cols_len: usize,
@@ -117,15 +223,37 @@ fn length_equality_check<T: Any>(
 fn serialize_element<'a>(
     col: &ColumnSpec,
     val: &Bound<PyAny>,
+    options: ValueListOptions,
     row_writer: &'a mut RowWriter<'_>,
 ) -> Result<WrittenCellProof<'a>, SerializationError> {
-    let wrapper = PyAnyWrapper::new(val);
     let sub_writer = row_writer.make_cell_writer();
+
+    // `Unset` leaves the column untouched, while `None` writes a null (a tombstone)
+    // unless `none_as_unset` is enabled. Unset is only meaningful for whole bound values,
+    // not inside collections or UDTs.
+    if val.is_instance_of::<UnsetType>() || (options.none_as_unset && val.is_none()) {
+        return Ok(sub_writer.set_unset());
+    }
+
+    if options.empty_string_as_null
+        && matches!(
+            col.typ(),
+            ColumnType::Native(NativeType::Ascii | NativeType::Text)
+        )
+        && val
+            .cast::<PyString>()
+            .is_ok_and(|s| s.to_str().is_ok_and(str::is_empty))
+    {
+        return Ok(sub_writer.set_null());
+    }
+
+    let wrapper = PyAnyWrapper::new(val);
     SerializeValue::serialize(&wrapper, col.typ(), sub_writer)
 }
 
 fn serialize_sequence<'py>(
     value_list: &Bound<'py, PySequence>,
+    options: ValueListOptions,
     ctx: &RowSerializationContext<'_>,
     row_writer: &mut RowWriter,
 ) -> Result<(), SerializationError> {
@@ -141,7 +269,7 @@ fn serialize_sequence<'py>(
 
     for (index, (col, val)) in ctx.columns().iter().zip(iter).enumerate() {
         let val = val.map_err(DriverSerializationError::python_interop_failed)?;
-        serialize_element(col, &val, row_writer).map_err(|err| {
+        serialize_element(col, &val, options, row_writer).map_err(|err| {
             DriverSerializationError::scylla_serialize_failed(err).at_parameter_index(index)
         })?;
     }
@@ -151,6 +279,7 @@ fn serialize_sequence<'py>(
 
 fn serialize_mapping<'py>(
     value_list: &Bound<'py, PyMapping>,
+    options: ValueListOptions,
     ctx: &RowSerializationContext<'_>,
     row_writer: &mut RowWriter,
 ) -> Result<(), SerializationError> {
@@ -162,17 +291,22 @@ fn serialize_mapping<'py>(
 
     for col in ctx.columns().iter() {
         let item: Bound<PyAny> = value_list.get_item(col.name()).map_err(|e| {
-            if e.is_instance_of::<PyKeyError>(py) {
+            if !e.is_instance_of::<PyKeyError>(py) {
+                SerializationError::new(DriverSerializationError::python_interop_failed(e))
+            } else if let Some(key) = key_differing_in_case(value_list, col.name()) {
+                SerializationError::new(DriverSerializationError::name_case_mismatch(
+                    col.name(),
+                    key,
+                ))
+            } else {
                 mk_typck_err_val_list::<PyMapping>(
                     BuiltinTypeCheckErrorKind::ValueMissingForColumn {
                         name: col.name().into(),
                     },
                 )
-            } else {
-                SerializationError::new(DriverSerializationError::python_interop_failed(e))
             }
         })?;
-        serialize_element(col, &item, row_writer).map_err(|err| {
+        serialize_element(col, &item, options, row_writer).map_err(|err| {
             DriverSerializationError::scylla_serialize_failed(err).at_parameter_name(col.name())
         })?;
     }
@@ -180,6 +314,17 @@ fn serialize_mapping<'py>(
     Ok(())
 }
 
+/// Returns a `str` key of `mapping` that matches `name` except for letter case, if any.
+fn key_differing_in_case(mapping: &Bound<'_, PyMapping>, name: &str) -> Option<String> {
+    let name = name.to_lowercase();
+    mapping
+        .keys()
+        .ok()?
+        .iter()
+        .filter_map(|key| key.extract::<String>().ok())
+        .find(|key| key.to_lowercase() == name)
+}
+
 fn mk_typck_err_val_list<T>(kind: impl Into<BuiltinTypeCheckErrorKind>) -> SerializationError {
     SerializationError::new(BuiltinTypeCheckError {
         rust_name: std::any::type_name::<T>(),
@@ -1,43 +1,92 @@
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, OnceLock};
 
 use crate::RUNTIME;
 use crate::batch::PyBatch;
+use crate::cache::LruCache;
+use crate::cluster::metadata::ddl::{self, DescendingColumns};
 use crate::cluster::state::PyClusterState;
-use crate::deserialize::results::{Pager, PyPagingState, RequestResult, RowFactory};
+use crate::deserialize::results::{Pager, PyPagingState, RequestResult, RowFactory, query_hash};
+use crate::enums::PyAllowFilteringPolicy;
 use crate::errors::{
     DriverExecuteError, DriverPrepareError, DriverSchemaAgreementError,
-    DriverStatementConversionError, DriverUseKeyspaceError,
+    DriverStatementConversionError, DriverTracingError, DriverUseKeyspaceError,
+    DriverWaitForNodesError, NoRunningEventLoop, SessionShutDown,
 };
+use crate::routing::PyToken;
 use crate::serialize::value_list::PyValueList;
+use crate::session_builder::PyDuration;
 use crate::statement::PyPreparedStatement;
-use crate::statement::PyStatement;
+use crate::statement::{PyStatement, has_allow_filtering, has_mixed_bind_markers};
+use crate::tracing_info::PyTracingInfo;
+use crate::utils::{AbortOnDropHandle, ensure_event_loop_is_running, event_loop_is_running};
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use pyo3::sync::MutexExt;
-use pyo3::types::PyString;
+use pyo3::types::{PyDict, PyString};
+use scylla::client::execution_profile::ExecutionProfileHandle;
 use scylla::client::session::Session;
+use scylla::errors::{DbError, ExecutionError, RequestAttemptError};
 use scylla::response::query_result::QueryResult;
 use scylla::statement::batch::BatchStatement;
 use scylla::statement::prepared::PreparedStatement;
 use scylla::statement::unprepared::Statement;
 use scylla_cql::frame::request::query::{PagingState, PagingStateResponse};
+use std::collections::HashMap;
 use std::future::Future;
+use std::num::NonZeroUsize;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 
 #[pyclass(name = "Session", frozen, skip_from_py_object)]
 #[derive(Clone)]
 pub(crate) struct PySession {
-    pub(crate) _inner: Arc<Session>,
+    /// The Rust driver session, `None` once `shutdown` was called.
+    /// Shared with clones, such as the ones held by paged results.
+    _inner: Arc<Mutex<Option<Arc<Session>>>>,
     pub(crate) cluster_state: Arc<Mutex<Py<PyClusterState>>>,
+    /// Idempotence of executed statements that do not set it explicitly.
+    pub(crate) default_idempotence: bool,
+    /// Whether empty strings bound to text columns are written as nulls.
+    pub(crate) empty_string_as_null: bool,
+    /// What to do when an executed statement contains `ALLOW FILTERING`.
+    pub(crate) allow_filtering_policy: PyAllowFilteringPolicy,
+    /// Handle of the session's default execution profile, kept to read it after `shutdown`.
+    default_execution_profile_handle: ExecutionProfileHandle,
+    /// Recently prepared statements, see [`PreparedStatementKey`].
+    prepared_statements: Arc<LruCache<PreparedStatementKey, PreparedStatement>>,
+    /// Server information read from the system tables on first use.
+    server_info: Arc<OnceLock<ServerInfo>>,
+    /// Callable notified after each request, set with `set_request_hook`.
+    request_hook: Arc<Mutex<Option<Arc<Py<PyAny>>>>>,
 }
 
-impl TryFrom<Arc<Session>> for PySession {
-    type Error = PyErr;
-    fn try_from(_inner: Arc<Session>) -> Result<Self, Self::Error> {
+/// Settings of a `Session` implemented by this crate rather than by the Rust
+/// driver, configured with `SessionBuilder`.
+#[derive(Clone, Copy)]
+pub(crate) struct PySessionSettings {
+    pub(crate) default_idempotence: bool,
+    pub(crate) empty_string_as_null: bool,
+    pub(crate) allow_filtering_policy: PyAllowFilteringPolicy,
+    /// Capacity of the prepared statement cache.
+    pub(crate) prepared_statement_cache_size: usize,
+}
+
+impl PySession {
+    pub(crate) fn new(_inner: Arc<Session>, settings: PySessionSettings) -> PyResult<Self> {
         let cluster_state = Python::attach(|py| {
             Py::new(py, PyClusterState::try_from(_inner.get_cluster_state())?)
         })?;
         Ok(Self {
             cluster_state: Arc::new(Mutex::new(cluster_state)),
-            _inner,
+            default_execution_profile_handle: _inner.get_default_execution_profile_handle().clone(),
+            _inner: Arc::new(Mutex::new(Some(_inner))),
+            default_idempotence: settings.default_idempotence,
+            empty_string_as_null: settings.empty_string_as_null,
+            allow_filtering_policy: settings.allow_filtering_policy,
+            prepared_statements: Arc::new(LruCache::new(settings.prepared_statement_cache_size)),
+            server_info: Arc::new(OnceLock::new()),
+            request_hook: Arc::new(Mutex::new(None)),
         })
     }
 }
@@ -58,44 +107,162 @@ impl PySession {
         .await
     }
 
-    #[pyo3(signature = (statement, values=None, /, *, factory=None, paging_state=None, paged=true))]
+    #[pyo3(signature = (statement, values=None, /, *, factory=None, paging_state=None, paged=true, max_rows=None, retries=0))]
+    #[expect(clippy::too_many_arguments)]
     async fn execute(
         &self,
-        statement: ExecutableStatement,
+        statement: RequestStatement,
         values: Option<PyValueList>,
         factory: Option<Py<RowFactory>>,
         paging_state: Option<Py<PyPagingState>>,
         paged: bool,
+        max_rows: Option<usize>,
+        retries: u32,
     ) -> Result<RequestResult, DriverExecuteError> {
-        // Why not accept PyValueList instead of Option<PyValueList>?
-        // It would require us to use `Default::default` as default value in
-        // `pyo3(signature = ...)`, and thus use `text_signature` as well
-        // to keep signature usable for Python users. I think it is cleaner
-        // to `unwrap_or_default()` here.
-        let values = values.unwrap_or_default();
+        self.execute_request(
+            statement,
+            values,
+            factory,
+            paging_state,
+            paged,
+            max_rows,
+            retries,
+        )
+        .await
+    }
 
-        if paged {
-            self.execute_paged(statement, paging_state, values, factory)
-                .await
-        } else {
-            if paging_state.is_some() {
-                return Err(DriverExecuteError::paging_state_must_be_none_for_unpaged_execution());
+    /// Prepares `query` (reusing a statement prepared by an earlier call with
+    /// the same text) and executes it with `values`.
+    #[pyo3(signature = (query, values=None, /, *, factory=None, paging_state=None, paged=true, max_rows=None, retries=0))]
+    #[expect(clippy::too_many_arguments)]
+    async fn prepare_and_execute(
+        &self,
+        query: String,
+        values: Option<PyValueList>,
+        factory: Option<Py<RowFactory>>,
+        paging_state: Option<Py<PyPagingState>>,
+        paged: bool,
+        max_rows: Option<usize>,
+        retries: u32,
+    ) -> PyResult<RequestResult> {
+        let prepared = self.cached_prepare(query).await?;
+        let statement = RequestStatement {
+            statement: ExecutableStatement::Prepared(prepared),
+            is_serial_consistency_set: false,
+            is_idempotence_set: false,
+        };
+
+        Ok(self
+            .execute_request(
+                statement,
+                values,
+                factory,
+                paging_state,
+                paged,
+                max_rows,
+                retries,
+            )
+            .await?)
+    }
+
+    /// Executes the statement this session prepared with `statement_id`,
+    /// without holding its `PreparedStatement`. If the id is unknown to the
+    /// session, `query` is prepared instead (reusing a statement prepared by
+    /// `prepare_and_execute` with the same text).
+    #[pyo3(signature = (statement_id, values=None, /, *, query=None, factory=None, paging_state=None, paged=true, max_rows=None, retries=0))]
+    #[expect(clippy::too_many_arguments)]
+    async fn execute_by_id(
+        &self,
+        statement_id: Vec<u8>,
+        values: Option<PyValueList>,
+        query: Option<String>,
+        factory: Option<Py<RowFactory>>,
+        paging_state: Option<Py<PyPagingState>>,
+        paged: bool,
+        max_rows: Option<usize>,
+        retries: u32,
+    ) -> PyResult<RequestResult> {
+        let known = self
+            .prepared_statements
+            .get(&PreparedStatementKey::Id(statement_id.clone()));
+        let prepared = match (known, query) {
+            (Some(prepared), _) => prepared,
+            (None, Some(query)) => self.cached_prepare(query).await?,
+            (None, None) => {
+                return Err(DriverExecuteError::unknown_prepared_statement_id(statement_id).into());
             }
+        };
+        let statement = RequestStatement {
+            statement: ExecutableStatement::Prepared(prepared),
+            is_serial_consistency_set: false,
+            is_idempotence_set: false,
+        };
+
+        Ok(self
+            .execute_request(
+                statement,
+                values,
+                factory,
+                paging_state,
+                paged,
+                max_rows,
+                retries,
+            )
+            .await?)
+    }
 
-            self.execute_unpaged(statement, values, factory).await
+    /// Blocking variant of `execute`.
+    ///
+    /// Runs the request on the driver's runtime and blocks the calling thread
+    /// until it completes. The GIL is released while waiting.
+    #[pyo3(signature = (statement, values=None, /, *, factory=None, paging_state=None, paged=true, max_rows=None, retries=0))]
+    #[expect(clippy::too_many_arguments)]
+    fn execute_blocking(
+        &self,
+        py: Python<'_>,
+        statement: RequestStatement,
+        values: Option<PyValueList>,
+        factory: Option<Py<RowFactory>>,
+        paging_state: Option<Py<PyPagingState>>,
+        paged: bool,
+        max_rows: Option<usize>,
+        retries: u32,
+    ) -> Result<RequestResult, DriverExecuteError> {
+        if event_loop_is_running(py) {
+            return Err(DriverExecuteError::blocking_call_inside_event_loop());
         }
+
+        py.detach(|| {
+            RUNTIME.block_on(self.execute_request(
+                statement,
+                values,
+                factory,
+                paging_state,
+                paged,
+                max_rows,
+                retries,
+            ))
+        })
     }
 
-    async fn prepare(
+    /// Blocking variant of `prepare`. The GIL is released while waiting.
+    fn prepare_blocking(
         &self,
-        statement: ExecutableStatement,
+        py: Python<'_>,
+        statement: RequestStatement,
     ) -> Result<PyPreparedStatement, DriverPrepareError> {
-        match statement {
-            ExecutableStatement::Unprepared(s) => self.scylla_prepare(s).await,
-            ExecutableStatement::Prepared(_) => {
-                Err(DriverPrepareError::cannot_prepare_prepared_statement())
-            }
+        if event_loop_is_running(py) {
+            return Err(DriverPrepareError::blocking_call_inside_event_loop());
         }
+
+        py.detach(|| RUNTIME.block_on(self.prepare_request(statement)))
+    }
+
+    async fn prepare(
+        &self,
+        statement: RequestStatement,
+    ) -> Result<PyPreparedStatement, DriverPrepareError> {
+        self.prepare_request(statement).await
     }
 
     #[pyo3(signature = (batch, /, *,  factory=None))]
@@ -104,17 +271,177 @@ impl PySession {
         batch: PyBatch,
         factory: Option<Py<RowFactory>>,
     ) -> Result<RequestResult, DriverExecuteError> {
+        let batch = batch.with_default_idempotence(self.default_idempotence);
+        let hook = self.request_hook();
+        let query = hook.as_ref().map(|_| {
+            batch
+                ._inner
+                .statements
+                .iter()
+                .map(|statement| match statement {
+                    BatchStatement::Query(s) => s.contents.as_str(),
+                    BatchStatement::PreparedStatement(p) => p.get_statement(),
+                })
+                .collect::<Vec<_>>()
+                .join("; ")
+        });
+        let started = Instant::now();
+
+        let values: Vec<PyValueList> = batch
+            .values
+            .into_iter()
+            .map(|values| values.with_empty_string_as_null(self.empty_string_as_null))
+            .collect();
         let result = self
             .session_spawn_on_runtime(async move |s| {
-                s.batch(&batch._inner, batch.values)
+                s.batch(&batch._inner, values)
                     .await
                     .map_err(DriverExecuteError::rust_driver_execution_error)
             })
-            .await?;
+            .await;
+
+        if let (Some(hook), Some(query)) = (hook, query) {
+            notify_request_hook(&hook, query, started.elapsed(), result.is_ok());
+        }
+        let result = result?;
 
         Ok(RequestResult::new(result, Pager::unpaged(), factory))
     }
 
+    /// Executes `prepared` once for every row of `rows`, keeping at most
+    /// `concurrency` requests in flight, and reports which rows failed.
+    #[pyo3(signature = (prepared, rows, /, *, concurrency=NonZeroUsize::new(32).unwrap()))]
+    async fn insert_many(
+        &self,
+        prepared: Py<PyPreparedStatement>,
+        rows: Vec<PyValueList>,
+        concurrency: NonZeroUsize,
+    ) -> Result<PyInsertManyResult, DriverExecuteError> {
+        let prepared = prepared.get();
+        let hook = self.request_hook();
+        let query = hook
+            .as_ref()
+            .map(|_| prepared._inner.get_statement().to_owned());
+
+        if let Err(err) = self.check_allow_filtering(prepared._inner.get_statement()) {
+            // None of the rows is sent, so each of them is reported as failed.
+            if let (Some(hook), Some(query)) = (hook, query) {
+                for _ in 0..rows.len() {
+                    notify_request_hook(&hook, query.clone(), Duration::ZERO, false);
+                }
+            }
+            return Err(err);
+        }
+        let mut statement = prepared._inner.clone();
+        if self.default_idempotence && !prepared.is_idempotence_set {
+            statement.set_is_idempotent(true);
+        }
+
+        // Rows that cannot be serialized are reported without being sent.
+        let mut failures = Vec::new();
+        let total = rows.len();
+        let mut requests = Vec::with_capacity(total);
+        for (index, values) in rows.into_iter().enumerate() {
+            let values = values.with_empty_string_as_null(self.empty_string_as_null);
+            match statement.serialize_values_unstable(&values) {
+                Ok(serialized_values) => requests.push((index, serialized_values)),
+                Err(err) => failures.push((index, DriverExecuteError::serialization_failed(err))),
+            }
+        }
+
+        let executed = self
+            .session_spawn_on_runtime(async move |s| {
+                let started = Instant::now();
+                let permits = Arc::new(Semaphore::new(concurrency.get()));
+                // Dropping the set (e.g. on cancellation) aborts the requests still running.
+                let mut tasks = JoinSet::new();
+                let mut task_rows = HashMap::with_capacity(requests.len());
+                for (index, serialized_values) in requests {
+                    let permit = Arc::clone(&permits)
+                        .acquire_owned()
+                        .await
+                        .expect("semaphore is never closed");
+                    let (s, statement) = (Arc::clone(&s), statement.clone());
+                    let task = tasks.spawn(async move {
+                        let started = Instant::now();
+                        let result = s
+                            .execute_unstable(
+                                &statement,
+                                &serialized_values,
+                                false,
+                                PagingState::start(),
+                            )
+                            .await;
+                        drop(permit);
+                        (started.elapsed(), result.err())
+                    });
+                    task_rows.insert(task.id(), index);
+                }
+
+                let mut executed = Vec::with_capacity(task_rows.len());
+                while let Some(joined) = tasks.join_next_with_id().await {
+                    executed.push(match joined {
+                        Ok((id, (elapsed, err))) => (
+                            task_rows[&id],
+                            elapsed,
+                            err.map(DriverExecuteError::rust_driver_execution_error),
+                        ),
+                        // The set is not aborted here, so this is a request that panicked.
+                        Err(err) => (
+                            task_rows[&err.id()],
+                            started.elapsed(),
+                            Some(DriverExecuteError::runtime_task_join_failed(err)),
+                        ),
+                    });
+                }
+                Ok::<_, DriverExecuteError>(executed)
+            })
+            .await?;
+
+        if let (Some(hook), Some(query)) = (hook, query) {
+            // Rows that could not be serialized are reported as failed without a duration.
+            let mut reported = failures
+                .iter()
+                .map(|(index, _)| (*index, Duration::ZERO, false))
+                .chain(
+                    executed
+                        .iter()
+                        .map(|(index, elapsed, err)| (*index, *elapsed, err.is_none())),
+                )
+                .collect::<Vec<_>>();
+            reported.sort_unstable_by_key(|(index, _, _)| *index);
+            for (_, elapsed, succeeded) in reported {
+                notify_request_hook(&hook, query.clone(), elapsed, succeeded);
+            }
+        }
+
+        failures.extend(
+            executed
+                .into_iter()
+                .filter_map(|(index, _, err)| err.map(|err| (index, err))),
+        );
+        failures.sort_unstable_by_key(|(index, _)| *index);
+
+        Ok(Python::attach(|py| {
+            PyInsertManyResult::new(py, total, failures)
+        }))
+    }
+
+    /// Sets the callable notified after each request, or removes it if `None`.
+    #[pyo3(signature = (hook, /))]
+    fn set_request_hook(&self, py: Python<'_>, hook: Option<Py<PyAny>>) -> PyResult<()> {
+        if let Some(hook) = &hook
+            && !hook.bind(py).is_callable()
+        {
+            return Err(PyTypeError::new_err(
+                "Request hook must be callable or None",
+            ));
+        }
+
+        *self.request_hook.lock_py_attached(py).unwrap() = hook.map(Arc::new);
+        Ok(())
+    }
+
     async fn await_schema_agreement(&self) -> Result<uuid::Uuid, DriverSchemaAgreementError> {
         let schema_version = self
             .session_spawn_on_runtime(async move |s| {
@@ -127,6 +454,50 @@ impl PySession {
         Ok(schema_version)
     }
 
+    async fn wait_for_nodes(
+        &self,
+        count: usize,
+        timeout: PyDuration,
+    ) -> Result<(), DriverWaitForNodesError> {
+        let timeout = timeout.0;
+        self.session_spawn_on_runtime(async move |s| {
+            let deadline = tokio::time::Instant::now() + timeout;
+            loop {
+                let connected = s
+                    .get_cluster_state()
+                    .get_nodes_info()
+                    .iter()
+                    .filter(|node| node.is_connected() && node.is_enabled())
+                    .count();
+                if connected >= count {
+                    return Ok(());
+                }
+
+                let now = tokio::time::Instant::now();
+                if now >= deadline {
+                    return Err(DriverWaitForNodesError::timeout(count, connected, timeout));
+                }
+                tokio::time::sleep(WAIT_FOR_NODES_POLL_INTERVAL.min(deadline - now)).await;
+            }
+        })
+        .await
+    }
+
+    async fn get_tracing_info(
+        &self,
+        tracing_id: uuid::Uuid,
+    ) -> Result<PyTracingInfo, DriverTracingError> {
+        let tracing_info = self
+            .session_spawn_on_runtime(async move |s| {
+                s.get_tracing_info(&tracing_id)
+                    .await
+                    .map_err(DriverTracingError::rust_driver_tracing_error)
+            })
+            .await?;
+
+        Ok(PyTracingInfo::from(tracing_info))
+    }
+
     async fn check_schema_agreement(
         &self,
     ) -> Result<Option<uuid::Uuid>, DriverSchemaAgreementError> {
@@ -141,6 +512,118 @@ impl PySession {
         Ok(schema_version)
     }
 
+    /// Releases the Rust driver session. Later requests fail, while requests
+    /// already in flight complete; the connections are closed once they finish.
+    fn shutdown(&self, py: Python<'_>) {
+        let session = self._inner.lock_py_attached(py).unwrap().take();
+        // Dropping the last reference stops the driver's background tasks,
+        // which does not need the GIL.
+        py.detach(|| drop(session));
+    }
+
+    #[getter]
+    fn is_shut_down(&self) -> bool {
+        self.inner().is_err()
+    }
+
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    fn __exit__(
+        &self,
+        py: Python<'_>,
+        _exc_type: &Bound<'_, PyAny>,
+        _exc_value: &Bound<'_, PyAny>,
+        _traceback: &Bound<'_, PyAny>,
+    ) -> bool {
+        self.shutdown(py);
+        false
+    }
+
+    /// Returns the request timeout of the default execution profile in seconds,
+    /// or `None` if requests do not time out.
+    fn default_request_timeout(&self) -> Option<f64> {
+        self.default_execution_profile_handle
+            .to_profile()
+            .get_request_timeout()
+            .map(|timeout| timeout.as_secs_f64())
+    }
+
+    /// Returns the notable protocol options in use by this session.
+    ///
+    /// The Rust driver does not expose the protocol version or the compression
+    /// negotiated on each connection, so they are not reported.
+    fn protocol_features(&self) -> PyProtocolFeatures {
+        PyProtocolFeatures {
+            shard_aware: self.is_shard_aware(),
+        }
+    }
+
+    /// Returns the release version reported by the server.
+    async fn server_version(&self) -> Result<String, DriverExecuteError> {
+        Ok(self.server_info().await?.release_version)
+    }
+
+    /// Returns versions and features advertised by the server.
+    async fn supported_features(&self) -> PyResult<Py<PyDict>> {
+        let info = self.server_info().await?;
+        let shard_aware = self.is_shard_aware();
+
+        Python::attach(|py| {
+            let dict = PyDict::new(py);
+            dict.set_item("release_version", info.release_version)?;
+            dict.set_item("cql_version", info.cql_version)?;
+            dict.set_item("native_protocol_version", info.native_protocol_version)?;
+            dict.set_item("shard_aware", shard_aware)?;
+            dict.set_item("features", info.features)?;
+            Ok(dict.unbind())
+        })
+    }
+
+    /// Returns the per-range size estimates of the table from `system.size_estimates`.
+    ///
+    /// Each node reports estimates only for the ranges it owns, as computed
+    /// periodically by the server. The list is empty if none were computed yet.
+    async fn size_estimates(
+        &self,
+        keyspace: String,
+        table: String,
+    ) -> Result<Vec<PySizeEstimate>, DriverExecuteError> {
+        self.session_spawn_on_runtime(async move |s| {
+            PySizeEstimate::fetch(&s, &keyspace, &table).await
+        })
+        .await
+    }
+
+    /// Renders the keyspace, or its table `table`, as CQL DDL statements.
+    ///
+    /// Unlike `export_as_string` of the schema metadata, includes the
+    /// clustering order of tables, read from `system_schema.columns`.
+    /// Returns `None` if the keyspace or the table is not in the metadata.
+    #[pyo3(signature = (keyspace, table=None))]
+    async fn export_schema(
+        &self,
+        keyspace: String,
+        table: Option<String>,
+    ) -> Result<Option<String>, DriverExecuteError> {
+        self.session_spawn_on_runtime(async move |s| {
+            let descending = fetch_descending_columns(&s, &keyspace).await?;
+            let cluster_state = s.get_cluster_state();
+            let Some(metadata) = cluster_state.get_keyspace(&keyspace) else {
+                return Ok(None);
+            };
+
+            Ok(match table {
+                None => Some(ddl::export_keyspace(&keyspace, metadata, Some(&descending))),
+                Some(table) => metadata.tables.get(&table).map(|metadata| {
+                    ddl::create_table(&keyspace, &table, metadata, Some(&descending))
+                }),
+            })
+        })
+        .await
+    }
+
     #[getter]
     fn get_cluster_state<'py>(&self, py: Python<'py>) -> PyResult<Py<PyClusterState>> {
         // PyClusterState holds `Arc<ClusterState>` preventing Rust driver from replacing
@@ -150,14 +633,16 @@ impl PySession {
         // and `PyClusterState`'s internal `ClusterState` pointer
         // we can determine if the `PyClusterState`'s snapshot is stale
         // and needs to be replaced with a fresh snapshot.
+        //
+        // After `shutdown` the last snapshot is returned.
         let mut py_cluster_state = self.cluster_state.lock_py_attached(py).unwrap();
-        let rust_current_cluster_state = self._inner.get_cluster_state();
-        let python_snapshot_cluster_state = &py_cluster_state.get()._inner;
-        if !Arc::ptr_eq(&rust_current_cluster_state, python_snapshot_cluster_state) {
-            *py_cluster_state = Py::new(
-                py,
-                PyClusterState::try_from(self._inner.get_cluster_state())?,
-            )?;
+        if let Ok(session) = self.inner() {
+            let rust_current_cluster_state = session.get_cluster_state();
+            let python_snapshot_cluster_state = &py_cluster_state.get()._inner;
+            if !Arc::ptr_eq(&rust_current_cluster_state, python_snapshot_cluster_state) {
+                *py_cluster_state =
+                    Py::new(py, PyClusterState::try_from(rust_current_cluster_state)?)?;
+            }
         }
 
         Ok(py_cluster_state.clone_ref(py))
@@ -165,11 +650,134 @@ impl PySession {
 }
 
 impl PySession {
+    #[expect(clippy::too_many_arguments)]
+    async fn execute_request(
+        &self,
+        statement: RequestStatement,
+        values: Option<PyValueList>,
+        factory: Option<Py<RowFactory>>,
+        paging_state: Option<Py<PyPagingState>>,
+        paged: bool,
+        max_rows: Option<usize>,
+        retries: u32,
+    ) -> Result<RequestResult, DriverExecuteError> {
+        // Why not accept PyValueList instead of Option<PyValueList>?
+        // It would require us to use `Default::default` as default value in
+        // `pyo3(signature = ...)`, and thus use `text_signature` as well
+        // to keep signature usable for Python users. I think it is cleaner
+        // to `unwrap_or_default()` here.
+        let values = values
+            .unwrap_or_default()
+            .with_empty_string_as_null(self.empty_string_as_null);
+        let statement = statement.with_default_idempotence(self.default_idempotence);
+
+        let hook = self.request_hook();
+        let query = hook.as_ref().map(|_| statement.contents().to_owned());
+        let started = Instant::now();
+
+        // Requests rejected by these checks are reported to the hook as failed, too.
+        let result = async {
+            if retries > 0 && !statement.is_idempotent() {
+                return Err(DriverExecuteError::retries_require_idempotent_statement());
+            }
+
+            if paged && max_rows.is_some() {
+                return Err(DriverExecuteError::max_rows_must_be_none_for_paged_execution());
+            }
+            if !paged && paging_state.is_some() {
+                return Err(DriverExecuteError::paging_state_must_be_none_for_unpaged_execution());
+            }
+            self.check_allow_filtering(statement.contents())?;
+            if matches!(values, PyValueList::Mapping { .. })
+                && has_mixed_bind_markers(statement.contents())
+            {
+                return Err(DriverExecuteError::mapping_for_mixed_bind_markers());
+            }
+
+            if paged {
+                self.execute_paged(statement, paging_state, values, factory, retries)
+                    .await
+            } else {
+                self.execute_unpaged(statement, values, factory, max_rows, retries)
+                    .await
+            }
+        }
+        .await;
+
+        if let (Some(hook), Some(query)) = (hook, query) {
+            notify_request_hook(&hook, query, started.elapsed(), result.is_ok());
+        }
+        result
+    }
+
+    /// Applies the session's `AllowFilteringPolicy` to the statement text.
+    /// The check is textual, see `has_allow_filtering`.
+    fn check_allow_filtering(&self, query: &str) -> Result<(), DriverExecuteError> {
+        match self.allow_filtering_policy {
+            PyAllowFilteringPolicy::Off => Ok(()),
+            _ if !has_allow_filtering(query) => Ok(()),
+            PyAllowFilteringPolicy::Warn => {
+                log::warn!("Executing a statement with ALLOW FILTERING: {query}");
+                Ok(())
+            }
+            PyAllowFilteringPolicy::Error => {
+                Err(DriverExecuteError::allow_filtering_rejected(query))
+            }
+        }
+    }
+
+    /// Returns the Rust driver session, unless the session was shut down.
+    fn inner(&self) -> Result<Arc<Session>, SessionShutDown> {
+        self._inner.lock().unwrap().clone().ok_or(SessionShutDown)
+    }
+
+    /// Returns the request hook, if one is set. Cloning the `Arc` instead of
+    /// the `Py` does not need the GIL.
+    fn request_hook(&self) -> Option<Arc<Py<PyAny>>> {
+        self.request_hook.lock().unwrap().clone()
+    }
+
+    /// Prepares `statement`, keeping whether its serial consistency and
+    /// idempotence were set explicitly, as the prepared statement inherits both.
+    async fn prepare_request(
+        &self,
+        statement: RequestStatement,
+    ) -> Result<PyPreparedStatement, DriverPrepareError> {
+        match statement.statement {
+            ExecutableStatement::Unprepared(s) => Ok(PyPreparedStatement::new(
+                self.scylla_prepare(s).await?,
+                statement.is_serial_consistency_set,
+                statement.is_idempotence_set,
+            )),
+            ExecutableStatement::Prepared(_) => {
+                Err(DriverPrepareError::cannot_prepare_prepared_statement())
+            }
+        }
+    }
+
+    /// Returns the statement prepared for `query`, preparing and caching it
+    /// on first use.
+    async fn cached_prepare(&self, query: String) -> Result<PreparedStatement, DriverPrepareError> {
+        let key = PreparedStatementKey::Query(query.clone());
+        if let Some(prepared) = self.prepared_statements.get(&key) {
+            return Ok(prepared);
+        }
+
+        // Concurrent first calls may both prepare the statement; the server
+        // returns the same id, so whichever is cached last is as good.
+        let prepared = self.scylla_prepare(query).await?;
+        self.prepared_statements.insert(key, prepared.clone());
+
+        Ok(prepared)
+    }
+
     async fn execute_unpaged(
         &self,
         statement: ExecutableStatement,
         values: PyValueList,
         factory: Option<Py<RowFactory>>,
+        max_rows: Option<usize>,
+        retries: u32,
     ) -> Result<RequestResult, DriverExecuteError> {
         let result = match statement {
             ExecutableStatement::Prepared(p) => {
@@ -177,23 +785,43 @@ impl PySession {
                     .serialize_values_unstable(&values)
                     .map_err(DriverExecuteError::serialization_failed)?;
                 self.session_spawn_on_runtime(async move |s| {
-                    s.execute_unstable(&p, &serialized_values, false, PagingState::start())
-                        .await
-                        .map(|(result, _paging_response)| result)
-                        .map_err(DriverExecuteError::rust_driver_execution_error)
+                    retry_on_timeout(retries, || {
+                        let (s, p, serialized_values) =
+                            (Arc::clone(&s), p.clone(), serialized_values.clone());
+                        async move {
+                            s.execute_unstable(&p, &serialized_values, false, PagingState::start())
+                                .await
+                                .map(|(result, _paging_response)| result)
+                        }
+                    })
+                    .await
                 })
                 .await?
             }
             ExecutableStatement::Unprepared(q) => {
                 self.session_spawn_on_runtime(async move |s| {
-                    s.query_unpaged(q, values)
-                        .await
-                        .map_err(DriverExecuteError::rust_driver_execution_error)
+                    retry_on_timeout(retries, || {
+                        let (s, q, values) = (Arc::clone(&s), q.clone(), values.clone());
+                        async move { s.query_unpaged(q, values).await }
+                    })
+                    .await
                 })
                 .await?
             }
         };
 
+        // The whole response is already in memory at this point, but rows are
+        // only turned into Python objects lazily, so checking the count here
+        // still spares the user from materializing an accidental full scan.
+        if let Some(limit) = max_rows {
+            let rows_count = result
+                .deserialized_metadata_and_rows()
+                .map_or(0, |rows| rows.rows_count());
+            if rows_count > limit {
+                return Err(DriverExecuteError::row_limit_exceeded(limit, rows_count));
+            }
+        }
+
         Ok(RequestResult::new(result, Pager::unpaged(), factory))
     }
 
@@ -203,24 +831,58 @@ impl PySession {
         paging_state: Option<Py<PyPagingState>>,
         values: PyValueList,
         factory: Option<Py<RowFactory>>,
+        retries: u32,
     ) -> Result<RequestResult, DriverExecuteError> {
         let paging_state = if let Some(state) = paging_state {
-            Python::attach(|py| state.borrow(py).inner.clone())
+            let state = state.get();
+            if state
+                .query_hash
+                .is_some_and(|query_hash| query_hash != statement.query_hash())
+            {
+                return Err(DriverExecuteError::paging_state_of_other_statement());
+            }
+            state.inner.clone()
         } else {
             PagingState::start()
         };
 
         let (result, paging_response) = self
-            .execute_single_page(paging_state, statement.clone(), values.clone())
+            .execute_single_page(paging_state, statement.clone(), values.clone(), retries)
             .await?;
 
         Ok(RequestResult::new(
             result,
-            Pager::paged(paging_response, self.clone(), statement, values),
+            Pager::paged(paging_response, self.clone(), statement, values, retries),
             factory,
         ))
     }
 
+    /// Whether at least one known node uses the ScyllaDB shard-awareness extension.
+    fn is_shard_aware(&self) -> bool {
+        // A node has a sharder only if it advertised Scylla sharding info
+        // in SUPPORTED, i.e. connections to it are shard-aware.
+        self.inner().is_ok_and(|session| {
+            session
+                .get_cluster_state()
+                .get_nodes_info()
+                .iter()
+                .any(|node| node.sharder().is_some())
+        })
+    }
+
+    /// Returns the cached server information, querying the system tables on first use.
+    async fn server_info(&self) -> Result<ServerInfo, DriverExecuteError> {
+        if let Some(info) = self.server_info.get() {
+            return Ok(info.clone());
+        }
+
+        let info = self
+            .session_spawn_on_runtime(async move |s| ServerInfo::fetch(&s).await)
+            .await?;
+
+        Ok(self.server_info.get_or_init(|| info).clone())
+    }
+
     async fn session_spawn_on_runtime<F, Fut, R, E>(&self, f: F) -> Result<R, E>
     where
         // closure: takes Arc<ScyllaSession> and returns a future
@@ -228,22 +890,42 @@ impl PySession {
         // for spawn we need Send + 'static
         Fut: Future<Output = Result<R, E>> + Send + 'static,
         R: Send + 'static,
-        // Error: Send + 'static, and also convertible from JoinError for better error handling
-        E: From<tokio::task::JoinError> + Send + 'static,
+        // Error: Send + 'static, and also convertible from JoinError for better error handling,
+        // from SessionShutDown for requests made after `shutdown`
+        // and from NoRunningEventLoop for coroutines driven outside of asyncio
+        E: From<tokio::task::JoinError>
+            + From<SessionShutDown>
+            + From<NoRunningEventLoop>
+            + Send
+            + 'static,
     {
-        let session_clone = Arc::clone(&self._inner);
+        // Every request of a coroutine goes through here, so this is the one place
+        // to refuse being driven without an event loop, before anything is sent.
+        ensure_event_loop_is_running()?;
+        let session_clone = self.inner()?;
 
-        RUNTIME.spawn(async move { f(session_clone).await }).await?
+        // If the Python awaitable is cancelled, the future driving this call
+        // is dropped, and with it the spawned task is aborted.
+        AbortOnDropHandle(RUNTIME.spawn(async move { f(session_clone).await })).await?
     }
 
     async fn scylla_prepare(
         &self,
         statement: impl Into<Statement>,
-    ) -> Result<PyPreparedStatement, DriverPrepareError> {
-        match self._inner.prepare(statement).await {
-            Ok(prepared) => Ok(PyPreparedStatement::new(prepared, false)),
-            Err(err) => Err(DriverPrepareError::rust_driver_prepare_error(err)),
-        }
+    ) -> Result<PreparedStatement, DriverPrepareError> {
+        let statement: Statement = statement.into();
+        let prepared = self
+            .session_spawn_on_runtime(async move |s| {
+                s.prepare(statement)
+                    .await
+                    .map_err(DriverPrepareError::rust_driver_prepare_error)
+            })
+            .await?;
+        self.prepared_statements.insert(
+            PreparedStatementKey::Id(prepared.get_id().to_vec()),
+            prepared.clone(),
+        );
+        Ok(prepared)
     }
 
     pub(crate) async fn execute_single_page(
@@ -251,6 +933,7 @@ impl PySession {
         paging_state: PagingState,
         query_request: ExecutableStatement,
         values: PyValueList,
+        retries: u32,
     ) -> Result<(QueryResult, PagingStateResponse), DriverExecuteError> {
         match query_request {
             ExecutableStatement::Prepared(p) => {
@@ -258,17 +941,34 @@ impl PySession {
                     .serialize_values_unstable(&values)
                     .map_err(DriverExecuteError::serialization_failed)?;
                 self.session_spawn_on_runtime(async move |s| {
-                    s.execute_unstable(&p, &serialized_values, true, paging_state)
-                        .await
-                        .map_err(DriverExecuteError::rust_driver_execution_error)
+                    retry_on_timeout(retries, || {
+                        let (s, p, serialized_values, paging_state) = (
+                            Arc::clone(&s),
+                            p.clone(),
+                            serialized_values.clone(),
+                            paging_state.clone(),
+                        );
+                        async move {
+                            s.execute_unstable(&p, &serialized_values, true, paging_state)
+                                .await
+                        }
+                    })
+                    .await
                 })
                 .await
             }
             ExecutableStatement::Unprepared(q) => {
                 self.session_spawn_on_runtime(async move |s| {
-                    s.query_single_page(q, values, paging_state)
-                        .await
-                        .map_err(DriverExecuteError::rust_driver_execution_error)
+                    retry_on_timeout(retries, || {
+                        let (s, q, values, paging_state) = (
+                            Arc::clone(&s),
+                            q.clone(),
+                            values.clone(),
+                            paging_state.clone(),
+                        );
+                        async move { s.query_single_page(q, values, paging_state).await }
+                    })
+                    .await
                 })
                 .await
             }
@@ -276,6 +976,298 @@ impl PySession {
     }
 }
 
+/// Base delay between attempts made by `retry_on_timeout`, multiplied by the attempt number.
+const RETRY_BACKOFF: Duration = Duration::from_millis(100);
+pub(crate) const WAIT_FOR_NODES_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Number of entries kept in a session's prepared statement cache, unless
+/// configured with `SessionBuilder.prepared_statement_cache_size`.
+pub(crate) const DEFAULT_PREPARED_STATEMENT_CACHE_SIZE: usize = 512;
+
+/// Key of a statement in a session's prepared statement cache.
+///
+/// A statement prepared by `prepare_and_execute` is cached under both keys.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub(crate) enum PreparedStatementKey {
+    /// Query text of a statement prepared by `prepare_and_execute`.
+    Query(String),
+    /// Id assigned by the server to a statement prepared by the session,
+    /// looked up by `execute_by_id`.
+    Id(Vec<u8>),
+}
+
+/// Runs `attempt` up to `retries + 1` times, as long as it fails with a timeout
+/// or an `Unavailable` error, sleeping for a linearly growing backoff in between.
+///
+/// Must be called on the runtime (uses `tokio::time::sleep`). Callers are
+/// responsible for only using it with idempotent statements.
+async fn retry_on_timeout<R, Fut>(
+    retries: u32,
+    mut attempt: impl FnMut() -> Fut,
+) -> Result<R, DriverExecuteError>
+where
+    Fut: Future<Output = Result<R, ExecutionError>>,
+{
+    let mut attempts = 1;
+    loop {
+        match attempt().await {
+            Ok(result) => return Ok(result),
+            Err(err) if attempts <= retries && is_timeout_or_unavailable(&err) => {
+                tokio::time::sleep(RETRY_BACKOFF * attempts).await;
+                attempts += 1;
+            }
+            Err(err) if attempts > 1 => {
+                return Err(DriverExecuteError::retries_exhausted(attempts, err));
+            }
+            Err(err) => return Err(DriverExecuteError::rust_driver_execution_error(err)),
+        }
+    }
+}
+
+fn is_timeout_or_unavailable(err: &ExecutionError) -> bool {
+    match err {
+        ExecutionError::RequestTimeout(_) => true,
+        ExecutionError::LastAttemptError(RequestAttemptError::DbError(db_error, _)) => matches!(
+            db_error,
+            DbError::Unavailable { .. }
+                | DbError::ReadTimeout { .. }
+                | DbError::WriteTimeout { .. }
+        ),
+        _ => false,
+    }
+}
+
+/// Versions and features reported by the server in `system.local`.
+///
+/// The options advertised in the SUPPORTED response of the native protocol,
+/// such as ScyllaDB's protocol extensions, are read by the Rust driver when it
+/// opens a connection but are not exposed, so they are not part of it.
+#[derive(Clone)]
+pub(crate) struct ServerInfo {
+    release_version: String,
+    cql_version: Option<String>,
+    native_protocol_version: Option<String>,
+    /// ScyllaDB cluster features; empty for servers that do not report them.
+    features: Vec<String>,
+}
+
+impl ServerInfo {
+    async fn fetch(session: &Session) -> Result<Self, DriverExecuteError> {
+        let (release_version, cql_version, native_protocol_version) = session
+            .query_unpaged(
+                "SELECT release_version, cql_version, native_protocol_version \
+                 FROM system.local WHERE key = 'local'",
+                (),
+            )
+            .await
+            .map_err(DriverExecuteError::rust_driver_execution_error)?
+            .into_rows_result()
+            .map_err(DriverExecuteError::unexpected_system_table_result)?
+            .single_row::<(String, Option<String>, Option<String>)>()
+            .map_err(DriverExecuteError::unexpected_system_table_result)?;
+
+        // `supported_features` is a ScyllaDB-specific column.
+        let features = match session
+            .query_unpaged(
+                "SELECT supported_features FROM system.local WHERE key = 'local'",
+                (),
+            )
+            .await
+        {
+            Ok(result) => result
+                .into_rows_result()
+                .map_err(DriverExecuteError::unexpected_system_table_result)?
+                .single_row::<(Option<String>,)>()
+                .map_err(DriverExecuteError::unexpected_system_table_result)?
+                .0
+                .map(|features| {
+                    features
+                        .split(',')
+                        .filter(|feature| !feature.is_empty())
+                        .map(str::to_owned)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            Err(ExecutionError::LastAttemptError(RequestAttemptError::DbError(
+                DbError::Invalid,
+                _,
+            ))) => Vec::new(),
+            Err(err) => return Err(DriverExecuteError::rust_driver_execution_error(err)),
+        };
+
+        Ok(Self {
+            release_version,
+            cql_version,
+            native_protocol_version,
+            features,
+        })
+    }
+}
+
+/// Estimated size of a token range of a table, read from `system.size_estimates`.
+#[pyclass(get_all, name = "SizeEstimate", frozen, skip_from_py_object)]
+pub(crate) struct PySizeEstimate {
+    range_start: PyToken,
+    range_end: PyToken,
+    /// Mean size of a partition in the range, in bytes.
+    mean_partition_size: i64,
+    partitions_count: i64,
+}
+
+impl PySizeEstimate {
+    async fn fetch(
+        session: &Session,
+        keyspace: &str,
+        table: &str,
+    ) -> Result<Vec<Self>, DriverExecuteError> {
+        let rows_result = session
+            .query_unpaged(
+                "SELECT range_start, range_end, mean_partition_size, partitions_count \
+                 FROM system.size_estimates WHERE keyspace_name = ? AND table_name = ?",
+                (keyspace, table),
+            )
+            .await
+            .map_err(DriverExecuteError::rust_driver_execution_error)?
+            .into_rows_result()
+            .map_err(DriverExecuteError::unexpected_system_table_result)?;
+
+        let parse_token = |value: &str| {
+            value
+                .parse::<i64>()
+                .map(|value| PyToken::from(scylla::routing::Token::new(value)))
+                .map_err(|_| {
+                    DriverExecuteError::unexpected_system_table_result(format!(
+                        "range bound {value:?} is not a Murmur3 token"
+                    ))
+                })
+        };
+
+        rows_result
+            .rows::<(String, String, i64, i64)>()
+            .map_err(DriverExecuteError::unexpected_system_table_result)?
+            .map(|row| {
+                let (range_start, range_end, mean_partition_size, partitions_count) =
+                    row.map_err(DriverExecuteError::unexpected_system_table_result)?;
+                Ok(Self {
+                    range_start: parse_token(&range_start)?,
+                    range_end: parse_token(&range_end)?,
+                    mean_partition_size,
+                    partitions_count,
+                })
+            })
+            .collect()
+    }
+}
+
+#[pymethods]
+impl PySizeEstimate {
+    fn __repr__(&self) -> String {
+        format!(
+            "SizeEstimate(range_start={}, range_end={}, mean_partition_size={}, partitions_count={})",
+            self.range_start._inner.value(),
+            self.range_end._inner.value(),
+            self.mean_partition_size,
+            self.partitions_count
+        )
+    }
+}
+
+/// Reads the clustering columns of the keyspace's tables which are sorted
+/// in descending order from `system_schema.columns`.
+async fn fetch_descending_columns(
+    session: &Session,
+    keyspace: &str,
+) -> Result<DescendingColumns, DriverExecuteError> {
+    let rows_result = session
+        .query_unpaged(
+            "SELECT table_name, column_name, clustering_order \
+             FROM system_schema.columns WHERE keyspace_name = ?",
+            (keyspace,),
+        )
+        .await
+        .map_err(DriverExecuteError::rust_driver_execution_error)?
+        .into_rows_result()
+        .map_err(DriverExecuteError::unexpected_system_table_result)?;
+
+    let mut descending = DescendingColumns::new();
+    for row in rows_result
+        .rows::<(String, String, String)>()
+        .map_err(DriverExecuteError::unexpected_system_table_result)?
+    {
+        let (table, column, order) =
+            row.map_err(DriverExecuteError::unexpected_system_table_result)?;
+        if order == "desc" {
+            descending.entry(table).or_default().insert(column);
+        }
+    }
+    Ok(descending)
+}
+
+/// Calls a request hook with the query text, the duration of the request in
+/// seconds and whether it succeeded. Exceptions raised by the hook are logged
+/// and do not affect the request.
+fn notify_request_hook(hook: &Py<PyAny>, query: String, elapsed: Duration, succeeded: bool) {
+    Python::attach(|py| {
+        if let Err(err) = hook.call1(py, (query, elapsed.as_secs_f64(), succeeded)) {
+            log::error!("Request hook raised an exception: {}", err);
+        }
+    });
+}
+
+/// Protocol-level features in use by a `Session`.
+#[pyclass(name = "ProtocolFeatures", frozen)]
+pub(crate) struct PyProtocolFeatures {
+    #[pyo3(get)]
+    shard_aware: bool,
+}
+
+#[pymethods]
+impl PyProtocolFeatures {
+    fn __repr__(&self) -> String {
+        format!(
+            "ProtocolFeatures(shard_aware={})",
+            if self.shard_aware { "True" } else { "False" },
+        )
+    }
+}
+
+/// Outcome of `Session.insert_many`.
+#[pyclass(name = "InsertManyResult", frozen)]
+pub(crate) struct PyInsertManyResult {
+    #[pyo3(get)]
+    succeeded: usize,
+    /// `(row index, error)` pairs of the rows that were not inserted, ordered by index.
+    #[pyo3(get)]
+    failures: Vec<(usize, Py<PyAny>)>,
+}
+
+impl PyInsertManyResult {
+    fn new(py: Python<'_>, total: usize, failures: Vec<(usize, DriverExecuteError)>) -> Self {
+        Self {
+            succeeded: total - failures.len(),
+            failures: failures
+                .into_iter()
+                .map(|(index, err)| (index, PyErr::from(err).into_value(py).into_any()))
+                .collect(),
+        }
+    }
+}
+
+#[pymethods]
+impl PyInsertManyResult {
+    #[getter]
+    fn failed(&self) -> usize {
+        self.failures.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "InsertManyResult(succeeded={}, failed={})",
+            self.succeeded,
+            self.failures.len()
+        )
+    }
+}
+
 #[derive(Clone)]
 pub(crate) enum ExecutableStatement {
     Prepared(PreparedStatement),
@@ -313,6 +1305,78 @@ impl<'py> FromPyObject<'_, 'py> for ExecutableStatement {
     }
 }
 
+/// A statement passed to `execute` or `prepare`, remembering whether its
+/// idempotence was set explicitly, so that the session's default idempotence
+/// can be applied otherwise, and whether its serial consistency was set.
+pub(crate) struct RequestStatement {
+    pub(crate) statement: ExecutableStatement,
+    pub(crate) is_serial_consistency_set: bool,
+    pub(crate) is_idempotence_set: bool,
+}
+
+impl<'py> FromPyObject<'_, 'py> for RequestStatement {
+    type Error = DriverStatementConversionError;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let (is_serial_consistency_set, is_idempotence_set) =
+            if let Ok(prepared) = obj.cast::<PyPreparedStatement>() {
+                let prepared = prepared.get();
+                (
+                    prepared.is_serial_consistency_set,
+                    prepared.is_idempotence_set,
+                )
+            } else if let Ok(statement) = obj.cast::<PyStatement>() {
+                let statement = statement.get();
+                (
+                    statement.is_serial_consistency_set,
+                    statement.is_idempotence_set,
+                )
+            } else {
+                (false, false)
+            };
+
+        Ok(Self {
+            statement: obj.extract()?,
+            is_serial_consistency_set,
+            is_idempotence_set,
+        })
+    }
+}
+
+impl RequestStatement {
+    pub(crate) fn with_default_idempotence(self, default_idempotence: bool) -> ExecutableStatement {
+        let mut statement = self.statement;
+        if default_idempotence && !self.is_idempotence_set {
+            match &mut statement {
+                ExecutableStatement::Prepared(p) => p.set_is_idempotent(true),
+                ExecutableStatement::Unprepared(s) => s.set_is_idempotent(true),
+            }
+        }
+        statement
+    }
+}
+
+impl ExecutableStatement {
+    fn contents(&self) -> &str {
+        match self {
+            ExecutableStatement::Prepared(p) => p.get_statement(),
+            ExecutableStatement::Unprepared(s) => &s.contents,
+        }
+    }
+
+    fn is_idempotent(&self) -> bool {
+        match self {
+            ExecutableStatement::Prepared(p) => p.get_is_idempotent(),
+            ExecutableStatement::Unprepared(s) => s.get_is_idempotent(),
+        }
+    }
+
+    /// Hash of the statement text, binding paging states to the statement.
+    pub(crate) fn query_hash(&self) -> u32 {
+        query_hash(self.contents())
+    }
+}
+
 impl From<ExecutableStatement> for BatchStatement {
     fn from(s: ExecutableStatement) -> Self {
         match s {
@@ -325,6 +1389,9 @@ impl From<ExecutableStatement> for BatchStatement {
 #[pymodule]
 pub(crate) fn session(_py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<PySession>()?;
+    module.add_class::<PyProtocolFeatures>()?;
+    module.add_class::<PyInsertManyResult>()?;
+    module.add_class::<PySizeEstimate>()?;
 
     Ok(())
 }
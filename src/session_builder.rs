@@ -1,5 +1,8 @@
 use crate::RUNTIME;
-use crate::enums::{PyCompression, PyPoolSize, PySelfIdentity, PyWriteCoalescingDelay};
+use crate::enums::{
+    PyAllowFilteringPolicy, PyCompression, PyConsistency, PyPoolSize, PySelfIdentity,
+    PySerialConsistency, PyWriteCoalescingDelay,
+};
 use crate::errors::{DriverSessionConfigError, DriverSessionConnectionError};
 use crate::execution_profile::ExecutionProfile;
 use crate::policies::{
@@ -7,13 +10,19 @@ use crate::policies::{
     InternalTimestampGenerator, PyAddressTranslator, PyAuthenticatorProvider, PyHostFilter,
     PyTimestampGenerator,
 };
-use crate::session::PySession;
+use crate::session::{
+    DEFAULT_PREPARED_STATEMENT_CACHE_SIZE, PySession, PySessionSettings,
+    WAIT_FOR_NODES_POLL_INTERVAL,
+};
+use crate::utils::{ensure_event_loop_is_running, event_loop_is_running};
 use pyo3::prelude::*;
 use pyo3::sync::MutexExt;
 use pyo3::types::{PySequence, PyString};
 use scylla::authentication::PlainTextAuthenticator;
 use scylla::client::session::SessionConfig;
+use scylla::cluster::KnownNode;
 use scylla::routing::ShardAwarePortRange;
+use std::collections::{HashMap, HashSet};
 use std::convert::Infallible;
 use std::net::{IpAddr, SocketAddr};
 use std::ops::RangeInclusive;
@@ -57,12 +66,55 @@ impl SessionBuilder {
         {
             let mut inner = slf.inner.lock_py_attached(py).unwrap();
             inner.execution_profile = execution_profile.clone();
-            inner.config.default_execution_profile_handle =
-                execution_profile.get()._inner.clone().into_handle();
+            inner.config.default_execution_profile_handle = execution_profile.get().handle.clone();
         }
         slf
     }
 
+    /// Replaces the consistency of the default execution profile, keeping its other settings.
+    fn default_consistency<'py>(
+        slf: PyRef<'py, Self>,
+        py: Python<'py>,
+        consistency: PyConsistency,
+    ) -> PyResult<PyRef<'py, Self>> {
+        {
+            let mut inner = slf.inner.lock_py_attached(py).unwrap();
+            let handle = inner
+                .execution_profile
+                .get()
+                ._inner
+                .to_builder()
+                .consistency(consistency.into())
+                .build()
+                .into_handle();
+            inner.config.default_execution_profile_handle = handle.clone();
+            inner.execution_profile = Py::new(py, ExecutionProfile::from(handle))?;
+        }
+        Ok(slf)
+    }
+
+    /// Replaces the serial consistency of the default execution profile, keeping its other settings.
+    fn default_serial_consistency<'py>(
+        slf: PyRef<'py, Self>,
+        py: Python<'py>,
+        serial_consistency: Option<PySerialConsistency>,
+    ) -> PyResult<PyRef<'py, Self>> {
+        {
+            let mut inner = slf.inner.lock_py_attached(py).unwrap();
+            let handle = inner
+                .execution_profile
+                .get()
+                ._inner
+                .to_builder()
+                .serial_consistency(serial_consistency.map(Into::into))
+                .build()
+                .into_handle();
+            inner.config.default_execution_profile_handle = handle.clone();
+            inner.execution_profile = Py::new(py, ExecutionProfile::from(handle))?;
+        }
+        Ok(slf)
+    }
+
     fn user<'py>(
         slf: PyRef<'py, Self>,
         py: Python<'py>,
@@ -144,13 +196,17 @@ impl SessionBuilder {
     fn local_ip_address<'py>(
         slf: PyRef<'py, Self>,
         py: Python<'py>,
-        ip: Option<IpAddr>,
-    ) -> PyRef<'py, Self> {
+        ip: Option<Bound<'py, PyAny>>,
+    ) -> Result<PyRef<'py, Self>, DriverSessionConfigError> {
+        let ip = ip
+            .map(|ip| ip.extract::<IpAddr>())
+            .transpose()
+            .map_err(DriverSessionConfigError::invalid_local_ip_address)?;
         {
             let mut inner = slf.inner.lock_py_attached(py).unwrap();
             inner.config.local_ip_address = ip;
         }
-        slf
+        Ok(slf)
     }
 
     fn shard_aware_local_port_range<'py>(
@@ -160,10 +216,10 @@ impl SessionBuilder {
     ) -> Result<PyRef<'py, Self>, DriverSessionConfigError> {
         {
             let mut inner = slf.inner.lock_py_attached(py).unwrap();
-            inner.shard_aware_local_port_range = port_range;
             inner.config.shard_aware_local_port_range =
                 ShardAwarePortRange::new(RangeInclusive::new(port_range.0, port_range.1))
                     .map_err(|_| DriverSessionConfigError::InvalidPortRange)?;
+            inner.shard_aware_local_port_range = port_range;
         }
         Ok(slf)
     }
@@ -420,6 +476,78 @@ impl SessionBuilder {
         slf
     }
 
+    fn default_idempotence<'py>(
+        slf: PyRef<'py, Self>,
+        py: Python<'py>,
+        is_idempotent: bool,
+    ) -> PyRef<'py, Self> {
+        {
+            let mut inner = slf.inner.lock_py_attached(py).unwrap();
+            inner.default_idempotence = is_idempotent;
+        }
+        slf
+    }
+
+    fn prepared_statement_cache_size<'py>(
+        slf: PyRef<'py, Self>,
+        py: Python<'py>,
+        size: usize,
+    ) -> PyRef<'py, Self> {
+        {
+            let mut inner = slf.inner.lock_py_attached(py).unwrap();
+            inner.prepared_statement_cache_size = size;
+        }
+        slf
+    }
+
+    fn empty_string_as_null<'py>(
+        slf: PyRef<'py, Self>,
+        py: Python<'py>,
+        enabled: bool,
+    ) -> PyRef<'py, Self> {
+        {
+            let mut inner = slf.inner.lock_py_attached(py).unwrap();
+            inner.empty_string_as_null = enabled;
+        }
+        slf
+    }
+
+    fn allow_filtering_policy<'py>(
+        slf: PyRef<'py, Self>,
+        py: Python<'py>,
+        policy: PyAllowFilteringPolicy,
+    ) -> PyRef<'py, Self> {
+        {
+            let mut inner = slf.inner.lock_py_attached(py).unwrap();
+            inner.allow_filtering_policy = policy;
+        }
+        slf
+    }
+
+    fn connect_deadline<'py>(
+        slf: PyRef<'py, Self>,
+        py: Python<'py>,
+        deadline: Option<PyDuration>,
+    ) -> PyRef<'py, Self> {
+        {
+            let mut inner = slf.inner.lock_py_attached(py).unwrap();
+            inner.connect_deadline = deadline.map(|deadline| deadline.0);
+        }
+        slf
+    }
+
+    fn wait_for_node_connections<'py>(
+        slf: PyRef<'py, Self>,
+        py: Python<'py>,
+        enabled: bool,
+    ) -> PyRef<'py, Self> {
+        {
+            let mut inner = slf.inner.lock_py_attached(py).unwrap();
+            inner.wait_for_node_connections = enabled;
+        }
+        slf
+    }
+
     pub fn custom_identity<'py>(
         slf: PyRef<'py, Self>,
         py: Python<'py>,
@@ -437,20 +565,111 @@ impl SessionBuilder {
         Py::new(py, inner.clone())
     }
 
+    /// Blocking variant of `connect`. The GIL is released while waiting.
+    fn connect_blocking(&self, py: Python<'_>) -> Result<PySession, DriverSessionConnectionError> {
+        if event_loop_is_running(py) {
+            return Err(DriverSessionConnectionError::blocking_call_inside_event_loop());
+        }
+
+        py.detach(|| RUNTIME.block_on(connect_session(&self.inner)))
+    }
+
     async fn connect(&self) -> Result<PySession, DriverSessionConnectionError> {
-        let config = Python::attach(|py| {
-            let inner = self.inner.lock_py_attached(py).unwrap();
-            inner.config.clone()
+        connect_session(&self.inner).await
+    }
+}
+
+/// Connects a session configured by `builder_config`, shared by `connect` and `connect_blocking`.
+async fn connect_session(
+    builder_config: &Mutex<PySessionBuilderConfig>,
+) -> Result<PySession, DriverSessionConnectionError> {
+    ensure_event_loop_is_running()?;
+
+    let (mut config, contact_points, settings, wait_for_node_connections, connect_deadline) =
+        Python::attach(|py| {
+            let inner = builder_config.lock_py_attached(py).unwrap();
+            (
+                inner.config.clone(),
+                inner
+                    .contact_points
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+                PySessionSettings {
+                    default_idempotence: inner.default_idempotence,
+                    empty_string_as_null: inner.empty_string_as_null,
+                    allow_filtering_policy: inner.allow_filtering_policy,
+                    prepared_statement_cache_size: inner.prepared_statement_cache_size,
+                },
+                inner.wait_for_node_connections,
+                inner.connect_deadline,
+            )
         });
+    let node_connections_timeout = config.connect_timeout;
+
+    let session = RUNTIME
+        .spawn(async move {
+            // The deadline covers every step of `connect`: resolving the
+            // contact points, connecting and waiting for node connections.
+            let connect = async {
+                resolve_contact_points(&mut config).await;
+                let session = scylla::client::session::Session::connect(config)
+                    .await
+                    .map_err(|err| {
+                        DriverSessionConnectionError::new_session_error(err, contact_points.clone())
+                    })?;
+
+                if wait_for_node_connections {
+                    wait_until_nodes_connected(&session, node_connections_timeout).await;
+                }
+                Ok::<_, DriverSessionConnectionError>(session)
+            };
+
+            match connect_deadline {
+                Some(deadline) => tokio::time::timeout(deadline, connect)
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(DriverSessionConnectionError::connect_deadline_exceeded(
+                            contact_points.clone(),
+                            deadline,
+                        ))
+                    }),
+                None => connect.await,
+            }
+        })
+        .await??;
+
+    PySession::new(Arc::new(session), settings)
+        .map_err(DriverSessionConnectionError::python_conversion_error)
+}
 
-        let session_result = RUNTIME
-            .spawn(async move { scylla::client::session::Session::connect(config).await })
-            .await?;
-        match session_result {
-            Ok(session) => PySession::try_from(Arc::new(session))
-                .map_err(DriverSessionConnectionError::python_conversion_error),
-            Err(err) => Err(DriverSessionConnectionError::new_session_error(err)),
+/// Waits until every enabled node has at least one open connection, for at most `timeout`.
+///
+/// The Rust driver fills connection pools in the background after `connect` and
+/// does not expose how many connections a pool holds, so the remaining connections
+/// to the other shards of a node may still be opening. Waiting is best-effort:
+/// on timeout the session is returned anyway.
+async fn wait_until_nodes_connected(session: &scylla::client::session::Session, timeout: Duration) {
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        let cluster_state = session.get_cluster_state();
+        let pending = cluster_state
+            .get_nodes_info()
+            .iter()
+            .filter(|node| node.is_enabled() && !node.is_connected())
+            .count();
+        if pending == 0 {
+            return;
         }
+
+        let now = tokio::time::Instant::now();
+        if now >= deadline {
+            log::warn!(
+                "Waiting for node connections timed out after {timeout:?} with {pending} node(s) not connected"
+            );
+            return;
+        }
+        tokio::time::sleep(WAIT_FOR_NODES_POLL_INTERVAL.min(deadline - now)).await;
     }
 }
 
@@ -472,6 +691,18 @@ struct PySessionBuilderConfig {
     pub timestamp_generator: Option<Py<PyAny>>,
     #[pyo3(get)]
     pub shard_aware_local_port_range: (u16, u16),
+    #[pyo3(get)]
+    pub default_idempotence: bool,
+    #[pyo3(get)]
+    pub prepared_statement_cache_size: usize,
+    #[pyo3(get)]
+    pub empty_string_as_null: bool,
+    #[pyo3(get)]
+    pub allow_filtering_policy: PyAllowFilteringPolicy,
+    #[pyo3(get)]
+    pub wait_for_node_connections: bool,
+    #[pyo3(get)]
+    pub connect_deadline: Option<Duration>,
 }
 
 impl PySessionBuilderConfig {
@@ -480,9 +711,7 @@ impl PySessionBuilderConfig {
 
         let execution_profile = Py::new(
             py,
-            ExecutionProfile {
-                _inner: config.default_execution_profile_handle.to_profile(),
-            },
+            ExecutionProfile::from(config.default_execution_profile_handle.clone()),
         )?;
 
         Ok(Self {
@@ -494,6 +723,12 @@ impl PySessionBuilderConfig {
             authenticator: None,
             address_translator: None,
             timestamp_generator: None,
+            default_idempotence: false,
+            prepared_statement_cache_size: DEFAULT_PREPARED_STATEMENT_CACHE_SIZE,
+            empty_string_as_null: false,
+            allow_filtering_policy: PyAllowFilteringPolicy::Off,
+            wait_for_node_connections: false,
+            connect_deadline: None,
         })
     }
 }
@@ -645,6 +880,87 @@ impl<'py> FromPyObject<'_, 'py> for PyDuration {
     }
 }
 
+/// Port used for hostname contact points which do not specify one.
+const DEFAULT_CQL_PORT: u16 = 9042;
+
+/// Resolves every hostname contact point to all of its addresses.
+///
+/// The Rust driver connects only to a single address of a hostname, so a DNS
+/// name with several A/AAAA records, e.g. one fronting the whole cluster, would
+/// contribute one contact point. The addresses are added next to the hostname,
+/// which is kept so that the Rust driver resolves it again when it reconnects
+/// the control connection, e.g. after the addresses behind the name changed.
+/// Hostnames which fail to resolve are left to the Rust driver, which reports
+/// them when connecting.
+async fn resolve_contact_points(config: &mut SessionConfig) {
+    let mut resolved = HashMap::new();
+    for node in &config.known_nodes {
+        let KnownNode::Hostname(hostname) = node else {
+            continue;
+        };
+        if resolved.contains_key(hostname) || hostname.parse::<IpAddr>().is_ok() {
+            continue;
+        }
+
+        let lookup = tokio::net::lookup_host(with_default_port(hostname));
+        let addresses = match config.hostname_resolution_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, lookup).await.ok(),
+            None => Some(lookup.await),
+        };
+        if let Some(Ok(addresses)) = addresses {
+            resolved.insert(hostname.clone(), addresses.collect::<Vec<_>>());
+        }
+    }
+
+    let known_nodes = std::mem::take(&mut config.known_nodes);
+    config.known_nodes = expand_hostnames(known_nodes, |hostname| resolved.get(hostname).cloned());
+}
+
+/// Appends the default CQL port unless the hostname already has one.
+fn with_default_port(hostname: &str) -> String {
+    match hostname.rsplit_once(':') {
+        Some((_, port)) if port.parse::<u16>().is_ok() => hostname.to_owned(),
+        _ => format!("{hostname}:{DEFAULT_CQL_PORT}"),
+    }
+}
+
+/// Adds the addresses returned by `resolve` after each hostname contact point,
+/// skipping duplicates. The hostnames themselves are kept.
+pub(crate) fn expand_hostnames(
+    known_nodes: Vec<KnownNode>,
+    mut resolve: impl FnMut(&str) -> Option<Vec<SocketAddr>>,
+) -> Vec<KnownNode> {
+    let mut expanded = Vec::with_capacity(known_nodes.len());
+    let mut seen_hostnames = HashSet::new();
+    let mut seen_addresses = HashSet::new();
+
+    for node in known_nodes {
+        match node {
+            KnownNode::Hostname(hostname) => {
+                if !seen_hostnames.insert(hostname.clone()) {
+                    continue;
+                }
+                let addresses = resolve(&hostname).unwrap_or_default();
+                expanded.push(KnownNode::Hostname(hostname));
+                expanded.extend(
+                    addresses
+                        .into_iter()
+                        .filter(|address| seen_addresses.insert(*address))
+                        .map(KnownNode::Address),
+                );
+            }
+            KnownNode::Address(address) => {
+                if seen_addresses.insert(address) {
+                    expanded.push(KnownNode::Address(address));
+                }
+            }
+            other => expanded.push(other),
+        }
+    }
+
+    expanded
+}
+
 #[derive(Clone)]
 enum ContactPoint {
     Host(String),
@@ -660,6 +976,15 @@ impl ContactPoint {
     }
 }
 
+impl std::fmt::Display for ContactPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContactPoint::Host(host) => f.write_str(host),
+            ContactPoint::SocketAddr(addr) => write!(f, "{addr}"),
+        }
+    }
+}
+
 impl<'py> IntoPyObject<'py> for ContactPoint {
     type Target = PyString;
     type Output = Bound<'py, PyString>;
@@ -1,14 +1,18 @@
 use pyo3::IntoPyObjectExt;
 use pyo3::prelude::*;
-use pyo3::types::{PyFloat, PyString};
+use pyo3::types::{PyBytes, PyFloat, PyList, PyString, PyTuple};
 use scylla::statement::SerialConsistency;
 use scylla::statement::prepared::PreparedStatement;
 use scylla::statement::unprepared::Statement;
+use std::sync::Arc;
 use std::time::Duration;
 
+use crate::cluster::metadata::column_type::{PyCqlColumnType, extract_column_type};
 use crate::enums::{PyConsistency, PySerialConsistency};
-use crate::errors::DriverStatementConfigError;
+use crate::errors::{DriverSerializationError, DriverStatementConfigError};
 use crate::execution_profile::ExecutionProfile;
+use crate::policies::PyLoadBalancingPolicy;
+use crate::serialize::value_list::PyValueList;
 use crate::types::UnsetType;
 
 #[pyclass(name = "PreparedStatement", frozen)]
@@ -18,51 +22,84 @@ pub(crate) struct PyPreparedStatement {
     // it cannot represent the `Unset` state. Therefore, the Python-rs driver must distinguish
     // between `Unset` and `None` in a different way. To preserve this distinction, an additional
     // flag `is_serial_consistency_set` is required.
-    is_serial_consistency_set: bool,
+    pub(crate) is_serial_consistency_set: bool,
+    // Likewise, `is_idempotent` is a plain `bool`, so this flag tells whether it was set
+    // explicitly, in which case it takes precedence over the session's default idempotence.
+    pub(crate) is_idempotence_set: bool,
 }
 
 impl PyPreparedStatement {
-    pub(crate) fn new(_inner: PreparedStatement, is_serial_consistency_set: bool) -> Self {
+    pub(crate) fn new(
+        _inner: PreparedStatement,
+        is_serial_consistency_set: bool,
+        is_idempotence_set: bool,
+    ) -> Self {
         Self {
             _inner,
             is_serial_consistency_set,
+            is_idempotence_set,
         }
     }
 }
 
 #[pymethods]
 impl PyPreparedStatement {
-    fn with_execution_profile(&self, profile: ExecutionProfile) -> Self {
+    /// Id assigned to the statement by the server when it was prepared.
+    #[getter]
+    fn get_id<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, self._inner.get_id())
+    }
+
+    /// Serializes `values` against the statement's bind markers without sending them.
+    /// Returns the serialization error, or `None` if the values are valid.
+    #[pyo3(signature = (values=None))]
+    fn validate_values(&self, values: Option<PyValueList>) -> Option<PyErr> {
+        self._inner
+            .serialize_values_unstable(&values.unwrap_or_default())
+            .err()
+            .map(|err| DriverSerializationError::scylla_serialize_failed(err).into())
+    }
+
+    /// Names and types of the statement's bind markers, in order, as reported by the server.
+    #[getter]
+    fn get_variable_columns(&self, py: Python<'_>) -> PyResult<Vec<(String, Py<PyCqlColumnType>)>> {
+        self._inner
+            .get_variable_col_specs()
+            .iter()
+            .map(|spec| Ok((spec.name().to_owned(), extract_column_type(py, spec.typ())?)))
+            .collect()
+    }
+
+    fn with_execution_profile(&self, profile: &ExecutionProfile) -> Self {
         let mut p = self._inner.clone();
-        p.set_execution_profile_handle(Some(profile._inner.into_handle()));
-        Self::new(p, self.is_serial_consistency_set)
+        p.set_execution_profile_handle(Some(profile.handle.clone()));
+        Self::new(p, self.is_serial_consistency_set, self.is_idempotence_set)
     }
 
     fn without_execution_profile(&self) -> Self {
         let mut p = self._inner.clone();
         p.set_execution_profile_handle(None);
-        Self::new(p, self.is_serial_consistency_set)
+        Self::new(p, self.is_serial_consistency_set, self.is_idempotence_set)
     }
 
     #[getter]
     fn get_execution_profile(&self) -> Option<ExecutionProfile> {
         self._inner
             .get_execution_profile_handle()
-            .map(|h| ExecutionProfile {
-                _inner: h.to_profile(),
-            })
+            .cloned()
+            .map(ExecutionProfile::from)
     }
 
     fn with_consistency(&self, c: PyConsistency) -> Self {
         let mut p = self._inner.clone();
         p.set_consistency(c.into());
-        Self::new(p, self.is_serial_consistency_set)
+        Self::new(p, self.is_serial_consistency_set, self.is_idempotence_set)
     }
 
     fn without_consistency(&self) -> Self {
         let mut p = self._inner.clone();
         p.unset_consistency();
-        Self::new(p, self.is_serial_consistency_set)
+        Self::new(p, self.is_serial_consistency_set, self.is_idempotence_set)
     }
 
     #[getter]
@@ -70,16 +107,31 @@ impl PyPreparedStatement {
         self._inner.get_consistency().map(PyConsistency::from)
     }
 
-    fn with_serial_consistency(&self, sc: Option<PySerialConsistency>) -> Self {
+    /// With `check_lwt=True`, rejects a serial consistency level for statements
+    /// that are neither confirmed as LWT by the server nor contain an `IF` keyword.
+    #[pyo3(signature = (sc, *, check_lwt=false))]
+    fn with_serial_consistency(
+        &self,
+        sc: Option<PySerialConsistency>,
+        check_lwt: bool,
+    ) -> Result<Self, DriverStatementConfigError> {
+        if check_lwt
+            && sc.is_some()
+            && !self._inner.is_confirmed_lwt()
+            && !has_if_keyword(self._inner.get_statement())
+        {
+            return Err(DriverStatementConfigError::serial_consistency_without_lwt());
+        }
+
         let mut p = self._inner.clone();
         p.set_serial_consistency(sc.map(SerialConsistency::from));
-        Self::new(p, true)
+        Ok(Self::new(p, true, self.is_idempotence_set))
     }
 
     fn without_serial_consistency(&self) -> Self {
         let mut p = self._inner.clone();
         p.unset_serial_consistency();
-        Self::new(p, false)
+        Self::new(p, false, self.is_idempotence_set)
     }
 
     #[getter]
@@ -111,13 +163,17 @@ impl PyPreparedStatement {
 
         p.set_request_timeout(Some(timeout));
 
-        Ok(Self::new(p, self.is_serial_consistency_set))
+        Ok(Self::new(
+            p,
+            self.is_serial_consistency_set,
+            self.is_idempotence_set,
+        ))
     }
 
     fn without_request_timeout(&self) -> Self {
         let mut p = self._inner.clone();
         p.set_request_timeout(None);
-        Self::new(p, self.is_serial_consistency_set)
+        Self::new(p, self.is_serial_consistency_set, self.is_idempotence_set)
     }
 
     #[getter]
@@ -132,13 +188,73 @@ impl PyPreparedStatement {
     fn with_page_size(&self, page_size: i32) -> Self {
         let mut p = self._inner.clone();
         p.set_page_size(page_size);
-        Self::new(p, self.is_serial_consistency_set)
+        Self::new(p, self.is_serial_consistency_set, self.is_idempotence_set)
     }
 
     #[getter]
     fn get_page_size(&self) -> i32 {
         self._inner.get_page_size()
     }
+
+    fn with_load_balancing_policy(&self, policy: PyLoadBalancingPolicy) -> Self {
+        let mut p = self._inner.clone();
+        p.set_load_balancing_policy(Some(policy._inner));
+        Self::new(p, self.is_serial_consistency_set, self.is_idempotence_set)
+    }
+
+    fn without_load_balancing_policy(&self) -> Self {
+        let mut p = self._inner.clone();
+        p.set_load_balancing_policy(None);
+        Self::new(p, self.is_serial_consistency_set, self.is_idempotence_set)
+    }
+
+    #[getter]
+    fn get_load_balancing_policy(&self) -> Option<PyLoadBalancingPolicy> {
+        self._inner
+            .get_load_balancing_policy()
+            .map(|policy| PyLoadBalancingPolicy {
+                _inner: Arc::clone(policy),
+            })
+    }
+
+    fn with_timestamp(&self, timestamp: i64) -> Self {
+        let mut p = self._inner.clone();
+        p.set_timestamp(Some(timestamp));
+        Self::new(p, self.is_serial_consistency_set, self.is_idempotence_set)
+    }
+
+    fn without_timestamp(&self) -> Self {
+        let mut p = self._inner.clone();
+        p.set_timestamp(None);
+        Self::new(p, self.is_serial_consistency_set, self.is_idempotence_set)
+    }
+
+    #[getter]
+    fn get_timestamp(&self) -> Option<i64> {
+        self._inner.get_timestamp()
+    }
+
+    fn with_is_idempotent(&self, is_idempotent: bool) -> Self {
+        let mut p = self._inner.clone();
+        p.set_is_idempotent(is_idempotent);
+        Self::new(p, self.is_serial_consistency_set, true)
+    }
+
+    #[getter]
+    fn get_is_idempotent(&self) -> bool {
+        self._inner.get_is_idempotent()
+    }
+
+    fn with_tracing(&self, tracing: bool) -> Self {
+        let mut p = self._inner.clone();
+        p.set_tracing(tracing);
+        Self::new(p, self.is_serial_consistency_set, self.is_idempotence_set)
+    }
+
+    #[getter]
+    fn get_tracing(&self) -> bool {
+        self._inner.get_tracing()
+    }
 }
 
 #[pyclass(name = "Statement", frozen)]
@@ -148,14 +264,22 @@ pub(crate) struct PyStatement {
     // it cannot represent the `Unset` state. Therefore, the Python-rs driver must distinguish
     // between `Unset` and `None` in a different way. To preserve this distinction, an additional
     // flag `is_serial_consistency_set` is required.
-    is_serial_consistency_set: bool,
+    pub(crate) is_serial_consistency_set: bool,
+    // Likewise, `is_idempotent` is a plain `bool`, so this flag tells whether it was set
+    // explicitly, in which case it takes precedence over the session's default idempotence.
+    pub(crate) is_idempotence_set: bool,
 }
 
 impl PyStatement {
-    pub(crate) fn new(_inner: Statement, is_serial_consistency_set: bool) -> Self {
+    pub(crate) fn new(
+        _inner: Statement,
+        is_serial_consistency_set: bool,
+        is_idempotence_set: bool,
+    ) -> Self {
         Self {
             _inner,
             is_serial_consistency_set,
+            is_idempotence_set,
         }
     }
 }
@@ -165,7 +289,52 @@ impl PyStatement {
     #[new]
     fn py_new(query_str: String) -> Self {
         let s = Statement::from(query_str);
-        Self::new(s, false)
+        Self::new(s, false, false)
+    }
+
+    /// Creates an unprepared statement with the CQL text of a prepared one.
+    ///
+    /// Execution profile, consistency, serial consistency, request timeout,
+    /// page size, timestamp, load balancing policy and idempotence are copied
+    /// over from the prepared statement.
+    #[staticmethod]
+    fn from_prepared(prepared: &Bound<'_, PyPreparedStatement>) -> Self {
+        let prepared = prepared.get();
+        let p = &prepared._inner;
+
+        let mut s = Statement::new(p.get_statement().to_owned());
+        s.set_execution_profile_handle(p.get_execution_profile_handle().cloned());
+        if let Some(c) = p.get_consistency() {
+            s.set_consistency(c);
+        }
+        if prepared.is_serial_consistency_set {
+            s.set_serial_consistency(p.get_serial_consistency());
+        }
+        s.set_request_timeout(p.get_request_timeout());
+        s.set_page_size(p.get_page_size());
+        s.set_timestamp(p.get_timestamp());
+        s.set_load_balancing_policy(p.get_load_balancing_policy().cloned());
+        s.set_is_idempotent(p.get_is_idempotent());
+
+        Self::new(
+            s,
+            prepared.is_serial_consistency_set,
+            prepared.is_idempotence_set,
+        )
+    }
+
+    /// Expands `IN ?` markers bound to lists or tuples into `IN (?, ?, ...)`.
+    ///
+    /// Returns the rewritten statement together with the flattened values,
+    /// ready to be passed to `Session.execute`.
+    #[staticmethod]
+    fn expand_in_lists<'py>(
+        py: Python<'py>,
+        query_str: &str,
+        values: Vec<Bound<'py, PyAny>>,
+    ) -> Result<(Self, Bound<'py, PyList>), DriverStatementConfigError> {
+        let (query, flat_values) = expand_in_markers(py, query_str, &values)?;
+        Ok((Self::new(Statement::from(query), false, false), flat_values))
     }
 
     #[getter]
@@ -173,37 +342,36 @@ impl PyStatement {
         PyString::new(py, &self._inner.contents)
     }
 
-    fn with_execution_profile(&self, profile: ExecutionProfile) -> Self {
+    fn with_execution_profile(&self, profile: &ExecutionProfile) -> Self {
         let mut s = self._inner.clone();
-        s.set_execution_profile_handle(Some(profile._inner.into_handle()));
-        Self::new(s, self.is_serial_consistency_set)
+        s.set_execution_profile_handle(Some(profile.handle.clone()));
+        Self::new(s, self.is_serial_consistency_set, self.is_idempotence_set)
     }
 
     fn without_execution_profile(&self) -> Self {
         let mut s = self._inner.clone();
         s.set_execution_profile_handle(None);
-        Self::new(s, self.is_serial_consistency_set)
+        Self::new(s, self.is_serial_consistency_set, self.is_idempotence_set)
     }
 
     #[getter]
     fn get_execution_profile(&self) -> Option<ExecutionProfile> {
         self._inner
             .get_execution_profile_handle()
-            .map(|h| ExecutionProfile {
-                _inner: h.to_profile(),
-            })
+            .cloned()
+            .map(ExecutionProfile::from)
     }
 
     fn with_consistency(&self, c: PyConsistency) -> Self {
         let mut s = self._inner.clone();
         s.set_consistency(c.into());
-        Self::new(s, self.is_serial_consistency_set)
+        Self::new(s, self.is_serial_consistency_set, self.is_idempotence_set)
     }
 
     fn without_consistency(&self) -> Self {
         let mut s = self._inner.clone();
         s.unset_consistency();
-        Self::new(s, self.is_serial_consistency_set)
+        Self::new(s, self.is_serial_consistency_set, self.is_idempotence_set)
     }
 
     #[getter]
@@ -211,16 +379,27 @@ impl PyStatement {
         self._inner.get_consistency().map(PyConsistency::from)
     }
 
-    fn with_serial_consistency(&self, sc: Option<PySerialConsistency>) -> Self {
+    /// With `check_lwt=True`, rejects a serial consistency level for statements
+    /// whose text does not contain an `IF` keyword.
+    #[pyo3(signature = (sc, *, check_lwt=false))]
+    fn with_serial_consistency(
+        &self,
+        sc: Option<PySerialConsistency>,
+        check_lwt: bool,
+    ) -> Result<Self, DriverStatementConfigError> {
+        if check_lwt && sc.is_some() && !has_if_keyword(&self._inner.contents) {
+            return Err(DriverStatementConfigError::serial_consistency_without_lwt());
+        }
+
         let mut s = self._inner.clone();
         s.set_serial_consistency(sc.map(SerialConsistency::from));
-        Self::new(s, true)
+        Ok(Self::new(s, true, self.is_idempotence_set))
     }
 
     fn without_serial_consistency(&self) -> Self {
         let mut s = self._inner.clone();
         s.unset_serial_consistency();
-        Self::new(s, false)
+        Self::new(s, false, self.is_idempotence_set)
     }
 
     #[getter]
@@ -250,13 +429,17 @@ impl PyStatement {
 
         let mut s = self._inner.clone();
         s.set_request_timeout(Some(timeout));
-        Ok(Self::new(s, self.is_serial_consistency_set))
+        Ok(Self::new(
+            s,
+            self.is_serial_consistency_set,
+            self.is_idempotence_set,
+        ))
     }
 
     fn without_request_timeout(&self) -> Self {
         let mut s = self._inner.clone();
         s.set_request_timeout(None);
-        Self::new(s, self.is_serial_consistency_set)
+        Self::new(s, self.is_serial_consistency_set, self.is_idempotence_set)
     }
 
     #[getter]
@@ -271,13 +454,336 @@ impl PyStatement {
     fn with_page_size(&self, page_size: i32) -> Self {
         let mut s = self._inner.clone();
         s.set_page_size(page_size);
-        Self::new(s, self.is_serial_consistency_set)
+        Self::new(s, self.is_serial_consistency_set, self.is_idempotence_set)
     }
 
     #[getter]
     fn get_page_size(&self) -> i32 {
         self._inner.get_page_size()
     }
+
+    fn with_load_balancing_policy(&self, policy: PyLoadBalancingPolicy) -> Self {
+        let mut s = self._inner.clone();
+        s.set_load_balancing_policy(Some(policy._inner));
+        Self::new(s, self.is_serial_consistency_set, self.is_idempotence_set)
+    }
+
+    fn without_load_balancing_policy(&self) -> Self {
+        let mut s = self._inner.clone();
+        s.set_load_balancing_policy(None);
+        Self::new(s, self.is_serial_consistency_set, self.is_idempotence_set)
+    }
+
+    #[getter]
+    fn get_load_balancing_policy(&self) -> Option<PyLoadBalancingPolicy> {
+        self._inner
+            .get_load_balancing_policy()
+            .map(|policy| PyLoadBalancingPolicy {
+                _inner: Arc::clone(policy),
+            })
+    }
+
+    fn with_timestamp(&self, timestamp: i64) -> Self {
+        let mut s = self._inner.clone();
+        s.set_timestamp(Some(timestamp));
+        Self::new(s, self.is_serial_consistency_set, self.is_idempotence_set)
+    }
+
+    fn without_timestamp(&self) -> Self {
+        let mut s = self._inner.clone();
+        s.set_timestamp(None);
+        Self::new(s, self.is_serial_consistency_set, self.is_idempotence_set)
+    }
+
+    #[getter]
+    fn get_timestamp(&self) -> Option<i64> {
+        self._inner.get_timestamp()
+    }
+
+    fn with_is_idempotent(&self, is_idempotent: bool) -> Self {
+        let mut s = self._inner.clone();
+        s.set_is_idempotent(is_idempotent);
+        Self::new(s, self.is_serial_consistency_set, true)
+    }
+
+    #[getter]
+    fn get_is_idempotent(&self) -> bool {
+        self._inner.get_is_idempotent()
+    }
+
+    fn with_tracing(&self, tracing: bool) -> Self {
+        let mut s = self._inner.clone();
+        s.set_tracing(tracing);
+        Self::new(s, self.is_serial_consistency_set, self.is_idempotence_set)
+    }
+
+    #[getter]
+    fn get_tracing(&self) -> bool {
+        self._inner.get_tracing()
+    }
+}
+
+/// A token of a CQL statement, as seen by the best-effort checks below.
+///
+/// Whitespace and comments are skipped and never produce a token.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum CqlToken<'a> {
+    /// An unquoted identifier, keyword or numeric literal.
+    Word(&'a [u8]),
+    /// A string literal, a quoted identifier or a dollar-quoted string.
+    Quoted,
+    /// A positional bind marker, `?`.
+    PositionalMarker,
+    /// A named bind marker, `:name` or `:"Name"`.
+    NamedMarker,
+    /// A `::` pair, which is never a bind marker.
+    DoubleColon,
+    /// Any other single character, e.g. punctuation or an operator.
+    Symbol(u8),
+}
+
+/// Splits a CQL statement into tokens, yielding each with its starting byte offset.
+///
+/// A colon starts a named bind marker only where a term may begin. After a term
+/// inside braces (`{'key': value}`, `{field: value}`) it separates a map key or
+/// a user-defined type field from its value, so it is reported as a symbol.
+struct CqlTokens<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    brace_depth: usize,
+    previous: Option<CqlToken<'a>>,
+}
+
+impl<'a> CqlTokens<'a> {
+    fn new(query: &'a str) -> Self {
+        Self {
+            bytes: query.as_bytes(),
+            pos: 0,
+            brace_depth: 0,
+            previous: None,
+        }
+    }
+
+    /// Whether a colon at the current position may start a named bind marker,
+    /// judging by the token before it.
+    fn colon_starts_marker(&self) -> bool {
+        match self.previous {
+            None => true,
+            Some(CqlToken::Symbol(c)) => !matches!(c, b')' | b']' | b'}'),
+            Some(CqlToken::Word(_)) => self.brace_depth == 0,
+            Some(CqlToken::DoubleColon) => true,
+            Some(CqlToken::Quoted | CqlToken::PositionalMarker | CqlToken::NamedMarker) => false,
+        }
+    }
+}
+
+impl<'a> Iterator for CqlTokens<'a> {
+    type Item = (usize, CqlToken<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.bytes;
+        loop {
+            let start = self.pos;
+            let token = match (*bytes.get(start)?, bytes.get(start + 1)) {
+                (c, _) if c.is_ascii_whitespace() => {
+                    self.pos += 1;
+                    continue;
+                }
+                (b'-', Some(b'-')) | (b'/', Some(b'/')) => {
+                    self.pos = skip_until(bytes, start + 2, b"\n");
+                    continue;
+                }
+                (b'/', Some(b'*')) => {
+                    self.pos = skip_until(bytes, start + 2, b"*/");
+                    continue;
+                }
+                (b'\'', _) | (b'"', _) => {
+                    self.pos = skip_quoted(bytes, start);
+                    CqlToken::Quoted
+                }
+                (b'$', Some(b'$')) => {
+                    self.pos = skip_until(bytes, start + 2, b"$$");
+                    CqlToken::Quoted
+                }
+                (c, _) if is_word_byte(c) => {
+                    self.pos = bytes[start..]
+                        .iter()
+                        .position(|c| !is_word_byte(*c))
+                        .map_or(bytes.len(), |len| start + len);
+                    CqlToken::Word(&bytes[start..self.pos])
+                }
+                (b'?', _) => {
+                    self.pos += 1;
+                    CqlToken::PositionalMarker
+                }
+                (b':', Some(b':')) => {
+                    self.pos += 2;
+                    CqlToken::DoubleColon
+                }
+                (b':', Some(&c))
+                    if (c.is_ascii_alphabetic() || c == b'_' || c == b'"')
+                        && self.colon_starts_marker() =>
+                {
+                    self.pos = if c == b'"' {
+                        skip_quoted(bytes, start + 1)
+                    } else {
+                        bytes[start + 1..]
+                            .iter()
+                            .position(|c| !is_word_byte(*c))
+                            .map_or(bytes.len(), |len| start + 1 + len)
+                    };
+                    CqlToken::NamedMarker
+                }
+                (c, _) => {
+                    match c {
+                        b'{' => self.brace_depth += 1,
+                        b'}' => self.brace_depth = self.brace_depth.saturating_sub(1),
+                        _ => {}
+                    }
+                    self.pos += 1;
+                    CqlToken::Symbol(c)
+                }
+            };
+            self.previous = Some(token);
+            return Some((start, token));
+        }
+    }
+}
+
+fn is_word_byte(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+/// Returns the index just past the quoted section starting at `start`.
+/// A doubled quote character inside the section is an escaped quote.
+fn skip_quoted(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        if bytes[i] == quote {
+            if bytes.get(i + 1) == Some(&quote) {
+                i += 2;
+                continue;
+            }
+            return i + 1;
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Returns the index just past the first occurrence of `terminator` at or after `start`.
+fn skip_until(bytes: &[u8], start: usize, terminator: &[u8]) -> usize {
+    bytes
+        .get(start..)
+        .and_then(|rest| {
+            rest.windows(terminator.len())
+                .position(|window| window == terminator)
+        })
+        .map_or(bytes.len(), |pos| start + pos + terminator.len())
+}
+
+/// Rewrites positional `?` markers that follow the `IN` keyword and are bound
+/// to a Python list or tuple into a parenthesized list of markers, one per element.
+///
+/// String literals, quoted identifiers and comments are skipped, so question
+/// marks inside them are not treated as bind markers. An empty list is expanded
+/// into `IN ()`, which matches no rows.
+fn expand_in_markers<'py>(
+    py: Python<'py>,
+    query: &str,
+    values: &[Bound<'py, PyAny>],
+) -> Result<(String, Bound<'py, PyList>), DriverStatementConfigError> {
+    let mut expanded = String::with_capacity(query.len());
+    let flat_values = PyList::empty(py);
+    let mut copied_up_to = 0;
+    let mut markers = 0;
+    let mut after_in = false;
+
+    for (i, token) in CqlTokens::new(query) {
+        if token == CqlToken::PositionalMarker {
+            if let Some(value) = values.get(markers) {
+                let is_list = value.is_instance_of::<PyList>() || value.is_instance_of::<PyTuple>();
+                if is_list && after_in {
+                    let elements = value
+                        .try_iter()
+                        .and_then(|it| it.collect::<PyResult<Vec<_>>>())
+                        .map_err(DriverStatementConfigError::python_conversion_failed)?;
+
+                    expanded.push_str(&query[copied_up_to..i]);
+                    expanded.push('(');
+                    expanded.push_str(&vec!["?"; elements.len()].join(", "));
+                    expanded.push(')');
+                    copied_up_to = i + 1;
+
+                    for element in elements {
+                        flat_values
+                            .append(element)
+                            .map_err(DriverStatementConfigError::python_conversion_failed)?;
+                    }
+                } else {
+                    flat_values
+                        .append(value)
+                        .map_err(DriverStatementConfigError::python_conversion_failed)?;
+                }
+            }
+            markers += 1;
+        }
+        after_in = matches!(token, CqlToken::Word(word) if word.eq_ignore_ascii_case(b"in"));
+    }
+
+    if markers != values.len() {
+        return Err(DriverStatementConfigError::bind_marker_count_mismatch(
+            markers,
+            values.len(),
+        ));
+    }
+
+    expanded.push_str(&query[copied_up_to..]);
+    Ok((expanded, flat_values))
+}
+
+/// Best-effort check whether a statement is a lightweight transaction: looks
+/// for the `IF` keyword outside string literals, quoted identifiers and comments.
+///
+/// Note that `IF NOT EXISTS` in schema statements is matched as well.
+fn has_if_keyword(query: &str) -> bool {
+    CqlTokens::new(query)
+        .any(|(_, token)| matches!(token, CqlToken::Word(word) if word.eq_ignore_ascii_case(b"if")))
+}
+
+/// Best-effort check whether a statement contains the `ALLOW FILTERING` clause:
+/// looks for the two keywords, in order, outside string literals, quoted
+/// identifiers and comments.
+pub(crate) fn has_allow_filtering(query: &str) -> bool {
+    let mut after_allow = false;
+    CqlTokens::new(query).any(|(_, token)| match token {
+        CqlToken::Word(word) if after_allow && word.eq_ignore_ascii_case(b"filtering") => true,
+        _ => {
+            after_allow =
+                matches!(token, CqlToken::Word(word) if word.eq_ignore_ascii_case(b"allow"));
+            false
+        }
+    })
+}
+
+/// Best-effort check whether a statement uses both positional (`?`) and named
+/// (`:name` or `:"Name"`) bind markers, outside string literals, quoted
+/// identifiers and comments.
+///
+/// Colons separating map keys or user-defined type fields from their values,
+/// and `::` pairs, are not mistaken for named markers.
+pub(crate) fn has_mixed_bind_markers(query: &str) -> bool {
+    let mut positional = false;
+    let mut named = false;
+    for (_, token) in CqlTokens::new(query) {
+        match token {
+            CqlToken::PositionalMarker => positional = true,
+            CqlToken::NamedMarker => named = true,
+            _ => {}
+        }
+    }
+    positional && named
 }
 
 #[pymodule]
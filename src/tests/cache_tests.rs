@@ -5,7 +5,7 @@ use std::sync::{
     atomic::{AtomicUsize, Ordering},
 };
 
-use crate::cache::Cache;
+use crate::cache::{Cache, LruCache};
 
 #[test]
 fn get_or_init_inserts_and_reuses() {
@@ -246,3 +246,40 @@ fn get_or_init_mixes_string_insert_and_str_lookup() {
         assert_eq!(calls.load(Ordering::SeqCst), 0);
     });
 }
+
+#[test]
+fn lru_cache_evicts_least_recently_used() {
+    let cache = LruCache::<String, i64>::new(2);
+
+    cache.insert("a".to_string(), 1);
+    cache.insert("b".to_string(), 2);
+    // Using "a" makes "b" the least recently used entry.
+    assert_eq!(cache.get("a"), Some(1));
+
+    cache.insert("c".to_string(), 3);
+
+    assert_eq!(cache.get("a"), Some(1));
+    assert_eq!(cache.get("b"), None);
+    assert_eq!(cache.get("c"), Some(3));
+}
+
+#[test]
+fn lru_cache_replaces_existing_key_without_evicting() {
+    let cache = LruCache::<String, i64>::new(2);
+
+    cache.insert("a".to_string(), 1);
+    cache.insert("b".to_string(), 2);
+    cache.insert("a".to_string(), 10);
+
+    assert_eq!(cache.get("a"), Some(10));
+    assert_eq!(cache.get("b"), Some(2));
+}
+
+#[test]
+fn lru_cache_with_zero_capacity_stores_nothing() {
+    let cache = LruCache::<String, i64>::new(0);
+
+    cache.insert("a".to_string(), 1);
+
+    assert_eq!(cache.get("a"), None);
+}
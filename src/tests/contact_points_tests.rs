@@ -0,0 +1,82 @@
+use std::net::SocketAddr;
+
+use scylla::cluster::KnownNode;
+
+use crate::session_builder::expand_hostnames;
+
+fn addr(s: &str) -> SocketAddr {
+    s.parse().unwrap()
+}
+
+fn addresses(nodes: &[KnownNode]) -> Vec<String> {
+    nodes
+        .iter()
+        .map(|node| match node {
+            KnownNode::Hostname(hostname) => hostname.clone(),
+            KnownNode::Address(address) => address.to_string(),
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+#[test]
+fn hostname_with_multiple_records_adds_all_addresses() {
+    let known_nodes = vec![
+        KnownNode::Hostname("cluster.example:9042".to_owned()),
+        KnownNode::Address(addr("10.0.0.9:9042")),
+    ];
+
+    let expanded = expand_hostnames(known_nodes, |hostname| {
+        assert_eq!(hostname, "cluster.example:9042");
+        Some(vec![
+            addr("10.0.0.1:9042"),
+            addr("10.0.0.2:9042"),
+            addr("10.0.0.3:9042"),
+        ])
+    });
+
+    assert_eq!(
+        addresses(&expanded),
+        [
+            "cluster.example:9042",
+            "10.0.0.1:9042",
+            "10.0.0.2:9042",
+            "10.0.0.3:9042",
+            "10.0.0.9:9042"
+        ]
+    );
+}
+
+#[test]
+fn duplicate_addresses_are_registered_once() {
+    let known_nodes = vec![
+        KnownNode::Address(addr("10.0.0.1:9042")),
+        KnownNode::Hostname("a.example".to_owned()),
+        KnownNode::Hostname("b.example".to_owned()),
+        KnownNode::Hostname("a.example".to_owned()),
+    ];
+
+    let expanded = expand_hostnames(known_nodes, |_| {
+        Some(vec![addr("10.0.0.1:9042"), addr("10.0.0.2:9042")])
+    });
+
+    assert_eq!(
+        addresses(&expanded),
+        ["10.0.0.1:9042", "a.example", "10.0.0.2:9042", "b.example"]
+    );
+}
+
+#[test]
+fn unresolved_hostname_is_kept() {
+    let known_nodes = vec![
+        KnownNode::Hostname("unknown.example".to_owned()),
+        KnownNode::Hostname("empty.example".to_owned()),
+    ];
+
+    let expanded = expand_hostnames(known_nodes, |hostname| match hostname {
+        "empty.example" => Some(Vec::new()),
+        _ => None,
+    });
+
+    assert_eq!(addresses(&expanded), ["unknown.example", "empty.example"]);
+}
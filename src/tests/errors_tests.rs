@@ -0,0 +1,150 @@
+use pyo3::prelude::*;
+use scylla::errors::{DbError, ExecutionError, PrepareError, RequestAttemptError, WriteType};
+use scylla::statement::Consistency;
+
+use crate::enums::PyConsistency;
+use crate::errors::{
+    DriverExecuteError, DriverPrepareError, ExecuteError, InvalidQueryError, InvalidStatementError,
+    WriteTimeoutError,
+};
+
+fn db_error(error: DbError) -> ExecutionError {
+    ExecutionError::LastAttemptError(RequestAttemptError::DbError(
+        error,
+        "server message".to_owned(),
+    ))
+}
+
+#[test]
+fn write_timeout_exposes_write_type() {
+    Python::initialize();
+    Python::attach(|py| {
+        let source = db_error(DbError::WriteTimeout {
+            consistency: Consistency::Quorum,
+            received: 1,
+            required: 2,
+            write_type: WriteType::BatchLog,
+        });
+        let err = PyErr::from(DriverExecuteError::rust_driver_execution_error(source));
+
+        assert!(err.is_instance_of::<WriteTimeoutError>(py));
+        assert!(err.is_instance_of::<ExecuteError>(py));
+        let inst = err.value(py);
+        let write_type: String = inst.getattr("write_type").unwrap().extract().unwrap();
+        assert_eq!(write_type, "BATCH_LOG");
+        let consistency: PyConsistency = inst.getattr("consistency").unwrap().extract().unwrap();
+        assert!(consistency == PyConsistency::Quorum);
+        let received: i32 = inst.getattr("received").unwrap().extract().unwrap();
+        let required: i32 = inst.getattr("required").unwrap().extract().unwrap();
+        assert_eq!((received, required), (1, 2));
+    });
+}
+
+#[test]
+fn write_timeout_after_retries_exposes_write_type() {
+    Python::initialize();
+    Python::attach(|py| {
+        let source = db_error(DbError::WriteTimeout {
+            consistency: Consistency::One,
+            received: 0,
+            required: 1,
+            write_type: WriteType::Counter,
+        });
+        let err = PyErr::from(DriverExecuteError::retries_exhausted(3, source));
+
+        assert!(err.is_instance_of::<WriteTimeoutError>(py));
+        let write_type: String = err
+            .value(py)
+            .getattr("write_type")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(write_type, "COUNTER");
+    });
+}
+
+#[test]
+fn other_db_errors_stay_execute_errors() {
+    Python::initialize();
+    Python::attach(|py| {
+        let source = db_error(DbError::Unavailable {
+            consistency: Consistency::Quorum,
+            required: 2,
+            alive: 1,
+        });
+        let err = PyErr::from(DriverExecuteError::rust_driver_execution_error(source));
+
+        assert!(err.is_instance_of::<ExecuteError>(py));
+        assert!(!err.is_instance_of::<WriteTimeoutError>(py));
+    });
+}
+
+fn invalid_query_reason(py: Python<'_>, message: &str) -> Option<String> {
+    let source = ExecutionError::LastAttemptError(RequestAttemptError::DbError(
+        DbError::Invalid,
+        message.to_owned(),
+    ));
+    let err = PyErr::from(DriverExecuteError::rust_driver_execution_error(source));
+
+    assert!(err.is_instance_of::<InvalidQueryError>(py));
+    assert!(err.is_instance_of::<ExecuteError>(py));
+    err.value(py).getattr("reason").unwrap().extract().unwrap()
+}
+
+#[test]
+fn invalid_query_exposes_missing_schema_reason() {
+    Python::initialize();
+    Python::attach(|py| {
+        assert_eq!(
+            invalid_query_reason(py, "unconfigured table no_such_table").as_deref(),
+            Some("table_not_found")
+        );
+        assert_eq!(
+            invalid_query_reason(py, "table ks.no_such_table does not exist").as_deref(),
+            Some("table_not_found")
+        );
+        assert_eq!(
+            invalid_query_reason(py, "Keyspace 'no_such_ks' does not exist").as_deref(),
+            Some("keyspace_not_found")
+        );
+        assert_eq!(
+            invalid_query_reason(py, "Undefined column name foo").as_deref(),
+            None
+        );
+    });
+}
+
+fn invalid_prepare_error(message: &str) -> PrepareError {
+    PrepareError::AllAttemptsFailed {
+        first_attempt: RequestAttemptError::DbError(DbError::Invalid, message.to_owned()),
+    }
+}
+
+#[test]
+fn invalid_prepare_exposes_missing_schema_reason() {
+    Python::initialize();
+    Python::attach(|py| {
+        let err = PyErr::from(DriverPrepareError::rust_driver_prepare_error(
+            invalid_prepare_error("unconfigured table no_such_table"),
+        ));
+
+        assert!(err.is_instance_of::<InvalidStatementError>(py));
+        let reason: Option<String> = err.value(py).getattr("reason").unwrap().extract().unwrap();
+        assert_eq!(reason.as_deref(), Some("table_not_found"));
+    });
+}
+
+#[test]
+fn invalid_implicit_prepare_exposes_missing_schema_reason() {
+    Python::initialize();
+    Python::attach(|py| {
+        let source = ExecutionError::PrepareError(invalid_prepare_error(
+            "Keyspace 'no_such_ks' does not exist",
+        ));
+        let err = PyErr::from(DriverExecuteError::rust_driver_execution_error(source));
+
+        assert!(err.is_instance_of::<InvalidQueryError>(py));
+        let reason: Option<String> = err.value(py).getattr("reason").unwrap().extract().unwrap();
+        assert_eq!(reason.as_deref(), Some("keyspace_not_found"));
+    });
+}
@@ -1 +1,8 @@
 mod cache_tests;
+mod contact_points_tests;
+mod errors_tests;
+mod retry_policy_tests;
+mod runtime_tests;
+mod serialization_tests;
+mod session_tests;
+mod statement_tests;
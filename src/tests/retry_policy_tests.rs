@@ -0,0 +1,114 @@
+use scylla::errors::{DbError, RequestAttemptError, WriteType};
+use scylla::policies::retry::{RequestInfo, RetryDecision, RetryPolicy};
+use scylla::statement::Consistency;
+use std::sync::Arc;
+
+use crate::policies::{PyDefaultRetryPolicy, PyDowngradingConsistencyRetryPolicy};
+
+fn downgrading() -> Arc<dyn RetryPolicy> {
+    PyDowngradingConsistencyRetryPolicy::new().1._inner
+}
+
+fn db_error(error: DbError) -> RequestAttemptError {
+    RequestAttemptError::DbError(error, "server message".to_owned())
+}
+
+fn decide(
+    policy: &Arc<dyn RetryPolicy>,
+    error: &RequestAttemptError,
+    is_idempotent: bool,
+) -> RetryDecision {
+    policy.new_session().decide_should_retry(RequestInfo {
+        error,
+        is_idempotent,
+        consistency: Consistency::Quorum,
+    })
+}
+
+#[test]
+fn unavailable_is_retried_at_the_consistency_of_alive_replicas() {
+    let error = db_error(DbError::Unavailable {
+        consistency: Consistency::Quorum,
+        required: 2,
+        alive: 1,
+    });
+
+    assert!(matches!(
+        decide(&downgrading(), &error, true),
+        RetryDecision::RetrySameTarget(Some(Consistency::One))
+    ));
+    // The default policy never lowers the consistency level.
+    assert!(!matches!(
+        decide(&PyDefaultRetryPolicy::new().1._inner, &error, true),
+        RetryDecision::RetrySameTarget(Some(_)) | RetryDecision::RetryNextTarget(Some(_))
+    ));
+}
+
+#[test]
+fn read_timeout_is_retried_at_the_consistency_of_received_responses() {
+    let error = db_error(DbError::ReadTimeout {
+        consistency: Consistency::Quorum,
+        received: 1,
+        required: 2,
+        data_present: false,
+    });
+
+    assert!(matches!(
+        decide(&downgrading(), &error, true),
+        RetryDecision::RetrySameTarget(Some(Consistency::One))
+    ));
+}
+
+#[test]
+fn downgraded_request_is_not_retried_again() {
+    let error = db_error(DbError::Unavailable {
+        consistency: Consistency::Quorum,
+        required: 2,
+        alive: 1,
+    });
+    let policy = downgrading();
+    let mut session = policy.new_session();
+    let request_info = || RequestInfo {
+        error: &error,
+        is_idempotent: true,
+        consistency: Consistency::Quorum,
+    };
+
+    assert!(matches!(
+        session.decide_should_retry(request_info()),
+        RetryDecision::RetrySameTarget(Some(Consistency::One))
+    ));
+    assert!(matches!(
+        session.decide_should_retry(request_info()),
+        RetryDecision::DontRetry
+    ));
+}
+
+#[test]
+fn no_alive_replicas_is_not_retried() {
+    let error = db_error(DbError::Unavailable {
+        consistency: Consistency::Quorum,
+        required: 2,
+        alive: 0,
+    });
+
+    assert!(matches!(
+        decide(&downgrading(), &error, true),
+        RetryDecision::DontRetry
+    ));
+}
+
+#[test]
+fn non_idempotent_write_timeout_is_not_retried() {
+    let error = db_error(DbError::WriteTimeout {
+        consistency: Consistency::Quorum,
+        received: 1,
+        required: 2,
+        write_type: WriteType::Simple,
+    });
+
+    assert!(matches!(
+        decide(&downgrading(), &error, false),
+        RetryDecision::DontRetry
+    ));
+}
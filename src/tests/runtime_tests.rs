@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use pyo3::prelude::*;
+use tokio::sync::oneshot;
+
+use crate::RUNTIME;
+use crate::errors::{DriverExecuteError, ExecuteError};
+use crate::utils::AbortOnDropHandle;
+
+#[test]
+fn panicking_runtime_task_becomes_execute_error() {
+    let join_result = RUNTIME.block_on(AbortOnDropHandle::<()>(
+        RUNTIME.spawn(async { panic!("poisoned request") }),
+    ));
+    let err = DriverExecuteError::from(join_result.unwrap_err());
+
+    Python::initialize();
+    Python::attach(|py| {
+        let err = PyErr::from(err);
+
+        assert!(err.is_instance_of::<ExecuteError>(py));
+        let message = err.value(py).to_string();
+        assert!(message.contains("Internal driver error"), "{message}");
+        assert!(message.contains("panicked"), "{message}");
+    });
+}
+
+/// Notifies the receiving end when dropped, i.e. when the task owning it is aborted.
+struct DropGuard(Option<oneshot::Sender<()>>);
+
+impl Drop for DropGuard {
+    fn drop(&mut self) {
+        if let Some(sender) = self.0.take() {
+            let _ = sender.send(());
+        }
+    }
+}
+
+#[test]
+fn cancelled_await_aborts_runtime_task() {
+    let (sender, dropped) = oneshot::channel();
+    let handle = AbortOnDropHandle(RUNTIME.spawn(async move {
+        let _guard = DropGuard(Some(sender));
+        std::future::pending::<()>().await
+    }));
+
+    RUNTIME.block_on(async {
+        // The timeout drops the awaiting future, as cancelling a Python awaitable does.
+        let awaited = tokio::time::timeout(Duration::from_millis(10), handle).await;
+        assert!(awaited.is_err());
+
+        tokio::time::timeout(Duration::from_secs(5), dropped)
+            .await
+            .expect("the task was not aborted")
+            .unwrap();
+    });
+}
+
+#[test]
+fn dropping_handle_aborts_runtime_task() {
+    let (sender, dropped) = oneshot::channel();
+    let handle = AbortOnDropHandle(RUNTIME.spawn(async move {
+        let _guard = DropGuard(Some(sender));
+        std::future::pending::<()>().await
+    }));
+
+    drop(handle);
+
+    RUNTIME
+        .block_on(tokio::time::timeout(Duration::from_secs(5), dropped))
+        .expect("the task was not aborted")
+        .unwrap();
+}
@@ -0,0 +1,53 @@
+use pyo3::prelude::*;
+
+use crate::errors::{DriverSerializationError, ValueOverflowSerializationError};
+use crate::serialize::value::collection_element_count;
+
+#[test]
+fn collection_element_count_accepts_i32_max() {
+    assert_eq!(
+        collection_element_count(i32::MAX as usize).unwrap(),
+        i32::MAX
+    );
+}
+
+#[test]
+fn collection_element_count_rejects_oversized_collection() {
+    Python::initialize();
+    Python::attach(|py| {
+        let err = collection_element_count(i32::MAX as usize + 1).unwrap_err();
+        let err = PyErr::from(err.at_parameter_index(1));
+
+        assert!(err.is_instance_of::<ValueOverflowSerializationError>(py));
+        let message = err.value(py).to_string();
+        assert!(message.contains("2147483648 elements"), "{message}");
+        assert!(message.contains("at most 2147483647"), "{message}");
+        assert!(message.contains("parameter_index=1"), "{message}");
+    });
+}
+
+#[test]
+fn integer_out_of_range_states_value_and_range() {
+    Python::initialize();
+    Python::attach(|py| {
+        let err = DriverSerializationError::integer_out_of_range(
+            "bigint",
+            "9223372036854775808",
+            i64::MIN,
+            i64::MAX,
+        );
+        let err = PyErr::from(err.at_parameter_index(0));
+
+        assert!(err.is_instance_of::<ValueOverflowSerializationError>(py));
+        let message = err.value(py).to_string();
+        assert!(
+            message.contains("9223372036854775808 is out of range for CQL bigint"),
+            "{message}"
+        );
+        assert!(
+            message.contains("from -9223372036854775808 to 9223372036854775807"),
+            "{message}"
+        );
+        assert!(message.contains("parameter_index=0"), "{message}");
+    });
+}
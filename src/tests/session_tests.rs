@@ -0,0 +1,62 @@
+use scylla::statement::batch::{Batch, BatchType};
+use scylla::statement::unprepared::Statement;
+
+use crate::batch::PyBatch;
+use crate::session::{ExecutableStatement, RequestStatement};
+
+fn request(is_idempotent: bool, is_idempotence_set: bool) -> RequestStatement {
+    let mut statement = Statement::new("SELECT * FROM system.local");
+    statement.set_is_idempotent(is_idempotent);
+    RequestStatement {
+        statement: ExecutableStatement::Unprepared(statement),
+        is_serial_consistency_set: false,
+        is_idempotence_set,
+    }
+}
+
+fn is_idempotent(statement: ExecutableStatement) -> bool {
+    match statement {
+        ExecutableStatement::Prepared(p) => p.get_is_idempotent(),
+        ExecutableStatement::Unprepared(s) => s.get_is_idempotent(),
+    }
+}
+
+#[test]
+fn default_idempotence_applies_to_statements_without_their_own() {
+    assert!(is_idempotent(
+        request(false, false).with_default_idempotence(true)
+    ));
+    assert!(!is_idempotent(
+        request(false, false).with_default_idempotence(false)
+    ));
+}
+
+#[test]
+fn statement_idempotence_takes_precedence_over_default() {
+    assert!(!is_idempotent(
+        request(false, true).with_default_idempotence(true)
+    ));
+    assert!(is_idempotent(
+        request(true, true).with_default_idempotence(false)
+    ));
+}
+
+fn batch_is_idempotent(is_idempotence_set: bool, default_idempotence: bool) -> bool {
+    PyBatch::new(
+        Batch::new(BatchType::Logged),
+        vec![],
+        false,
+        is_idempotence_set,
+    )
+    .with_default_idempotence(default_idempotence)
+    ._inner
+    .get_is_idempotent()
+}
+
+#[test]
+fn default_idempotence_applies_to_batches_without_their_own() {
+    assert!(batch_is_idempotent(false, true));
+    assert!(!batch_is_idempotent(false, false));
+    // A batch created with `with_is_idempotent(False)` keeps its setting.
+    assert!(!batch_is_idempotent(true, true));
+}
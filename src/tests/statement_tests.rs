@@ -0,0 +1,49 @@
+use crate::statement::{has_allow_filtering, has_mixed_bind_markers};
+
+#[test]
+fn mixed_bind_markers_are_detected() {
+    assert!(has_mixed_bind_markers(
+        "SELECT * FROM t WHERE a = ? AND b = :b"
+    ));
+    assert!(has_mixed_bind_markers(
+        "SELECT * FROM t WHERE a = ? LIMIT :l"
+    ));
+    assert!(has_mixed_bind_markers(
+        "UPDATE t SET m = m + {'k': :v} WHERE a = ?"
+    ));
+}
+
+#[test]
+fn map_and_udt_literals_are_not_named_markers() {
+    assert!(!has_mixed_bind_markers(
+        "UPDATE t SET m = {'k':v} WHERE a = ?"
+    ));
+    assert!(!has_mixed_bind_markers(
+        "UPDATE t SET u = {field:value} WHERE a = ?"
+    ));
+    assert!(!has_mixed_bind_markers(
+        "SELECT '1'::int FROM t WHERE a = ?"
+    ));
+}
+
+#[test]
+fn markers_in_literals_and_comments_are_ignored() {
+    assert!(!has_mixed_bind_markers(
+        "SELECT * FROM t WHERE a = ? AND b = ':b' -- :c"
+    ));
+    assert!(!has_mixed_bind_markers(
+        "SELECT * FROM t WHERE a = :a AND b = '?' /* ? */"
+    ));
+}
+
+#[test]
+fn allow_filtering_is_detected_outside_literals() {
+    assert!(has_allow_filtering("SELECT * FROM t ALLOW FILTERING"));
+    assert!(has_allow_filtering(
+        "SELECT * FROM t allow /* comment */ filtering"
+    ));
+    assert!(!has_allow_filtering(
+        "SELECT * FROM t WHERE a = 'ALLOW FILTERING'"
+    ));
+    assert!(!has_allow_filtering("SELECT allow, filtering FROM t"));
+}
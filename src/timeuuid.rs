@@ -0,0 +1,146 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use pyo3::prelude::*;
+use pyo3::types::PyDateTime;
+use uuid::Uuid;
+
+use crate::errors::DriverTimeuuidError;
+use crate::serialize::value::extract_utc_datetime;
+
+/// Number of 100ns intervals between the UUID epoch (1582-10-15) and the Unix epoch.
+const UUID_EPOCH_OFFSET: i64 = 0x01B2_1DD2_1381_4000;
+
+/// Timestamps of version 1 UUIDs are 60-bit counts of 100ns intervals.
+const MAX_UUID_TICKS: i64 = (1 << 60) - 1;
+
+// Scylla compares the clock sequence and node of timeuuids as signed bytes.
+// These are the lowest and the highest values that keep the RFC 4122 variant.
+const MIN_CLOCK_SEQ_AND_NODE: u64 = 0x8080_8080_8080_8080;
+const MAX_CLOCK_SEQ_AND_NODE: u64 = 0xBF7F_7F7F_7F7F_7F7F;
+
+const VARIANT_MASK: u64 = 0x3FFF_FFFF_FFFF_FFFF;
+const VARIANT_RFC4122: u64 = 0x8000_0000_0000_0000;
+// Random node IDs must have the multicast bit set, so they never collide with MAC addresses.
+const NODE_MULTICAST_BIT: u64 = 0x0000_0100_0000_0000;
+
+/// Generate a version 1 UUID (`timeuuid`) with a random clock sequence and node.
+///
+/// Uses the current time if `timestamp` is `None`.
+#[pyfunction]
+#[pyo3(signature = (timestamp=None))]
+pub(crate) fn timeuuid_from_time(
+    timestamp: Option<&Bound<'_, PyAny>>,
+) -> Result<Uuid, DriverTimeuuidError> {
+    let ticks = match timestamp {
+        Some(timestamp) => unix_ticks(timestamp)?,
+        None => now_ticks(),
+    };
+
+    let random = u64::from_be_bytes(Uuid::new_v4().as_bytes()[8..].try_into().unwrap());
+    let clock_seq_and_node = (random & VARIANT_MASK) | VARIANT_RFC4122 | NODE_MULTICAST_BIT;
+
+    timeuuid(ticks, clock_seq_and_node)
+}
+
+/// Lowest `timeuuid` for the given timestamp, for use as a lower bound in range queries.
+#[pyfunction]
+pub(crate) fn min_timeuuid(timestamp: &Bound<'_, PyAny>) -> Result<Uuid, DriverTimeuuidError> {
+    timeuuid(unix_ticks(timestamp)?, MIN_CLOCK_SEQ_AND_NODE)
+}
+
+/// Highest `timeuuid` for the given timestamp, for use as an upper bound in range queries.
+#[pyfunction]
+pub(crate) fn max_timeuuid(timestamp: &Bound<'_, PyAny>) -> Result<Uuid, DriverTimeuuidError> {
+    timeuuid(unix_ticks(timestamp)?, MAX_CLOCK_SEQ_AND_NODE)
+}
+
+/// Timestamp of a `timeuuid`, as a UTC `datetime` with microsecond precision.
+#[pyfunction]
+pub(crate) fn datetime_from_timeuuid(uuid: Uuid) -> Result<DateTime<Utc>, DriverTimeuuidError> {
+    let version = uuid.get_version_num();
+    if version != 1 {
+        return Err(DriverTimeuuidError::not_a_timeuuid(version));
+    }
+
+    let (high, _) = uuid.as_u64_pair();
+    let time_low = (high >> 32) as i64;
+    let time_mid = ((high >> 16) & 0xFFFF) as i64;
+    let time_hi = (high & 0x0FFF) as i64;
+    let ticks = (time_hi << 48 | time_mid << 32 | time_low) - UUID_EPOCH_OFFSET;
+
+    DateTime::from_timestamp_micros(ticks.div_euclid(10))
+        .ok_or_else(DriverTimeuuidError::timestamp_out_of_range)
+}
+
+/// Builds a version 1 UUID from 100ns intervals since the Unix epoch.
+fn timeuuid(unix_ticks: i64, clock_seq_and_node: u64) -> Result<Uuid, DriverTimeuuidError> {
+    let ticks = unix_ticks
+        .checked_add(UUID_EPOCH_OFFSET)
+        .filter(|ticks| (0..=MAX_UUID_TICKS).contains(ticks))
+        .ok_or_else(DriverTimeuuidError::timestamp_out_of_range)? as u64;
+
+    let time_low = ticks & 0xFFFF_FFFF;
+    let time_mid = (ticks >> 32) & 0xFFFF;
+    let time_hi_and_version = ((ticks >> 48) & 0x0FFF) | 0x1000;
+
+    Ok(Uuid::from_u64_pair(
+        time_low << 32 | time_mid << 16 | time_hi_and_version,
+        clock_seq_and_node,
+    ))
+}
+
+/// Converts a Python `datetime` or a number of seconds since the Unix epoch
+/// into 100ns intervals since the Unix epoch.
+///
+/// Naive datetimes are treated as UTC.
+fn unix_ticks(timestamp: &Bound<'_, PyAny>) -> Result<i64, DriverTimeuuidError> {
+    if timestamp.is_instance_of::<PyDateTime>() {
+        let datetime = match timestamp.extract::<NaiveDateTime>() {
+            Ok(naive) => naive.and_utc(),
+            Err(_) => extract_utc_datetime(timestamp)
+                .ok_or_else(DriverTimeuuidError::timestamp_out_of_range)?,
+        };
+
+        return datetime
+            .timestamp_micros()
+            .checked_mul(10)
+            .ok_or_else(DriverTimeuuidError::timestamp_out_of_range);
+    }
+
+    let seconds = timestamp
+        .extract::<f64>()
+        .map_err(|_| DriverTimeuuidError::invalid_timestamp(timestamp))?;
+
+    let ticks = (seconds * 1e7).round();
+    if !ticks.is_finite() || ticks.abs() > MAX_UUID_TICKS as f64 {
+        return Err(DriverTimeuuidError::timestamp_out_of_range());
+    }
+
+    Ok(ticks as i64)
+}
+
+/// Timestamp of the last `timeuuid` generated for the current time, in 100ns
+/// intervals since the Unix epoch.
+static LAST_NOW_TICKS: AtomicI64 = AtomicI64::new(0);
+
+/// Returns the current time in 100ns intervals since the Unix epoch, later than
+/// the one returned by the previous call.
+///
+/// Scylla orders timeuuids by their timestamp first, so strictly increasing
+/// timestamps make timeuuids generated one after another sort in generation
+/// order, even within the same 100ns interval or if the system clock goes back.
+fn now_ticks() -> i64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| (d.as_nanos() / 100) as i64)
+        .unwrap_or(0);
+
+    let last = LAST_NOW_TICKS
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| {
+            Some(now.max(last + 1))
+        })
+        .expect("the update closure always returns Some");
+    now.max(last + 1)
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use chrono::{DateTime, Utc};
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+use scylla::observability::tracing::{TracingEvent, TracingInfo};
+use uuid::Uuid;
+
+/// Single row of `system_traces.events`, describing one step of a traced request.
+#[pyclass(get_all, name = "TracingEvent", frozen, skip_from_py_object)]
+#[derive(Clone)]
+pub(crate) struct PyTracingEvent {
+    event_id: Uuid,
+    activity: Option<String>,
+    source: Option<IpAddr>,
+    /// Microseconds elapsed on the `source` node since it started handling the request.
+    source_elapsed: Option<i32>,
+    thread: Option<String>,
+}
+
+impl From<TracingEvent> for PyTracingEvent {
+    fn from(event: TracingEvent) -> Self {
+        Self {
+            event_id: event.event_id,
+            activity: event.activity,
+            source: event.source,
+            source_elapsed: event.source_elapsed,
+            thread: event.thread,
+        }
+    }
+}
+
+#[pymethods]
+impl PyTracingEvent {
+    fn __repr__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyString>> {
+        PyString::from_fmt(
+            py,
+            format_args!(
+                "TracingEvent(source={:?}, source_elapsed={:?}, activity={:?})",
+                self.source, self.source_elapsed, self.activity
+            ),
+        )
+    }
+}
+
+/// Tracing session of a request, read from `system_traces.sessions`,
+/// together with its events.
+#[pyclass(get_all, name = "TracingInfo", frozen, skip_from_py_object)]
+pub(crate) struct PyTracingInfo {
+    client: Option<IpAddr>,
+    command: Option<String>,
+    coordinator: Option<IpAddr>,
+    /// Duration of the request on the coordinator, in microseconds.
+    duration: Option<i32>,
+    parameters: Option<HashMap<String, String>>,
+    request: Option<String>,
+    started_at: Option<DateTime<Utc>>,
+    /// Events ordered by `source_elapsed`, events without it come first.
+    events: Vec<PyTracingEvent>,
+}
+
+impl From<TracingInfo> for PyTracingInfo {
+    fn from(info: TracingInfo) -> Self {
+        let mut events = info
+            .events
+            .into_iter()
+            .map(PyTracingEvent::from)
+            .collect::<Vec<_>>();
+        // Stable sort, so events with equal elapsed times keep the order of `event_id`.
+        events.sort_by_key(|event| event.source_elapsed);
+
+        Self {
+            client: info.client,
+            command: info.command,
+            coordinator: info.coordinator,
+            duration: info.duration,
+            parameters: info.parameters,
+            request: info.request,
+            started_at: info
+                .started_at
+                .and_then(|ts| DateTime::from_timestamp_millis(ts.0)),
+            events,
+        }
+    }
+}
+
+#[pymethods]
+impl PyTracingInfo {
+    fn __repr__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyString>> {
+        PyString::from_fmt(
+            py,
+            format_args!(
+                "TracingInfo(coordinator={:?}, request={:?}, duration={:?}, events={})",
+                self.coordinator,
+                self.request,
+                self.duration,
+                self.events.len()
+            ),
+        )
+    }
+}
+
+#[pymodule]
+pub(crate) fn tracing(_py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyTracingInfo>()?;
+    module.add_class::<PyTracingEvent>()?;
+    Ok(())
+}
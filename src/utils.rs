@@ -1,7 +1,17 @@
+use std::borrow::Cow;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
 use pyo3::{
-    Bound, PyResult, Python,
+    Bound, Py, PyAny, PyResult, Python,
+    sync::PyOnceLock,
     types::{PyAnyMethods, PyModule, PyModuleMethods},
 };
+use tokio::runtime::Handle;
+use tokio::task::{JoinError, JoinHandle};
+
+use crate::errors::NoRunningEventLoop;
 
 /// Add submodule.
 ///
@@ -40,3 +50,65 @@ pub(crate) fn add_submodule(
         .set_item(&full_name, sub_module)?;
     Ok(())
 }
+
+/// Resolves a keyspace, table or column name for a schema lookup. With
+/// `normalize_case`, the name is lowercased, as the server does with
+/// unquoted identifiers. Otherwise names are matched as stored, so names
+/// created quoted are found by their exact name.
+pub(crate) fn cql_identifier(name: &str, normalize_case: bool) -> Cow<'_, str> {
+    if normalize_case {
+        Cow::Owned(name.to_lowercase())
+    } else {
+        Cow::Borrowed(name)
+    }
+}
+
+/// Returns `true` if an asyncio event loop is running in the current thread.
+///
+/// Blocking APIs use this to refuse being called from async code,
+/// where they would stall the event loop.
+pub(crate) fn event_loop_is_running(py: Python<'_>) -> bool {
+    static GET_RUNNING_LOOP: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+
+    GET_RUNNING_LOOP
+        .import(py, "asyncio", "get_running_loop")
+        .and_then(|get_running_loop| get_running_loop.call0())
+        .is_ok()
+}
+
+/// Fails if called from a coroutine driven without a running asyncio event loop.
+///
+/// The coroutines returned by the driver can only be driven by
+/// asyncio-compatible event loops, so requests check this before anything
+/// is sent. Code running on `RUNTIME`, such as the blocking API and tasks
+/// spawned there, is not driven by Python and is not checked.
+pub(crate) fn ensure_event_loop_is_running() -> Result<(), NoRunningEventLoop> {
+    if Handle::try_current().is_ok() || Python::attach(event_loop_is_running) {
+        Ok(())
+    } else {
+        Err(NoRunningEventLoop)
+    }
+}
+
+/// Join handle that aborts the spawned task when dropped.
+///
+/// Dropping a plain `JoinHandle` detaches the task, so a Python awaitable
+/// that gets cancelled would leave the request running on `RUNTIME`.
+/// Awaiting through this wrapper ties the lifetime of the task
+/// to the lifetime of the awaiting future.
+pub(crate) struct AbortOnDropHandle<T>(pub(crate) JoinHandle<T>);
+
+impl<T> Future for AbortOnDropHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+impl<T> Drop for AbortOnDropHandle<T> {
+    fn drop(&mut self) {
+        // No-op if the task has already completed.
+        self.0.abort();
+    }
+}